@@ -0,0 +1,85 @@
+//! Benchmarks for encoding and decoding across input sizes and alphabets.
+//!
+//! Run with `cargo bench --bench codec`.
+
+use std::hint::black_box;
+
+use b58::{Alphabet, decode_with_alphabet, decode32, encode_with_alphabet, encode32};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+/// Input sizes of common payloads: a hash160, a key or hash, a signature, and a blob
+const SIZES: [usize; 4] = [20, 32, 64, 256];
+
+/// Deterministic bytes with no leading zeros
+fn input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 131 + 7) as u8 | 1).collect()
+}
+
+fn bench_sizes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("size");
+    for len in SIZES {
+        let data = input(len);
+        let encoded = encode_with_alphabet(&data, Alphabet::Bitcoin);
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("encode", len), &data, |b, data| {
+            b.iter(|| encode_with_alphabet(black_box(data), Alphabet::Bitcoin))
+        });
+        group.bench_with_input(BenchmarkId::new("decode", len), &encoded, |b, encoded| {
+            b.iter(|| decode_with_alphabet(black_box(encoded), Alphabet::Bitcoin))
+        });
+    }
+    group.finish();
+}
+
+fn bench_leading_zeros(c: &mut Criterion) {
+    let mut group = c.benchmark_group("leading_zeros");
+    for zeros in [0, 16, 31] {
+        let mut data = input(32);
+        data[..zeros].fill(0);
+        let encoded = encode_with_alphabet(&data, Alphabet::Bitcoin);
+        group.bench_with_input(BenchmarkId::new("encode", zeros), &data, |b, data| {
+            b.iter(|| encode_with_alphabet(black_box(data), Alphabet::Bitcoin))
+        });
+        group.bench_with_input(BenchmarkId::new("decode", zeros), &encoded, |b, encoded| {
+            b.iter(|| decode_with_alphabet(black_box(encoded), Alphabet::Bitcoin))
+        });
+    }
+    group.finish();
+}
+
+fn bench_alphabets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alphabet");
+    let data = input(32);
+    for (name, alphabet) in [
+        ("bitcoin", Alphabet::Bitcoin),
+        ("ripple", Alphabet::Ripple),
+        ("flickr", Alphabet::Flickr),
+    ] {
+        let encoded = encode_with_alphabet(&data, alphabet);
+        group.bench_with_input(BenchmarkId::new("encode", name), &data, |b, data| {
+            b.iter(|| encode_with_alphabet(black_box(data), alphabet))
+        });
+        group.bench_with_input(BenchmarkId::new("decode", name), &encoded, |b, encoded| {
+            b.iter(|| decode_with_alphabet(black_box(encoded), alphabet))
+        });
+    }
+    group.finish();
+}
+
+fn bench_specialized(c: &mut Criterion) {
+    let mut group = c.benchmark_group("specialized");
+    let data: [u8; 32] = input(32).try_into().unwrap();
+    let encoded = encode32(data);
+    group.bench_function("encode32", |b| b.iter(|| encode32(black_box(data))));
+    group.bench_function("decode32", |b| b.iter(|| decode32(black_box(&encoded))));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_sizes,
+    bench_leading_zeros,
+    bench_alphabets,
+    bench_specialized
+);
+criterion_main!(benches);
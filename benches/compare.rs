@@ -0,0 +1,56 @@
+//! Side-by-side benchmarks against the `bs58` crate.
+//!
+//! Run with `cargo bench --bench compare`. Both crates encode the same inputs with the
+//! Bitcoin alphabet, so their results are checked to match before timing.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+/// Input sizes of common payloads: a hash160, a key or hash, a signature, and a blob
+const SIZES: [usize; 4] = [20, 32, 64, 256];
+
+/// Deterministic bytes with no leading zeros
+fn input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 131 + 7) as u8 | 1).collect()
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_encode");
+    for len in SIZES {
+        let data = input(len);
+        assert_eq!(b58::encode(&data), bs58::encode(&data).into_string());
+
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("b58", len), &data, |b, data| {
+            b.iter(|| b58::encode(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("bs58", len), &data, |b, data| {
+            b.iter(|| bs58::encode(black_box(data)).into_string())
+        });
+    }
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_decode");
+    for len in SIZES {
+        let encoded = b58::encode(input(len));
+        assert_eq!(
+            b58::decode(&encoded).unwrap(),
+            bs58::decode(&encoded).into_vec().unwrap()
+        );
+
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("b58", len), &encoded, |b, encoded| {
+            b.iter(|| b58::decode(black_box(encoded)))
+        });
+        group.bench_with_input(BenchmarkId::new("bs58", len), &encoded, |b, encoded| {
+            b.iter(|| bs58::decode(black_box(encoded)).into_vec())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_decode);
+criterion_main!(benches);
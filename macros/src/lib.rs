@@ -0,0 +1,264 @@
+//! Compile-time Base58 literals for the `b58` crate.
+//!
+//! These macros are re-exported by `b58` when its `macros` feature is enabled; use them
+//! through `b58::b58!` and `b58::b58_encode!` rather than depending on this crate directly.
+//! Both use the Bitcoin alphabet and report invalid input as a compile error pointing at
+//! the literal.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Decodes a Base58 string literal into a `[u8; N]` array at compile time.
+///
+/// ```ignore
+/// const HELLO: [u8; 5] = b58::b58!("9Ajdvzr");
+/// assert_eq!(&HELLO, b"Hello");
+/// ```
+#[proc_macro]
+pub fn b58(input: TokenStream) -> TokenStream {
+    let (literal, span) = match single_literal(input) {
+        Ok(lit) => lit,
+        Err((span, msg)) => return compile_error(span, &msg),
+    };
+
+    let text = match parse_str_literal(&literal) {
+        Some(text) => text,
+        None => return compile_error(span, "expected a string literal"),
+    };
+
+    match decode(&text) {
+        Ok(bytes) => {
+            let mut elements = TokenStream::new();
+            for (i, byte) in bytes.iter().enumerate() {
+                if i > 0 {
+                    elements.extend([TokenTree::from(Punct::new(',', Spacing::Alone))]);
+                }
+                elements.extend([TokenTree::from(Literal::u8_suffixed(*byte))]);
+            }
+            let mut array = Group::new(Delimiter::Bracket, elements);
+            array.set_span(span);
+            TokenStream::from(TokenTree::from(array))
+        }
+        Err(msg) => compile_error(span, &msg),
+    }
+}
+
+/// Encodes a byte string literal into a Base58 `&'static str` at compile time.
+///
+/// ```ignore
+/// const HELLO: &str = b58::b58_encode!(b"Hello");
+/// assert_eq!(HELLO, "9Ajdvzr");
+/// ```
+#[proc_macro]
+pub fn b58_encode(input: TokenStream) -> TokenStream {
+    let (literal, span) = match single_literal(input) {
+        Ok(lit) => lit,
+        Err((span, msg)) => return compile_error(span, &msg),
+    };
+
+    match parse_byte_str_literal(&literal) {
+        Some(bytes) => {
+            let mut encoded = Literal::string(&encode(&bytes));
+            encoded.set_span(span);
+            TokenStream::from(TokenTree::from(encoded))
+        }
+        None => compile_error(span, "expected a byte string literal such as b\"...\""),
+    }
+}
+
+/// Extracts the only token of the macro input, which must be a literal.
+fn single_literal(input: TokenStream) -> Result<(String, Span), (Span, String)> {
+    let mut tokens = input.into_iter();
+    let token = match tokens.next() {
+        // Literals forwarded through `macro_rules!` arrive wrapped in an invisible group
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
+            return single_literal(group.stream());
+        }
+        Some(token) => token,
+        None => return Err((Span::call_site(), "expected a literal".to_string())),
+    };
+
+    if let Some(extra) = tokens.next() {
+        return Err((extra.span(), "unexpected token after literal".to_string()));
+    }
+
+    match token {
+        TokenTree::Literal(lit) => Ok((lit.to_string(), lit.span())),
+        other => Err((other.span(), "expected a literal".to_string())),
+    }
+}
+
+/// Returns the contents of a string literal, or `None` if `literal` is not one.
+fn parse_str_literal(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        return strip_raw(raw).map(str::to_string);
+    }
+
+    let inner = literal.strip_prefix('"')?.strip_suffix('"')?;
+    String::from_utf8(unescape(inner)?).ok()
+}
+
+/// Returns the bytes of a byte string literal, or `None` if `literal` is not one.
+fn parse_byte_str_literal(literal: &str) -> Option<Vec<u8>> {
+    if let Some(raw) = literal.strip_prefix("br") {
+        return strip_raw(raw).map(|s| s.as_bytes().to_vec());
+    }
+
+    let inner = literal.strip_prefix("b\"")?.strip_suffix('"')?;
+    unescape(inner)
+}
+
+/// Strips the `#...#"` / `"#...#` delimiters of a raw literal (after the `r`).
+fn strip_raw(raw: &str) -> Option<&str> {
+    let hashes = raw.len() - raw.trim_start_matches('#').len();
+    let body = &raw[hashes..raw.len().checked_sub(hashes)?];
+    body.strip_prefix('"')?.strip_suffix('"')
+}
+
+/// Resolves the escape sequences allowed in byte string literals.
+///
+/// Non-ASCII characters are passed through as their UTF-8 bytes.
+fn unescape(inner: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(inner.len());
+    let mut bytes = inner.bytes().peekable();
+
+    while let Some(b) = bytes.next() {
+        if b != b'\\' {
+            out.push(b);
+            continue;
+        }
+
+        match bytes.next()? {
+            b'n' => out.push(b'\n'),
+            b'r' => out.push(b'\r'),
+            b't' => out.push(b'\t'),
+            b'0' => out.push(0),
+            b'\\' => out.push(b'\\'),
+            b'\'' => out.push(b'\''),
+            b'"' => out.push(b'"'),
+            b'x' => {
+                let hi = (bytes.next()? as char).to_digit(16)?;
+                let lo = (bytes.next()? as char).to_digit(16)?;
+                out.push((hi * 16 + lo) as u8);
+            }
+            // Line continuation: skip the newline and any leading whitespace
+            b'\n' | b'\r' => while bytes.next_if(|b| b.is_ascii_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+
+    Some(out)
+}
+
+/// Encodes bytes using the Bitcoin alphabet.
+fn encode(input: &[u8]) -> String {
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in &input[leading_zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut encoded = "1".repeat(leading_zeros);
+    encoded.extend(digits.iter().rev().map(|&d| ALPHABET[d as usize] as char));
+    encoded
+}
+
+/// Decodes a string using the Bitcoin alphabet.
+fn decode(input: &str) -> Result<Vec<u8>, String> {
+    let leading_zeros = input.bytes().take_while(|&b| b == b'1').count();
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for (offset, c) in input.char_indices().skip(leading_zeros) {
+        let digit = ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| format!("invalid Base58 character '{c}' at byte offset {offset}"))?;
+
+        let mut carry = digit as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut decoded = vec![0; leading_zeros];
+    decoded.extend(bytes.iter().rev());
+    Ok(decoded)
+}
+
+/// Builds `compile_error!("...")` tokens attributed to `span`.
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let mut message = Literal::string(msg);
+    message.set_span(span);
+
+    let mut punct = Punct::new('!', Spacing::Alone);
+    punct.set_span(span);
+
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+    args.set_span(span);
+
+    [
+        TokenTree::from(Ident::new("compile_error", span)),
+        TokenTree::from(punct),
+        TokenTree::from(args),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        assert_eq!(encode(b"Hello"), "9Ajdvzr");
+        assert_eq!(encode(&[0, 0, 1, 2, 3]), "11Ldp");
+        assert_eq!(decode("9Ajdvzr").unwrap(), b"Hello");
+        assert_eq!(decode("11Ldp").unwrap(), vec![0, 0, 1, 2, 3]);
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_decode_reports_offset() {
+        assert_eq!(
+            decode("9Ajd0vzr").unwrap_err(),
+            "invalid Base58 character '0' at byte offset 4"
+        );
+    }
+
+    #[test]
+    fn test_parse_str_literal() {
+        assert_eq!(parse_str_literal("\"9Ajdvzr\"").unwrap(), "9Ajdvzr");
+        assert_eq!(parse_str_literal("r#\"9Ajdvzr\"#").unwrap(), "9Ajdvzr");
+        assert_eq!(parse_str_literal("b\"9Ajdvzr\""), None);
+        assert_eq!(parse_str_literal("42"), None);
+    }
+
+    #[test]
+    fn test_parse_byte_str_literal() {
+        assert_eq!(parse_byte_str_literal("b\"Hello\"").unwrap(), b"Hello");
+        assert_eq!(
+            parse_byte_str_literal(r#"b"\x00\x01\n\\\"""#).unwrap(),
+            b"\x00\x01\n\\\""
+        );
+        assert_eq!(parse_byte_str_literal("br\"a\\b\"").unwrap(), b"a\\b");
+        assert_eq!(parse_byte_str_literal("\"Hello\""), None);
+    }
+}
@@ -0,0 +1,310 @@
+//! Legacy Bitcoin addresses (P2PKH and P2SH).
+//!
+//! An address is the Base58Check encoding of a version byte followed by a 20-byte
+//! hash160: the RIPEMD-160 of the SHA-256 of a public key (P2PKH) or redeem script (P2SH).
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::address::{self, AddressKind, Network};
+//!
+//! let addr = address::parse("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap();
+//! assert_eq!(addr.kind, AddressKind::P2pkh);
+//! assert_eq!(addr.network, Network::Mainnet);
+//! assert_eq!(address::encode_p2pkh(&addr.hash, Network::Mainnet), "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+//! ```
+
+use crate::ripemd160::ripemd160;
+use crate::sha256::sha256;
+use crate::{DecodeError, decode_check, encode_check, version};
+
+pub use crate::wif::Network;
+
+/// Version byte of mainnet P2PKH addresses.
+pub const P2PKH_MAINNET_VERSION: u8 = version::BITCOIN_P2PKH;
+/// Version byte of mainnet P2SH addresses.
+pub const P2SH_MAINNET_VERSION: u8 = version::BITCOIN_P2SH;
+/// Version byte of testnet P2PKH addresses.
+pub const P2PKH_TESTNET_VERSION: u8 = version::BITCOIN_TESTNET_P2PKH;
+/// Version byte of testnet P2SH addresses.
+pub const P2SH_TESTNET_VERSION: u8 = version::BITCOIN_TESTNET_P2SH;
+
+/// The kind of script an address pays to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    /// Pay to public key hash.
+    P2pkh,
+    /// Pay to script hash.
+    P2sh,
+}
+
+/// A parsed legacy Bitcoin address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    /// The kind of script the address pays to.
+    pub kind: AddressKind,
+    /// The network the address belongs to.
+    pub network: Network,
+    /// The hash160 of the public key or redeem script.
+    pub hash: [u8; 20],
+}
+
+impl Address {
+    /// Returns the version byte for this address's kind and network.
+    pub fn version(&self) -> u8 {
+        match (self.kind, self.network) {
+            (AddressKind::P2pkh, Network::Mainnet) => P2PKH_MAINNET_VERSION,
+            (AddressKind::P2sh, Network::Mainnet) => P2SH_MAINNET_VERSION,
+            (AddressKind::P2pkh, Network::Testnet) => P2PKH_TESTNET_VERSION,
+            (AddressKind::P2sh, Network::Testnet) => P2SH_TESTNET_VERSION,
+        }
+    }
+
+    /// Encodes the address as a Base58Check string.
+    pub fn encode(&self) -> String {
+        let mut payload = [0u8; 21];
+        payload[0] = self.version();
+        payload[1..].copy_from_slice(&self.hash);
+        encode_check(payload)
+    }
+}
+
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl core::str::FromStr for Address {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// Error type for address parsing failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// The string is not valid Base58Check.
+    Decode(DecodeError),
+    /// The version byte is not a known P2PKH or P2SH version.
+    InvalidVersion(u8),
+    /// The payload is not 21 bytes long.
+    InvalidLength(usize),
+}
+
+impl core::fmt::Display for AddressError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AddressError::Decode(e) => write!(f, "Invalid address encoding: {e}"),
+            AddressError::InvalidVersion(v) => {
+                write!(f, "Unknown address version byte: 0x{v:02x}")
+            }
+            AddressError::InvalidLength(len) => {
+                write!(f, "Invalid address payload length: {len}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AddressError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            AddressError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for AddressError {
+    fn from(e: DecodeError) -> Self {
+        AddressError::Decode(e)
+    }
+}
+
+/// Computes the hash160 of a public key or redeem script.
+///
+/// # Arguments
+///
+/// * `data` - The serialized public key or script
+///
+/// # Returns
+///
+/// The RIPEMD-160 of the SHA-256 of `data`
+///
+/// # Examples
+///
+/// ```
+/// use b58::address::{self, Network};
+///
+/// let public_key = [
+///     0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+///     0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16,
+///     0xf8, 0x17, 0x98,
+/// ];
+/// let hash = address::hash160(&public_key);
+/// assert_eq!(
+///     address::encode_p2pkh(&hash, Network::Mainnet),
+///     "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+/// );
+/// ```
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    ripemd160(&sha256(data))
+}
+
+/// Encodes a pay-to-public-key-hash address.
+///
+/// # Arguments
+///
+/// * `hash160` - The hash160 of the public key
+/// * `network` - The network the address belongs to
+///
+/// # Returns
+///
+/// The Base58Check encoded address
+pub fn encode_p2pkh(hash160: &[u8; 20], network: Network) -> String {
+    Address {
+        kind: AddressKind::P2pkh,
+        network,
+        hash: *hash160,
+    }
+    .encode()
+}
+
+/// Encodes a pay-to-script-hash address.
+///
+/// # Arguments
+///
+/// * `hash160` - The hash160 of the redeem script
+/// * `network` - The network the address belongs to
+///
+/// # Returns
+///
+/// The Base58Check encoded address
+pub fn encode_p2sh(hash160: &[u8; 20], network: Network) -> String {
+    Address {
+        kind: AddressKind::P2sh,
+        network,
+        hash: *hash160,
+    }
+    .encode()
+}
+
+/// Parses and validates a legacy Bitcoin address.
+///
+/// # Arguments
+///
+/// * `input` - The address string
+///
+/// # Returns
+///
+/// The address kind, network and hash160, or an `AddressError` if the checksum,
+/// version byte or length is invalid
+pub fn parse(input: &str) -> Result<Address, AddressError> {
+    let payload = decode_check(input)?;
+    if payload.len() != 21 {
+        return Err(AddressError::InvalidLength(payload.len()));
+    }
+
+    let (kind, network) = match payload[0] {
+        P2PKH_MAINNET_VERSION => (AddressKind::P2pkh, Network::Mainnet),
+        P2SH_MAINNET_VERSION => (AddressKind::P2sh, Network::Mainnet),
+        P2PKH_TESTNET_VERSION => (AddressKind::P2pkh, Network::Testnet),
+        P2SH_TESTNET_VERSION => (AddressKind::P2sh, Network::Testnet),
+        version => return Err(AddressError::InvalidVersion(version)),
+    };
+
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(&payload[1..]);
+
+    Ok(Address {
+        kind,
+        network,
+        hash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_hex;
+
+    fn hash(hex: &str) -> [u8; 20] {
+        from_hex(hex).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_p2pkh_mainnet() {
+        let hash = hash("62e907b15cbf27d5425399ebf6f0fb50ebb88f18");
+        assert_eq!(
+            encode_p2pkh(&hash, Network::Mainnet),
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        );
+
+        let addr = parse("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap();
+        assert_eq!(addr.kind, AddressKind::P2pkh);
+        assert_eq!(addr.network, Network::Mainnet);
+        assert_eq!(addr.hash, hash);
+    }
+
+    #[test]
+    fn test_p2sh_mainnet() {
+        let hash = hash("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb");
+        assert_eq!(
+            encode_p2sh(&hash, Network::Mainnet),
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"
+        );
+
+        let addr: Address = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy".parse().unwrap();
+        assert_eq!(addr.kind, AddressKind::P2sh);
+        assert_eq!(addr.hash, hash);
+        assert_eq!(addr.to_string(), "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy");
+    }
+
+    #[test]
+    fn test_testnet_roundtrip() {
+        let hash = [0x24; 20];
+        for kind in [AddressKind::P2pkh, AddressKind::P2sh] {
+            let addr = Address {
+                kind,
+                network: Network::Testnet,
+                hash,
+            };
+            let encoded = addr.encode();
+            let expected_prefix = match kind {
+                AddressKind::P2pkh => ['m', 'n'],
+                AddressKind::P2sh => ['2', '2'],
+            };
+            assert!(encoded.starts_with(expected_prefix));
+            assert_eq!(parse(&encoded).unwrap(), addr);
+        }
+    }
+
+    #[test]
+    fn test_hash160() {
+        assert_eq!(
+            hash160(b""),
+            hash("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb")
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(AddressError::Decode(DecodeError::ChecksumMismatch))
+        );
+
+        let mut payload = [0u8; 21];
+        payload[0] = 0x80;
+        assert_eq!(
+            parse(&encode_check(payload)),
+            Err(AddressError::InvalidVersion(0x80))
+        );
+        assert_eq!(
+            parse(&encode_check(&payload[..10])),
+            Err(AddressError::InvalidLength(10))
+        );
+    }
+}
@@ -0,0 +1,589 @@
+//! Big-integer radix conversion for any alphabet size.
+//!
+//! Base58 is one instance of a general scheme: the input bytes are read as a big-endian
+//! number and written out in another radix, with each leading zero byte mapped to the
+//! alphabet's first character. [`BaseN`] exposes that scheme for other radices, with
+//! predefined codecs for Base36, Base45 and Base62.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::baseconv::{BASE36, BASE62};
+//!
+//! assert_eq!(BASE62.encode(b"Hello"), "5TP3P3v");
+//! assert_eq!(BASE36.decode("5pzcszu7").unwrap(), b"hello");
+//! ```
+
+use crate::{AlphabetError, DecodeError, wipe, wipe_slice};
+
+/// Base36: digits and lowercase letters.
+pub const BASE36: BaseN = BaseN::builtin(b"0123456789abcdefghijklmnopqrstuvwxyz");
+/// Base45 using the alphabet of RFC 9285.
+///
+/// This is the big-integer encoding over that alphabet, not the two-byte chunked scheme
+/// the RFC defines for QR codes, so the output is not RFC 9285 compatible.
+pub const BASE45: BaseN = BaseN::builtin(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:");
+/// Base58 with the Bitcoin alphabet, identical to [`encode`](crate::encode).
+pub const BASE58: BaseN =
+    BaseN::builtin(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+/// Base62: digits, uppercase and lowercase letters.
+pub const BASE62: BaseN =
+    BaseN::builtin(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+
+/// A big-integer codec for an alphabet of any length from 2 to 128.
+///
+/// # Examples
+///
+/// ```
+/// use b58::baseconv::BaseN;
+///
+/// const BINARY: BaseN = match BaseN::new(b"01") {
+///     Ok(codec) => codec,
+///     Err(_) => panic!("invalid alphabet"),
+/// };
+/// assert_eq!(BINARY.encode(&[5]), "101");
+/// assert_eq!(BINARY.decode("101").unwrap(), [5]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BaseN {
+    alphabet: &'static [u8],
+    decode_table: [u8; 256],
+}
+
+impl BaseN {
+    /// Creates a codec whose radix is the length of `alphabet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - The characters, in digit order
+    ///
+    /// # Returns
+    ///
+    /// The codec, or an `AlphabetError` if the alphabet has fewer than two characters, or
+    /// a character is not ASCII or appears more than once
+    pub const fn new(alphabet: &'static [u8]) -> Result<Self, AlphabetError> {
+        if alphabet.len() < 2 {
+            return Err(AlphabetError::InvalidLength(alphabet.len()));
+        }
+
+        let mut decode_table = [255u8; 256];
+        let mut i = 0;
+        while i < alphabet.len() {
+            let c = alphabet[i];
+            if !c.is_ascii() {
+                return Err(AlphabetError::NonAsciiCharacter(c));
+            }
+            if decode_table[c as usize] != 255 {
+                return Err(AlphabetError::DuplicateCharacter(c as char));
+            }
+            decode_table[c as usize] = i as u8;
+            i += 1;
+        }
+
+        Ok(BaseN {
+            alphabet,
+            decode_table,
+        })
+    }
+
+    /// Creates one of the predefined codecs, failing the build if the alphabet is invalid
+    const fn builtin(alphabet: &'static [u8]) -> Self {
+        match BaseN::new(alphabet) {
+            Ok(codec) => codec,
+            Err(_) => panic!("invalid built-in alphabet"),
+        }
+    }
+
+    /// Returns the radix, which is the number of characters in the alphabet.
+    pub const fn radix(&self) -> usize {
+        self.alphabet.len()
+    }
+
+    /// Returns the alphabet characters in digit order.
+    pub const fn alphabet(&self) -> &'static [u8] {
+        self.alphabet
+    }
+
+    /// Encodes bytes in this codec's radix.
+    pub fn encode(&self, input: &[u8]) -> String {
+        let mut out = String::new();
+        encode_into(input, self.alphabet, &mut out);
+        out
+    }
+
+    /// Decodes a string in this codec's radix.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The string to decode
+    ///
+    /// # Returns
+    ///
+    /// The decoded bytes, or `DecodeError::InvalidCharacter` if a character is not in
+    /// the alphabet
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        let mut out = Vec::new();
+        decode_into(
+            input.as_bytes(),
+            self.alphabet,
+            &self.decode_table,
+            &mut out,
+        )?;
+        Ok(out)
+    }
+}
+
+/// Encodes `input` in the radix of `alphabet`, replacing the contents of `out`
+pub(crate) fn encode_into(input: &[u8], alphabet: &[u8], out: &mut String) {
+    out.clear();
+
+    // Count leading zeros
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+
+    // Skip leading zeros for calculation
+    let significant_bytes = &input[leading_zeros..];
+
+    // Size the output once, so it is never reallocated while digits are appended
+    out.reserve(leading_zeros + max_encoded_digits(significant_bytes.len(), alphabet.len()));
+
+    // Leading zero bytes map directly to the first alphabet character
+    let zero_char = alphabet[0] as char;
+    out.extend(core::iter::repeat_n(zero_char, leading_zeros));
+
+    if !significant_bytes.is_empty() {
+        encode_big_int(significant_bytes, alphabet, out);
+    }
+}
+
+/// Decodes `input` in the radix of `alphabet`, replacing the contents of `out`
+pub(crate) fn decode_into(
+    input: &[u8],
+    alphabet: &[u8],
+    decode_table: &[u8; 256],
+    out: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    out.clear();
+
+    // Count leading zero characters. Alphabet characters are ASCII, so the input is
+    // processed as bytes without decoding UTF-8.
+    let leading_zeros = input.iter().take_while(|&&b| b == alphabet[0]).count();
+
+    // Size the output once, so it is never reallocated while bytes are appended
+    out.reserve(leading_zeros + max_decoded_bytes(input.len() - leading_zeros, alphabet.len()));
+
+    // Leading zero characters map directly to zero bytes
+    out.resize(leading_zeros, 0);
+
+    if leading_zeros < input.len() {
+        // Decode the remaining characters using big integer arithmetic
+        if let Err(e) = decode_big_int(input, leading_zeros, alphabet.len(), decode_table, out) {
+            out.clear();
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns an upper bound on the number of digits that `len` bytes without leading zeros
+/// encode to
+fn max_encoded_digits(len: usize, radix: usize) -> usize {
+    if radix == 58 {
+        // log(256) / log(58) is just under 1.38
+        return (len * 138).div_ceil(100);
+    }
+    // Each digit carries log2(radix) bits, with one digit to spare for rounding
+    (len as f64 * 8.0 / (radix as f64).log2()).ceil() as usize + 1
+}
+
+/// Returns an upper bound on the number of bytes that `len` digits without leading zero
+/// characters decode to
+fn max_decoded_bytes(len: usize, radix: usize) -> usize {
+    if radix == 58 {
+        // log(58) / log(256) is just under 0.733
+        return (len * 733).div_ceil(1000);
+    }
+    (len as f64 * (radix as f64).log2() / 8.0).ceil() as usize + 1
+}
+
+/// Largest input, in bytes, that is encoded entirely in stack buffers
+const SMALL_INPUT_LEN: usize = 64;
+/// Number of limbs in the stack buffers, enough to decode the 88 Base58 characters of a
+/// 64-byte value
+const SMALL_LIMBS: usize = 20;
+/// Number of digits in the stack buffer for encoding small inputs
+const SMALL_DIGITS_LEN: usize = 128;
+/// Number of characters whose digit values are looked up at a time when decoding
+const LOOKUP_CHUNK_LEN: usize = 64;
+
+/// Encodes a non-empty `input` that does not start with a zero byte, appending the digits
+/// to `out`
+fn encode_big_int(input: &[u8], alphabet: &[u8], out: &mut String) {
+    let radix = alphabet.len() as u32;
+    let limb_count = input.len().div_ceil(4);
+    // Each byte needs at most 8 / floor(log2(radix)) digits
+    let max_digits = input.len() * 8 / radix.ilog2() as usize + 1;
+
+    // Hashes, keys and addresses fit in fixed-size buffers on the stack
+    if input.len() <= SMALL_INPUT_LEN && max_digits <= SMALL_DIGITS_LEN {
+        let mut limbs = [0u32; SMALL_LIMBS];
+        let mut digits = [0u8; SMALL_DIGITS_LEN];
+        let len = encode_limbs(input, radix, &mut limbs[..limb_count], &mut digits);
+        write_digits(&mut digits[..len], alphabet, out);
+        wipe_slice(&mut limbs);
+        wipe_slice(&mut digits);
+    } else {
+        let mut limbs = vec![0u32; limb_count];
+        let mut digits = vec![0u8; max_digits];
+        let len = encode_limbs(input, radix, &mut limbs, &mut digits);
+        write_digits(&mut digits[..len], alphabet, out);
+        wipe(&mut limbs);
+        wipe(&mut digits);
+    }
+}
+
+/// Converts `input` to digits of `radix`, least significant first, and returns how many
+/// were written. `limbs` is scratch space of exactly one limb per 4 input bytes.
+fn encode_limbs(input: &[u8], radix: u32, limbs: &mut [u32], digits: &mut [u8]) -> usize {
+    bytes_to_limbs(input, limbs);
+
+    // Convert to the target radix using long division by the largest power of the
+    // radix that fits in a limb, so each pass yields several digits. Limbs that have
+    // already become zero are skipped.
+    let (power, digits_per_pass) = radix_power(radix);
+    let mut len = 0;
+    let mut start = 0;
+    while start < limbs.len() {
+        let mut remainder = divide(&mut limbs[start..], power);
+        while start < limbs.len() && limbs[start] == 0 {
+            start += 1;
+        }
+
+        if start < limbs.len() {
+            for _ in 0..digits_per_pass {
+                digits[len] = (remainder % radix) as u8;
+                remainder /= radix;
+                len += 1;
+            }
+        } else {
+            // The last pass holds the most significant digits, which have no zero padding
+            while remainder > 0 {
+                digits[len] = (remainder % radix) as u8;
+                remainder /= radix;
+                len += 1;
+            }
+        }
+    }
+    len
+}
+
+/// Appends digits given least significant first to `out` as alphabet characters,
+/// translating them in place
+fn write_digits(digits: &mut [u8], alphabet: &[u8], out: &mut String) {
+    digits.reverse();
+    digits_to_chars(digits, alphabet);
+    out.push_str(core::str::from_utf8(digits).expect("alphabet characters are ASCII"));
+}
+
+/// Replaces digit values with their alphabet characters
+fn digits_to_chars(digits: &mut [u8], alphabet: &[u8]) {
+    #[cfg(feature = "simd")]
+    let digits = {
+        let done = crate::simd::digits_to_chars(digits, alphabet);
+        &mut digits[done..]
+    };
+    for digit in digits {
+        *digit = alphabet[*digit as usize];
+    }
+}
+
+/// Looks up the digit value of each character, returning the offset of the first one
+/// that is not in the alphabet
+fn chars_to_digits(input: &[u8], decode_table: &[u8; 256], digits: &mut [u8]) -> Result<(), usize> {
+    #[cfg(feature = "simd")]
+    let done = crate::simd::chars_to_digits(input, decode_table, digits);
+    #[cfg(not(feature = "simd"))]
+    let done = 0;
+
+    for (i, (&c, digit)) in input.iter().zip(digits.iter_mut()).enumerate().skip(done) {
+        *digit = decode_table[c as usize];
+        if *digit == 255 {
+            return Err(i);
+        }
+    }
+    Ok(())
+}
+
+/// Packs big-endian bytes into big-endian 32-bit limbs, padding the first limb with zeros
+fn bytes_to_limbs(input: &[u8], limbs: &mut [u32]) {
+    let head = input.len() % 4;
+    let (body, rest) = if head > 0 {
+        limbs[0] = input[..head]
+            .iter()
+            .fold(0, |acc, &b| (acc << 8) | b as u32);
+        (&input[head..], &mut limbs[1..])
+    } else {
+        (input, limbs)
+    };
+    for (limb, chunk) in rest.iter_mut().zip(body.chunks_exact(4)) {
+        *limb = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+}
+
+/// Returns the largest power of `radix` that fits in a limb, and its exponent
+fn radix_power(radix: u32) -> (u32, usize) {
+    let mut power = radix;
+    let mut exponent = 1;
+    while let Some(next) = power.checked_mul(radix) {
+        power = next;
+        exponent += 1;
+    }
+    (power, exponent)
+}
+
+/// Divides big-endian limbs by `divisor` in place and returns the remainder
+fn divide(limbs: &mut [u32], divisor: u32) -> u32 {
+    let divisor = divisor as u64;
+    let mut remainder = 0u64;
+
+    for limb in limbs.iter_mut() {
+        let temp = (remainder << 32) | *limb as u64;
+        *limb = (temp / divisor) as u32;
+        remainder = temp % divisor;
+    }
+
+    remainder as u32
+}
+
+/// Decodes `input[start..]` using big integer arithmetic on 32-bit limbs, appending the
+/// bytes to `out`
+fn decode_big_int(
+    input: &[u8],
+    start: usize,
+    radix: usize,
+    decode_table: &[u8; 256],
+    out: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    // The value of n digits needs at most n * ceil(log2(radix)) bits
+    let bits_per_digit = (radix - 1).ilog2() as usize + 1;
+    let limb_count = ((input.len() - start) * bits_per_digit).div_ceil(32);
+
+    let result;
+    if limb_count <= SMALL_LIMBS {
+        let mut limbs = [0u32; SMALL_LIMBS];
+        result = decode_limbs(input, start, radix, decode_table, &mut limbs)
+            .map(|len| limbs_to_bytes(&limbs[..len], out));
+        wipe_slice(&mut limbs);
+    } else {
+        let mut limbs = vec![0u32; limb_count];
+        result = decode_limbs(input, start, radix, decode_table, &mut limbs)
+            .map(|len| limbs_to_bytes(&limbs[..len], out));
+        wipe(&mut limbs);
+    }
+    result
+}
+
+/// Accumulates the digits of `input[start..]` into little-endian `limbs` and returns how
+/// many limbs are in use
+fn decode_limbs(
+    input: &[u8],
+    start: usize,
+    radix: usize,
+    decode_table: &[u8; 256],
+    limbs: &mut [u32],
+) -> Result<usize, DecodeError> {
+    let radix = radix as u32;
+    let mut len = 0;
+    let mut push_group = |multiplier: u32, value: u32| {
+        let carry = mul_add_slice(&mut limbs[..len], multiplier, value);
+        if carry > 0 {
+            limbs[len] = carry;
+            len += 1;
+        }
+    };
+
+    // Digits are combined into groups as large as fit in a limb, and each group is
+    // folded into the limbs with a single multiplication by a power of the radix
+    let (power, digits_per_group) = radix_power(radix);
+    let mut group = 0;
+    let mut group_len = 0;
+
+    let mut digits = [0u8; LOOKUP_CHUNK_LEN];
+    for (n, chunk) in input[start..].chunks(LOOKUP_CHUNK_LEN).enumerate() {
+        let digits = &mut digits[..chunk.len()];
+        if let Err(i) = chars_to_digits(chunk, decode_table, digits) {
+            return Err(invalid_character(input, start + n * LOOKUP_CHUNK_LEN + i));
+        }
+
+        for &digit in digits.iter() {
+            group = group * radix + digit as u32;
+            group_len += 1;
+            if group_len == digits_per_group {
+                push_group(power, group);
+                group = 0;
+                group_len = 0;
+            }
+        }
+    }
+    if group_len > 0 {
+        push_group(radix.pow(group_len as u32), group);
+    }
+    Ok(len)
+}
+
+/// Builds the error for the invalid byte at `position`, reporting the whole character
+/// when the input is UTF-8 there
+fn invalid_character(input: &[u8], position: usize) -> DecodeError {
+    let rest = &input[position..input.len().min(position + 4)];
+    let character = String::from_utf8_lossy(rest)
+        .chars()
+        .next()
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+    DecodeError::InvalidCharacter {
+        character,
+        position,
+    }
+}
+
+/// Multiplies little-endian limbs by `multiplier` and adds `addend`
+pub(crate) fn mul_add(limbs: &mut Vec<u32>, multiplier: u32, addend: u32) {
+    let carry = mul_add_slice(limbs, multiplier, addend);
+    if carry > 0 {
+        limbs.push(carry);
+    }
+}
+
+/// Multiplies little-endian limbs by `multiplier` and adds `addend`, returning the carry
+/// out of the top limb
+fn mul_add_slice(limbs: &mut [u32], multiplier: u32, addend: u32) -> u32 {
+    let mut carry = addend as u64;
+
+    for limb in limbs.iter_mut() {
+        let temp = *limb as u64 * multiplier as u64 + carry;
+        *limb = temp as u32;
+        carry = temp >> 32;
+    }
+
+    carry as u32
+}
+
+/// Appends little-endian limbs to `out` as big-endian bytes without leading zeros
+pub(crate) fn limbs_to_bytes(limbs: &[u32], out: &mut Vec<u8>) {
+    let Some((&top, rest)) = limbs.split_last() else {
+        return;
+    };
+    let top = top.to_be_bytes();
+    let skip = top.iter().take_while(|&&b| b == 0).count();
+    out.extend_from_slice(&top[skip..]);
+    for limb in rest.iter().rev() {
+        out.extend_from_slice(&limb.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58_matches_crate_encoding() {
+        for input in [&b""[..], &[0, 0, 1], b"Hello, World!", &[0xFF; 40]] {
+            let encoded = BASE58.encode(input);
+            assert_eq!(encoded, crate::encode(input));
+            assert_eq!(BASE58.decode(&encoded).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(BASE36.encode(b"hello"), "5pzcszu7");
+        assert_eq!(BASE62.encode(b"Hello"), "5TP3P3v");
+        assert_eq!(BASE62.encode(&[0, 0, 255]), "0047");
+        assert_eq!(BASE45.encode(&[0xFF, 0xFF]), "WGF");
+    }
+
+    #[test]
+    fn test_roundtrip_all_codecs() {
+        let input: Vec<u8> = (0..100).map(|i| (i * 37 + 11) as u8).collect();
+        for codec in [BASE36, BASE45, BASE58, BASE62] {
+            let encoded = codec.encode(&input);
+            assert!(encoded.bytes().all(|b| codec.alphabet().contains(&b)));
+            assert_eq!(codec.decode(&encoded).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_limb_boundaries() {
+        // Binary output is easy to check by hand at every limb boundary
+        let binary = BaseN::new(b"01").unwrap();
+        for len in 1..=13 {
+            let input: Vec<u8> = (0..len).map(|i| 0x80 | (i * 7) as u8).collect();
+            let expected: String = input.iter().map(|b| format!("{b:08b}")).collect();
+            assert_eq!(binary.encode(&input), expected, "length {len}");
+            assert_eq!(binary.decode(&expected).unwrap(), input);
+            for codec in [BASE36, BASE58, BASE62] {
+                assert_eq!(codec.decode(&codec.encode(&input)).unwrap(), input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix_power() {
+        assert_eq!(radix_power(58), (656_356_768, 5));
+        assert_eq!(radix_power(2), (1 << 31, 31));
+        assert_eq!(radix_power(62), (62u32.pow(5), 5));
+
+        // Zero digits in the middle of the value must be kept
+        let input = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(BASE36.decode(&BASE36.encode(&input)).unwrap(), input);
+    }
+
+    #[test]
+    fn test_capacity_bounds() {
+        let binary = BaseN::new(b"01").unwrap();
+        for len in 1..300 {
+            for fill in [0x01, 0xFF] {
+                let input = vec![fill; len];
+                for codec in [binary, BASE36, BASE45, BASE58, BASE62] {
+                    let encoded = codec.encode(&input);
+                    assert!(encoded.len() <= max_encoded_digits(len, codec.radix()));
+                    assert!(len <= max_decoded_bytes(encoded.len(), codec.radix()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stack_buffer_threshold() {
+        let binary = BaseN::new(b"01").unwrap();
+        for len in SMALL_INPUT_LEN - 4..=SMALL_INPUT_LEN + 4 {
+            for fill in [0x01, 0xFF] {
+                let input = vec![fill; len];
+                for codec in [binary, BASE36, BASE58, BASE62] {
+                    let encoded = codec.encode(&input);
+                    assert_eq!(codec.decode(&encoded).unwrap(), input, "length {len}");
+                }
+                assert_eq!(BASE58.encode(&input), crate::encode(&input));
+            }
+        }
+
+        // The largest Base58 strings that still decode on the stack
+        let encoded = "z".repeat(106);
+        assert_eq!(BASE58.encode(&BASE58.decode(&encoded).unwrap()), encoded);
+    }
+
+    #[test]
+    fn test_invalid_alphabets_and_input() {
+        assert_eq!(BaseN::new(b"0"), Err(AlphabetError::InvalidLength(1)));
+        assert_eq!(
+            BaseN::new(b"0120"),
+            Err(AlphabetError::DuplicateCharacter('0'))
+        );
+        assert_eq!(
+            BASE36.decode("abcA"),
+            Err(DecodeError::InvalidCharacter {
+                character: 'A',
+                position: 3
+            })
+        );
+    }
+}
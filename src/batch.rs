@@ -0,0 +1,229 @@
+//! Encoding and decoding many inputs in one call.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Alphabet, DecodeError, decode_into, encode_into};
+
+/// Encodes each input as Base58 using the default Bitcoin alphabet.
+///
+/// Every input is encoded into one scratch buffer that is reused across the batch, and
+/// each result is then copied into a string of exactly its length. The buffer only grows
+/// a few times per batch rather than once per item, and the results carry no spare
+/// capacity.
+///
+/// # Arguments
+///
+/// * `inputs` - The byte slices to encode
+///
+/// # Returns
+///
+/// The encoded strings, in the same order as `inputs`
+///
+/// # Examples
+///
+/// ```
+/// use b58::encode_batch;
+///
+/// let ids = [b"Hello".to_vec(), vec![0, 1]];
+/// assert_eq!(encode_batch(&ids), ["9Ajdvzr", "12"]);
+/// ```
+pub fn encode_batch<T: AsRef<[u8]>>(inputs: &[T]) -> Vec<String> {
+    encode_batch_with_alphabet(inputs, Alphabet::Bitcoin)
+}
+
+/// Encodes each input as Base58 using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `inputs` - The byte slices to encode
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Returns
+///
+/// The encoded strings, in the same order as `inputs`
+pub fn encode_batch_with_alphabet<T: AsRef<[u8]>>(inputs: &[T], alphabet: Alphabet) -> Vec<String> {
+    let mut scratch = String::new();
+    let mut out = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        encode_into(input.as_ref(), &mut scratch, alphabet);
+        out.push(scratch.as_str().to_owned());
+    }
+    out
+}
+
+/// Decodes each Base58 string using the default Bitcoin alphabet.
+///
+/// Buffers are reused across the batch as in [`encode_batch`]. Decoding stops at the
+/// first invalid input.
+///
+/// # Arguments
+///
+/// * `inputs` - The Base58 strings to decode
+///
+/// # Returns
+///
+/// The decoded bytes in the same order as `inputs`, or the index of the first input that
+/// failed to decode along with its `DecodeError`
+///
+/// # Examples
+///
+/// ```
+/// use b58::{DecodeError, decode_batch};
+///
+/// assert_eq!(decode_batch(&["9Ajdvzr", "12"]).unwrap(), [b"Hello".to_vec(), vec![0, 1]]);
+///
+/// let (index, err) = decode_batch(&["9Ajdvzr", "0"]).unwrap_err();
+/// assert_eq!(index, 1);
+/// assert!(matches!(err, DecodeError::InvalidCharacter { character: '0', .. }));
+/// ```
+pub fn decode_batch<S: AsRef<str>>(inputs: &[S]) -> Result<Vec<Vec<u8>>, (usize, DecodeError)> {
+    decode_batch_with_alphabet(inputs, Alphabet::Bitcoin)
+}
+
+/// Decodes each Base58 string using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `inputs` - The Base58 strings to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes, or the index and error of the first input that failed to decode
+pub fn decode_batch_with_alphabet<S: AsRef<str>>(
+    inputs: &[S],
+    alphabet: Alphabet,
+) -> Result<Vec<Vec<u8>>, (usize, DecodeError)> {
+    let mut scratch = Vec::new();
+    let mut out = Vec::with_capacity(inputs.len());
+    for (i, input) in inputs.iter().enumerate() {
+        decode_into(input.as_ref(), &mut scratch, alphabet).map_err(|e| (i, e))?;
+        out.push(scratch.clone());
+    }
+    crate::wipe(&mut scratch);
+    Ok(out)
+}
+
+/// Encodes each input as Base58 on the rayon thread pool using the default Bitcoin
+/// alphabet.
+///
+/// Each worker thread reuses its own scratch buffer. The results are in the same order as
+/// `inputs`, exactly as returned by [`encode_batch`].
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_batch, par_encode_batch};
+///
+/// let keys: Vec<[u8; 32]> = (0..1000u32).map(|i| [i as u8; 32]).collect();
+/// assert_eq!(par_encode_batch(&keys), encode_batch(&keys));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_encode_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<String> {
+    par_encode_batch_with_alphabet(inputs, Alphabet::Bitcoin)
+}
+
+/// Encodes each input as Base58 on the rayon thread pool using the specified alphabet.
+#[cfg(feature = "rayon")]
+pub fn par_encode_batch_with_alphabet<T: AsRef<[u8]> + Sync>(
+    inputs: &[T],
+    alphabet: Alphabet,
+) -> Vec<String> {
+    inputs
+        .par_iter()
+        .map_init(String::new, |scratch, input| {
+            encode_into(input.as_ref(), scratch, alphabet);
+            scratch.as_str().to_owned()
+        })
+        .collect()
+}
+
+/// Decodes each Base58 string on the rayon thread pool using the default Bitcoin
+/// alphabet.
+///
+/// The result is the same as [`decode_batch`], including which error is reported: if
+/// several inputs are invalid, the one with the lowest index is returned, regardless of
+/// which thread finished first.
+#[cfg(feature = "rayon")]
+pub fn par_decode_batch<S: AsRef<str> + Sync>(
+    inputs: &[S],
+) -> Result<Vec<Vec<u8>>, (usize, DecodeError)> {
+    par_decode_batch_with_alphabet(inputs, Alphabet::Bitcoin)
+}
+
+/// Decodes each Base58 string on the rayon thread pool using the specified alphabet.
+#[cfg(feature = "rayon")]
+pub fn par_decode_batch_with_alphabet<S: AsRef<str> + Sync>(
+    inputs: &[S],
+    alphabet: Alphabet,
+) -> Result<Vec<Vec<u8>>, (usize, DecodeError)> {
+    let results: Vec<Result<Vec<u8>, DecodeError>> = inputs
+        .par_iter()
+        .map_init(Vec::new, |scratch, input| {
+            decode_into(input.as_ref(), scratch, alphabet)?;
+            Ok(scratch.clone())
+        })
+        .collect();
+
+    // Collecting in order picks the first error by index
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, result)| result.map_err(|e| (i, e)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_single_item_functions() {
+        let inputs: Vec<Vec<u8>> = (0..50u8)
+            .map(|n| (0..n).map(|i| i.wrapping_mul(n)).collect())
+            .collect();
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let encoded = encode_batch_with_alphabet(&inputs, alphabet);
+            for (input, encoded) in inputs.iter().zip(&encoded) {
+                assert_eq!(*encoded, crate::encode_with_alphabet(input, alphabet));
+            }
+            assert_eq!(
+                decode_batch_with_alphabet(&encoded, alphabet).unwrap(),
+                inputs
+            );
+        }
+        assert!(encode_batch::<&[u8]>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_decode_error_index() {
+        assert_eq!(
+            decode_batch(&["1", "2", "3l", "0"]),
+            Err((
+                2,
+                DecodeError::InvalidCharacter {
+                    character: 'l',
+                    position: 1
+                }
+            ))
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let inputs: Vec<Vec<u8>> = (0..2000u32)
+            .map(|n| n.to_be_bytes().repeat(n as usize % 9))
+            .collect();
+        let encoded = par_encode_batch(&inputs);
+        assert_eq!(encoded, encode_batch(&inputs));
+        assert_eq!(par_decode_batch(&encoded).unwrap(), inputs);
+
+        let mut invalid = encoded.clone();
+        invalid[1500].push('0');
+        invalid[700].push('I');
+        assert_eq!(par_decode_batch(&invalid), decode_batch(&invalid));
+        assert_eq!(par_decode_batch(&invalid).unwrap_err().0, 700);
+    }
+}
@@ -0,0 +1,350 @@
+//! BIP32 extended keys (`xpub`, `xprv`, `tpub`, `tprv`).
+//!
+//! An extended key is the Base58Check encoding of a 78-byte payload: a 4-byte version,
+//! the depth, the parent key's fingerprint, the child number, the 32-byte chain code,
+//! and 33 bytes of key material (a compressed public key, or `0x00` followed by the
+//! private key).
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::bip32::{ExtendedKey, KeyKind};
+//! use b58::wif::Network;
+//!
+//! let xpub: ExtendedKey = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(xpub.kind, KeyKind::Public);
+//! assert_eq!(xpub.network, Network::Mainnet);
+//! assert_eq!(xpub.depth, 0);
+//! ```
+
+use crate::wif::Network;
+use crate::{DecodeError, decode_check, encode_check};
+
+/// Version bytes of mainnet extended public keys (`xpub`).
+pub const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+/// Version bytes of mainnet extended private keys (`xprv`).
+pub const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+/// Version bytes of testnet extended public keys (`tpub`).
+pub const TPUB_VERSION: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+/// Version bytes of testnet extended private keys (`tprv`).
+pub const TPRV_VERSION: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+
+/// Length in bytes of a serialized extended key, before the checksum.
+pub const PAYLOAD_LEN: usize = 78;
+
+/// Whether an extended key holds public or private key material.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    /// An extended public key.
+    Public,
+    /// An extended private key.
+    Private,
+}
+
+/// A parsed BIP32 extended key.
+///
+/// The `Debug` output of a private key leaves out its key material and chain code,
+/// which together are enough to derive every child key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedKey {
+    /// The network the key belongs to.
+    pub network: Network,
+    /// Whether the key is public or private.
+    pub kind: KeyKind,
+    /// The number of derivation steps from the master key.
+    pub depth: u8,
+    /// The first four bytes of the parent key's hash160, or zero for the master key.
+    pub parent_fingerprint: [u8; 4],
+    /// The index of this key among its parent's children; hardened indices have the
+    /// top bit set.
+    pub child_number: u32,
+    /// The chain code.
+    pub chain_code: [u8; 32],
+    /// The compressed public key, or `0x00` followed by the 32-byte private key.
+    pub key: [u8; 33],
+}
+
+impl ExtendedKey {
+    /// Returns the version bytes for this key's kind and network.
+    pub fn version(&self) -> [u8; 4] {
+        match (self.network, self.kind) {
+            (Network::Mainnet, KeyKind::Public) => XPUB_VERSION,
+            (Network::Mainnet, KeyKind::Private) => XPRV_VERSION,
+            (Network::Testnet, KeyKind::Public) => TPUB_VERSION,
+            (Network::Testnet, KeyKind::Private) => TPRV_VERSION,
+        }
+    }
+
+    /// Serializes the key into its 78-byte payload.
+    pub fn to_bytes(&self) -> [u8; PAYLOAD_LEN] {
+        let mut out = [0u8; PAYLOAD_LEN];
+        out[0..4].copy_from_slice(&self.version());
+        out[4] = self.depth;
+        out[5..9].copy_from_slice(&self.parent_fingerprint);
+        out[9..13].copy_from_slice(&self.child_number.to_be_bytes());
+        out[13..45].copy_from_slice(&self.chain_code);
+        out[45..78].copy_from_slice(&self.key);
+        out
+    }
+
+    /// Parses and validates a 78-byte extended key payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The payload, without the Base58Check checksum
+    ///
+    /// # Returns
+    ///
+    /// The extended key, or an `ExtendedKeyError` if the version or key material is
+    /// invalid
+    pub fn from_bytes(bytes: &[u8; PAYLOAD_LEN]) -> Result<Self, ExtendedKeyError> {
+        let version = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        let (network, kind) = match version {
+            XPUB_VERSION => (Network::Mainnet, KeyKind::Public),
+            XPRV_VERSION => (Network::Mainnet, KeyKind::Private),
+            TPUB_VERSION => (Network::Testnet, KeyKind::Public),
+            TPRV_VERSION => (Network::Testnet, KeyKind::Private),
+            _ => return Err(ExtendedKeyError::InvalidVersion(version)),
+        };
+
+        let key_prefix = bytes[45];
+        let valid_prefix = match kind {
+            KeyKind::Public => key_prefix == 0x02 || key_prefix == 0x03,
+            KeyKind::Private => key_prefix == 0x00,
+        };
+        if !valid_prefix {
+            return Err(ExtendedKeyError::InvalidKeyPrefix(key_prefix));
+        }
+
+        let mut key = ExtendedKey {
+            network,
+            kind,
+            depth: bytes[4],
+            parent_fingerprint: [0; 4],
+            child_number: u32::from_be_bytes([bytes[9], bytes[10], bytes[11], bytes[12]]),
+            chain_code: [0; 32],
+            key: [0; 33],
+        };
+        key.parent_fingerprint.copy_from_slice(&bytes[5..9]);
+        key.chain_code.copy_from_slice(&bytes[13..45]);
+        key.key.copy_from_slice(&bytes[45..78]);
+
+        if key.depth == 0 && (key.parent_fingerprint != [0; 4] || key.child_number != 0) {
+            return Err(ExtendedKeyError::InvalidMasterKey);
+        }
+
+        Ok(key)
+    }
+
+    /// Encodes the key as a Base58Check string.
+    pub fn encode(&self) -> String {
+        encode_check(self.to_bytes())
+    }
+}
+
+impl core::fmt::Debug for ExtendedKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("ExtendedKey");
+        debug
+            .field("network", &self.network)
+            .field("kind", &self.kind)
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &self.parent_fingerprint)
+            .field("child_number", &self.child_number);
+        match self.kind {
+            KeyKind::Public => debug
+                .field("chain_code", &self.chain_code)
+                .field("key", &self.key),
+            KeyKind::Private => debug
+                .field("chain_code", &"<redacted>")
+                .field("key", &"<redacted>"),
+        }
+        .finish()
+    }
+}
+
+impl core::fmt::Display for ExtendedKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl core::str::FromStr for ExtendedKey {
+    type Err = ExtendedKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// Error type for extended key parsing failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtendedKeyError {
+    /// The string is not valid Base58Check.
+    Decode(DecodeError),
+    /// The payload is not 78 bytes long.
+    InvalidLength(usize),
+    /// The version bytes are not a known `xpub`/`xprv`/`tpub`/`tprv` version.
+    InvalidVersion([u8; 4]),
+    /// The first byte of the key material does not match the key kind.
+    InvalidKeyPrefix(u8),
+    /// A depth-0 key has a non-zero parent fingerprint or child number.
+    InvalidMasterKey,
+}
+
+impl core::fmt::Display for ExtendedKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExtendedKeyError::Decode(e) => write!(f, "Invalid extended key encoding: {e}"),
+            ExtendedKeyError::InvalidLength(len) => {
+                write!(f, "Invalid extended key payload length: {len}")
+            }
+            ExtendedKeyError::InvalidVersion(v) => write!(
+                f,
+                "Unknown extended key version: {:02x}{:02x}{:02x}{:02x}",
+                v[0], v[1], v[2], v[3]
+            ),
+            ExtendedKeyError::InvalidKeyPrefix(b) => {
+                write!(f, "Invalid extended key material prefix: 0x{b:02x}")
+            }
+            ExtendedKeyError::InvalidMasterKey => {
+                write!(
+                    f,
+                    "Master key has a non-zero parent fingerprint or child number"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ExtendedKeyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ExtendedKeyError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for ExtendedKeyError {
+    fn from(e: DecodeError) -> Self {
+        ExtendedKeyError::Decode(e)
+    }
+}
+
+/// Parses and validates a Base58Check encoded extended key.
+///
+/// # Arguments
+///
+/// * `input` - The extended key string, such as `xpub...`
+///
+/// # Returns
+///
+/// The extended key, or an `ExtendedKeyError` if the checksum, length, version or key
+/// material is invalid
+pub fn parse(input: &str) -> Result<ExtendedKey, ExtendedKeyError> {
+    let payload = decode_check(input)?;
+    let bytes: &[u8; PAYLOAD_LEN] = payload
+        .as_slice()
+        .try_into()
+        .map_err(|_| ExtendedKeyError::InvalidLength(payload.len()))?;
+    ExtendedKey::from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    // BIP32 test vector 1, chain m
+    const XPUB: &str = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+    const XPRV: &str = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+
+    #[test]
+    fn test_parse_xpub() {
+        let key = parse(XPUB).unwrap();
+        assert_eq!(key.network, Network::Mainnet);
+        assert_eq!(key.kind, KeyKind::Public);
+        assert_eq!(key.depth, 0);
+        assert_eq!(key.parent_fingerprint, [0; 4]);
+        assert_eq!(key.child_number, 0);
+        assert_eq!(
+            hex(&key.chain_code),
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        );
+        assert_eq!(
+            hex(&key.key),
+            "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2"
+        );
+        assert_eq!(key.to_string(), XPUB);
+    }
+
+    #[test]
+    fn test_parse_xprv() {
+        let key: ExtendedKey = XPRV.parse().unwrap();
+        assert_eq!(key.kind, KeyKind::Private);
+        assert_eq!(
+            hex(&key.key),
+            "00e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+        );
+        assert_eq!(key.encode(), XPRV);
+    }
+
+    #[test]
+    fn test_debug_redacts_private_keys() {
+        let xprv = parse(XPRV).unwrap();
+        assert_eq!(
+            format!("{xprv:?}"),
+            "ExtendedKey { network: Mainnet, kind: Private, depth: 0, \
+             parent_fingerprint: [0, 0, 0, 0], child_number: 0, \
+             chain_code: \"<redacted>\", key: \"<redacted>\" }"
+        );
+
+        let xpub = parse(XPUB).unwrap();
+        assert!(format!("{xpub:?}").contains(&format!("key: {:?}", xpub.key)));
+    }
+
+    #[test]
+    fn test_testnet_roundtrip() {
+        let mut key = parse(XPRV).unwrap();
+        key.network = Network::Testnet;
+        key.depth = 3;
+        key.parent_fingerprint = [1, 2, 3, 4];
+        key.child_number = 0x8000_0002;
+
+        let encoded = key.encode();
+        assert!(encoded.starts_with("tprv"));
+        assert_eq!(parse(&encoded).unwrap(), key);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut bytes = parse(XPUB).unwrap().to_bytes();
+
+        bytes[45] = 0x04;
+        assert_eq!(
+            parse(&encode_check(bytes)),
+            Err(ExtendedKeyError::InvalidKeyPrefix(0x04))
+        );
+
+        bytes[45] = 0x02;
+        bytes[5] = 1;
+        assert_eq!(
+            parse(&encode_check(bytes)),
+            Err(ExtendedKeyError::InvalidMasterKey)
+        );
+
+        bytes[0] = 0xFF;
+        assert_eq!(
+            parse(&encode_check(bytes)),
+            Err(ExtendedKeyError::InvalidVersion([0xFF, 0x88, 0xB2, 0x1E]))
+        );
+
+        assert_eq!(
+            parse(&encode_check(&bytes[..77])),
+            Err(ExtendedKeyError::InvalidLength(77))
+        );
+    }
+}
@@ -0,0 +1,452 @@
+//! Base58Check: Base58 with a 4-byte double SHA-256 checksum appended to the payload.
+//!
+//! Also provides CB58, Avalanche's variant that appends the last four bytes of a single
+//! SHA-256 instead.
+
+use crate::sha256::{double_sha256, sha256};
+use crate::specialized::{ENCODED_25, decode_fixed, encode_fixed};
+use crate::{
+    Alphabet, DecodeError, decode_slice_with_alphabet, decode_with_alphabet, encode_with_alphabet,
+    wipe, wipe_slice,
+};
+
+/// Length in bytes of the Base58Check checksum.
+pub const CHECKSUM_LEN: usize = 4;
+/// Length of an address payload: a version byte and a 20-byte hash
+const ADDRESS_PAYLOAD_LEN: usize = 21;
+
+/// Encodes a payload as Base58Check using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `payload` - The bytes to encode, including any version prefix
+///
+/// # Returns
+///
+/// A Base58 encoded string of the payload followed by its checksum
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_check, encode_check};
+///
+/// let encoded = encode_check(b"Hello");
+/// assert_eq!(decode_check(&encoded).unwrap(), b"Hello");
+/// ```
+pub fn encode_check<T: AsRef<[u8]>>(payload: T) -> String {
+    encode_check_with_alphabet(payload, Alphabet::Bitcoin)
+}
+
+/// Encodes a payload as Base58Check using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `payload` - The bytes to encode, including any version prefix
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Returns
+///
+/// A Base58 encoded string of the payload followed by its checksum
+pub fn encode_check_with_alphabet<T: AsRef<[u8]>>(payload: T, alphabet: Alphabet) -> String {
+    let payload = payload.as_ref();
+
+    // Addresses take a conversion specialized for their length
+    if payload.len() == ADDRESS_PAYLOAD_LEN {
+        let mut data = [0u8; ADDRESS_PAYLOAD_LEN + CHECKSUM_LEN];
+        data[..ADDRESS_PAYLOAD_LEN].copy_from_slice(payload);
+        data[ADDRESS_PAYLOAD_LEN..].copy_from_slice(&checksum(payload));
+        let mut buf = [0u8; ENCODED_25];
+        let start = encode_fixed::<25, 7, ENCODED_25>(&data, alphabet, &mut buf);
+        wipe_slice(&mut data);
+        return buf[start..].iter().map(|&b| b as char).collect();
+    }
+
+    // The payload may be a private key, as in WIF, so the copy is wiped
+    let mut data = Vec::with_capacity(payload.len() + CHECKSUM_LEN);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&checksum(payload));
+    let encoded = encode_with_alphabet(&data, alphabet);
+    wipe(&mut data);
+    encoded
+}
+
+/// Decodes a Base58Check string using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58Check string to decode
+///
+/// # Returns
+///
+/// The payload with the checksum verified and removed, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_check, DecodeError};
+///
+/// let payload = decode_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+/// assert_eq!(payload.len(), 21);
+/// assert_eq!(decode_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"), Err(DecodeError::ChecksumMismatch));
+/// ```
+pub fn decode_check(input: &str) -> Result<Vec<u8>, DecodeError> {
+    decode_check_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58Check string using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58Check string to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The payload with the checksum verified and removed, or a `DecodeError` on failure
+pub fn decode_check_with_alphabet(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    // Addresses take a conversion specialized for their length, and anything else,
+    // including invalid input, the general one
+    if let Some(data) = decode_fixed::<25, 7>(input, alphabet) {
+        let (payload, check) = data.split_at(ADDRESS_PAYLOAD_LEN);
+        if check != checksum(payload) {
+            return Err(DecodeError::ChecksumMismatch);
+        }
+        return Ok(payload.to_vec());
+    }
+
+    let mut data = decode_with_alphabet(input, alphabet)?;
+    if data.len() < CHECKSUM_LEN {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    let payload_len = data.len() - CHECKSUM_LEN;
+    if data[payload_len..] != checksum(&data[..payload_len]) {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+/// Checks a Base58Check string's characters and checksum using the default Bitcoin
+/// alphabet.
+///
+/// The string must still be decoded to verify the checksum, but strings that decode to
+/// at most 128 bytes, which covers addresses and extended keys, are decoded on the stack
+/// without allocating.
+///
+/// # Arguments
+///
+/// * `input` - The Base58Check string to check
+///
+/// # Returns
+///
+/// `Ok(())`, or the same `DecodeError` that [`decode_check`] would return
+///
+/// # Examples
+///
+/// ```
+/// use b58::{validate_check, DecodeError};
+///
+/// assert_eq!(validate_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"), Ok(()));
+/// assert_eq!(
+///     validate_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"),
+///     Err(DecodeError::ChecksumMismatch)
+/// );
+/// ```
+pub fn validate_check(input: &str) -> Result<(), DecodeError> {
+    validate_check_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Checks a Base58Check string's characters and checksum using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58Check string to check
+/// * `alphabet` - The alphabet the string should use
+///
+/// # Returns
+///
+/// `Ok(())`, or the same `DecodeError` that [`decode_check_with_alphabet`] would return
+pub fn validate_check_with_alphabet(input: &str, alphabet: Alphabet) -> Result<(), DecodeError> {
+    let mut buf = [0u8; 128];
+    let data = match decode_slice_with_alphabet(input, &mut buf, alphabet) {
+        Ok(len) => &buf[..len],
+        Err(DecodeError::BufferTooSmall) => {
+            return decode_check_with_alphabet(input, alphabet).map(drop);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let Some(payload_len) = data.len().checked_sub(CHECKSUM_LEN) else {
+        return Err(DecodeError::ChecksumMismatch);
+    };
+    if data[payload_len..] != checksum(&data[..payload_len]) {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+    Ok(())
+}
+
+/// Encodes a version byte and payload as Base58Check using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `version` - The version byte, such as one of the constants in [`crate::version`]
+/// * `payload` - The bytes following the version byte
+///
+/// # Returns
+///
+/// A Base58 encoded string of the version, payload and checksum
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_versioned, version};
+///
+/// let hash160 = [
+///     0x62, 0xe9, 0x07, 0xb1, 0x5c, 0xbf, 0x27, 0xd5, 0x42, 0x53, 0x99, 0xeb, 0xf6, 0xf0,
+///     0xfb, 0x50, 0xeb, 0xb8, 0x8f, 0x18,
+/// ];
+/// let address = encode_versioned(version::BITCOIN_P2PKH, &hash160);
+/// assert_eq!(address, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+/// ```
+pub fn encode_versioned(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 1);
+    data.push(version);
+    data.extend_from_slice(payload);
+    encode_check(&data)
+}
+
+/// Decodes a Base58Check string into its version byte and payload.
+///
+/// # Arguments
+///
+/// * `input` - The Base58Check string to decode
+///
+/// # Returns
+///
+/// The version byte and the payload following it, or a `DecodeError` if the checksum is
+/// invalid or there is no version byte
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_versioned, version};
+///
+/// let (v, hash160) = decode_versioned("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").unwrap();
+/// assert_eq!(v, version::BITCOIN_P2SH);
+/// assert_eq!(hash160.len(), 20);
+/// ```
+pub fn decode_versioned(input: &str) -> Result<(u8, Vec<u8>), DecodeError> {
+    let mut data = decode_check(input)?;
+    if data.is_empty() {
+        return Err(DecodeError::InvalidLength {
+            expected: 1,
+            actual: 0,
+        });
+    }
+    let version = data.remove(0);
+    Ok((version, data))
+}
+
+/// Encodes a payload as CB58, the Avalanche variant of Base58Check.
+///
+/// CB58 uses the Bitcoin alphabet and appends the last four bytes of the single
+/// SHA-256 of the payload.
+///
+/// # Arguments
+///
+/// * `payload` - The bytes to encode, such as an Avalanche ID or private key
+///
+/// # Returns
+///
+/// A Base58 encoded string of the payload followed by its checksum
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_cb58, encode_cb58};
+///
+/// let encoded = encode_cb58(b"Hello");
+/// assert_eq!(encoded, "vSxRbq4dWoKv");
+/// assert_eq!(decode_cb58(&encoded).unwrap(), b"Hello");
+/// ```
+pub fn encode_cb58<T: AsRef<[u8]>>(payload: T) -> String {
+    let payload = payload.as_ref();
+    let mut data = Vec::with_capacity(payload.len() + CHECKSUM_LEN);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&cb58_checksum(payload));
+    encode_with_alphabet(&data, Alphabet::Bitcoin)
+}
+
+/// Decodes a CB58 string.
+///
+/// # Arguments
+///
+/// * `input` - The CB58 string to decode
+///
+/// # Returns
+///
+/// The payload with the checksum verified and removed, or a `DecodeError` on failure
+pub fn decode_cb58(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut data = decode_with_alphabet(input, Alphabet::Bitcoin)?;
+    if data.len() < CHECKSUM_LEN {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    let payload_len = data.len() - CHECKSUM_LEN;
+    if data[payload_len..] != cb58_checksum(&data[..payload_len]) {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+/// Returns the first four bytes of the double SHA-256 of `payload`.
+pub(crate) fn checksum(payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = double_sha256(payload);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Returns the last four bytes of the SHA-256 of `payload`.
+fn cb58_checksum(payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = sha256(payload);
+    [hash[28], hash[29], hash[30], hash[31]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIF_PAYLOAD: [u8; 33] = [
+        0x80, 0x0C, 0x28, 0xFC, 0xA3, 0x86, 0xC7, 0xA2, 0x27, 0x60, 0x0B, 0x2F, 0xE5, 0x0B, 0x7C,
+        0xAE, 0x11, 0xEC, 0x86, 0xD3, 0xBF, 0x1F, 0xBE, 0x47, 0x1B, 0xE8, 0x98, 0x27, 0xE1, 0x9D,
+        0x72, 0xAA, 0x1D,
+    ];
+
+    #[test]
+    fn test_encode_check_known_vector() {
+        assert_eq!(
+            encode_check(WIF_PAYLOAD),
+            "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"
+        );
+    }
+
+    #[test]
+    fn test_decode_check_known_vector() {
+        assert_eq!(
+            decode_check("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap(),
+            WIF_PAYLOAD
+        );
+    }
+
+    #[test]
+    fn test_decode_check_rejects_bad_checksum() {
+        assert_eq!(
+            decode_check("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK"),
+            Err(DecodeError::ChecksumMismatch)
+        );
+        assert_eq!(decode_check("111"), Err(DecodeError::ChecksumMismatch));
+        assert_eq!(decode_check(""), Err(DecodeError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn test_check_roundtrip_with_alphabet() {
+        for payload in [&b""[..], &[0, 0, 1][..], &b"Hello, World!"[..]] {
+            let encoded = encode_check_with_alphabet(payload, Alphabet::Ripple);
+            let decoded = decode_check_with_alphabet(&encoded, Alphabet::Ripple).unwrap();
+            assert_eq!(decoded, payload);
+        }
+    }
+
+    #[test]
+    fn test_address_payloads_match_general_conversion() {
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple] {
+            for zeros in 0..=ADDRESS_PAYLOAD_LEN {
+                let mut payload = [0xA7u8; ADDRESS_PAYLOAD_LEN];
+                payload[..zeros].fill(0);
+                let mut data = payload.to_vec();
+                data.extend_from_slice(&checksum(&payload));
+
+                let encoded = encode_check_with_alphabet(payload, alphabet);
+                assert_eq!(encoded, encode_with_alphabet(&data, alphabet));
+                assert_eq!(
+                    decode_check_with_alphabet(&encoded, alphabet).unwrap(),
+                    payload
+                );
+            }
+        }
+
+        let mut corrupted = encode_with_alphabet([0x42; 25], Alphabet::Bitcoin);
+        assert_eq!(decode_check(&corrupted), Err(DecodeError::ChecksumMismatch));
+        corrupted.push('0');
+        assert!(matches!(
+            decode_check(&corrupted),
+            Err(DecodeError::InvalidCharacter { character: '0', .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_check_matches_decode_check() {
+        let long = encode_check([0xAB; 200]);
+        let cases = [
+            "",
+            "1",
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0",
+            &long,
+            &long[1..],
+        ];
+        for input in cases {
+            assert_eq!(
+                validate_check(input),
+                decode_check(input).map(drop),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cb58_known_vector() {
+        // The AVAX asset ID on the Avalanche mainnet X-Chain
+        let asset_id = [
+            0x21, 0xe6, 0x73, 0x17, 0xcb, 0xc4, 0xbe, 0x2a, 0xeb, 0x00, 0x67, 0x7a, 0xd6, 0x46,
+            0x27, 0x78, 0xa8, 0xf5, 0x22, 0x74, 0xb9, 0xd6, 0x05, 0xdf, 0x25, 0x91, 0xb2, 0x30,
+            0x27, 0xa8, 0x7d, 0xff,
+        ];
+        let encoded = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+        assert_eq!(encode_cb58(asset_id), encoded);
+        assert_eq!(decode_cb58(encoded).unwrap(), asset_id);
+    }
+
+    #[test]
+    fn test_cb58_rejects_bad_checksum() {
+        assert_eq!(
+            decode_cb58("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5a"),
+            Err(DecodeError::ChecksumMismatch)
+        );
+        assert_eq!(decode_cb58("11"), Err(DecodeError::ChecksumMismatch));
+        // A Base58Check string is not valid CB58
+        assert_eq!(
+            decode_cb58(&encode_check(b"Hello")),
+            Err(DecodeError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_versioned_roundtrip() {
+        let (version, payload) = decode_versioned(&encode_versioned(0xEF, &[1, 2, 3])).unwrap();
+        assert_eq!(version, 0xEF);
+        assert_eq!(payload, [1, 2, 3]);
+
+        assert_eq!(
+            decode_versioned(&encode_check([])),
+            Err(DecodeError::InvalidLength {
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! IPFS CIDv0 validation.
+//!
+//! A version 0 CID is the plain Base58 encoding of a 34-byte SHA-256 multihash: the
+//! hash function code `0x12`, the digest length `0x20`, and the 32-byte digest. Every
+//! CIDv0 is therefore 46 characters long and starts with `Qm`.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::cid;
+//!
+//! assert!(cid::is_cidv0("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"));
+//! assert!(!cid::is_cidv0("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+//! ```
+
+use crate::{DecodeError, decode_array};
+
+/// Multihash function code for SHA-256.
+pub const SHA2_256_CODE: u8 = 0x12;
+/// Length in bytes of a SHA-256 digest.
+pub const SHA2_256_LEN: u8 = 0x20;
+/// Length in bytes of a CIDv0 multihash.
+pub const MULTIHASH_LEN: usize = 34;
+
+/// Error type for CIDv0 decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CidError {
+    /// The string is not valid Base58 or does not decode to 34 bytes.
+    Decode(DecodeError),
+    /// The multihash is not a 32-byte SHA-256 digest.
+    InvalidMultihash {
+        /// The hash function code that was found.
+        code: u8,
+        /// The digest length that was found.
+        length: u8,
+    },
+}
+
+impl core::fmt::Display for CidError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CidError::Decode(e) => write!(f, "Invalid CIDv0 encoding: {e}"),
+            CidError::InvalidMultihash { code, length } => write!(
+                f,
+                "Invalid CIDv0 multihash: expected sha2-256 (0x12, 32 bytes), got code 0x{code:02x} with length {length}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for CidError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CidError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for CidError {
+    fn from(e: DecodeError) -> Self {
+        CidError::Decode(e)
+    }
+}
+
+/// Returns whether `input` is a well-formed CIDv0.
+pub fn is_cidv0(input: &str) -> bool {
+    decode_v0(input).is_ok()
+}
+
+/// Decodes a CIDv0 into its 34-byte multihash.
+///
+/// # Arguments
+///
+/// * `input` - The CID string, such as `Qm...`
+///
+/// # Returns
+///
+/// The multihash including its `0x12 0x20` prefix, or a `CidError` if the string is not
+/// valid Base58, has the wrong length, or is not a SHA-256 multihash
+pub fn decode_v0(input: &str) -> Result<[u8; MULTIHASH_LEN], CidError> {
+    let multihash: [u8; MULTIHASH_LEN] = decode_array(input)?;
+    if multihash[0] != SHA2_256_CODE || multihash[1] != SHA2_256_LEN {
+        return Err(CidError::InvalidMultihash {
+            code: multihash[0],
+            length: multihash[1],
+        });
+    }
+    Ok(multihash)
+}
+
+/// Encodes a SHA-256 digest as a CIDv0.
+///
+/// # Arguments
+///
+/// * `digest` - The SHA-256 digest of the content
+///
+/// # Returns
+///
+/// The 46-character `Qm...` CID
+pub fn encode_v0(digest: &[u8; 32]) -> String {
+    let mut multihash = [0u8; MULTIHASH_LEN];
+    multihash[0] = SHA2_256_CODE;
+    multihash[1] = SHA2_256_LEN;
+    multihash[2..].copy_from_slice(digest);
+    crate::encode(multihash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CID: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+    const DIGEST: [u8; 32] = [
+        0x9d, 0x6c, 0x2b, 0xe5, 0x0f, 0x70, 0x69, 0x53, 0x47, 0x9a, 0xb9, 0xdf, 0x2c, 0xe3, 0xed,
+        0xca, 0x90, 0xb6, 0x80, 0x53, 0xc0, 0x0b, 0x30, 0x04, 0xb7, 0xf0, 0xac, 0xcb, 0xe1, 0xe8,
+        0xee, 0xdf,
+    ];
+
+    #[test]
+    fn test_decode_v0_known_vector() {
+        let multihash = decode_v0(CID).unwrap();
+        assert_eq!(multihash[..2], [0x12, 0x20]);
+        assert_eq!(multihash[2..], DIGEST);
+        assert_eq!(encode_v0(&DIGEST), CID);
+    }
+
+    #[test]
+    fn test_is_cidv0() {
+        assert!(is_cidv0(CID));
+        assert!(!is_cidv0(""));
+        assert!(!is_cidv0(&CID[..45]));
+        assert!(!is_cidv0("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0"));
+    }
+
+    #[test]
+    fn test_decode_v0_errors() {
+        assert_eq!(
+            decode_v0("QmfZy5bvk7a3DQAjCbGNtmrPXWkyVvPrdnZMyBZ5q5ieKH"),
+            Err(CidError::InvalidMultihash {
+                code: 0x12,
+                length: 0x21
+            })
+        );
+        assert_eq!(
+            decode_v0("9Ajdvzr"),
+            Err(CidError::Decode(DecodeError::InvalidLength {
+                expected: 34,
+                actual: 5
+            }))
+        );
+    }
+}
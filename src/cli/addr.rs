@@ -0,0 +1,206 @@
+//! `base58 addr`: legacy Bitcoin addresses.
+
+use b58::address::{self, AddressKind};
+use b58::encode_check;
+
+use super::{network_name, value_or_stdin};
+use crate::{from_hex, parse_byte, to_hex};
+
+pub const USAGE: &str = "\
+base58 addr - Legacy Bitcoin (P2PKH and P2SH) addresses
+
+USAGE:
+    base58 addr validate [ADDRESS]
+    base58 addr from-pubkey [OPTIONS] [HEX]
+
+With no ADDRESS or HEX, read it from standard input.
+
+COMMANDS:
+    validate       Check an address and print its network, type and hash160
+    from-pubkey    Hash a compressed or uncompressed public key given as hex and
+                   encode the hash160 as an address
+
+OPTIONS:
+    --version-byte <BYTE>    Version byte of the address [default: 0x00]
+    --testnet                Use the testnet P2PKH version byte, 0x6f
+
+EXAMPLES:
+    base58 addr validate 1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2
+    base58 addr from-pubkey 0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798
+    base58 addr from-pubkey --version-byte 0x30 < pubkey.hex
+";
+
+/// Arguments of `base58 addr`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Addr {
+    Validate { address: Option<String> },
+    FromPubkey { hex: Option<String>, version: u8 },
+}
+
+/// Parses the arguments after `addr`
+pub fn parse(args: &[String]) -> Result<Addr, String> {
+    match args {
+        [action, rest @ ..] if action == "validate" => match rest {
+            [] => Ok(Addr::Validate { address: None }),
+            [address] => Ok(Addr::Validate {
+                address: Some(address.clone()),
+            }),
+            _ => Err("addr validate takes one address".into()),
+        },
+        [action, rest @ ..] if action == "from-pubkey" => parse_from_pubkey(rest),
+        [action, ..] => Err(format!("Unknown addr command: {action}")),
+        [] => Err("addr requires a command (validate or from-pubkey)".into()),
+    }
+}
+
+/// Parses the arguments after `from-pubkey`
+fn parse_from_pubkey(args: &[String]) -> Result<Addr, String> {
+    let mut version = address::P2PKH_MAINNET_VERSION;
+    let mut values = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--version-byte" => {
+                let value = args.next().ok_or("--version-byte requires a value")?;
+                version = parse_byte(value)?;
+            }
+            "--testnet" => version = address::P2PKH_TESTNET_VERSION,
+            arg if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ => values.push(arg.clone()),
+        }
+    }
+    if values.len() > 1 {
+        return Err("addr from-pubkey takes one public key".into());
+    }
+    Ok(Addr::FromPubkey {
+        hex: values.pop(),
+        version,
+    })
+}
+
+impl Addr {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Addr::Validate { address } => validate(&value_or_stdin(address)?),
+            Addr::FromPubkey { hex, version } => {
+                from_pubkey(&from_hex(value_or_stdin(hex)?.as_bytes())?, *version)
+            }
+        }
+    }
+}
+
+/// Describes a valid address, or explains why it is invalid
+fn validate(input: &str) -> Result<String, String> {
+    let addr = address::parse(input).map_err(|e| e.to_string())?;
+    let kind = match addr.kind {
+        AddressKind::P2pkh => "P2PKH",
+        AddressKind::P2sh => "P2SH",
+    };
+    Ok(format!(
+        "address:  {input}\n\
+         network:  {}\n\
+         type:     {kind}\n\
+         version:  0x{:02x}\n\
+         hash160:  {}\n\
+         checksum: valid",
+        network_name(addr.network),
+        addr.version(),
+        to_hex(&addr.hash)
+    ))
+}
+
+/// Encodes the hash160 of a public key as an address with the given version byte
+fn from_pubkey(public_key: &[u8], version: u8) -> Result<String, String> {
+    match (public_key.len(), public_key.first()) {
+        (33, Some(0x02 | 0x03)) | (65, Some(0x04)) => {}
+        (33 | 65, _) => return Err("Public key has an invalid prefix byte".into()),
+        (len, _) => {
+            return Err(format!(
+                "Public key must be 33 bytes compressed or 65 bytes uncompressed, found {len}"
+            ));
+        }
+    }
+
+    let hash = address::hash160(public_key);
+    let mut payload = vec![version];
+    payload.extend_from_slice(&hash);
+    Ok(format!(
+        "address:  {}\n\
+         version:  0x{version:02x}\n\
+         hash160:  {}",
+        encode_check(payload),
+        to_hex(&hash)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let report = validate("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        assert!(report.contains("network:  mainnet\n"));
+        assert!(report.contains("type:     P2PKH\n"));
+        assert!(report.contains("hash160:  77bff20c60e522dfaa3350c39b030a5d004e839a\n"));
+
+        let report = validate("2MzQwSSnBHWHqSAqtTVQ6v47XtaisrJa1Vc").unwrap();
+        assert!(report.contains("network:  testnet\n"));
+        assert!(report.contains("type:     P2SH\n"));
+
+        let err = validate("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3").unwrap_err();
+        assert!(err.contains("Checksum"), "{err}");
+    }
+
+    #[test]
+    fn test_from_pubkey() {
+        let from_pubkey = |extra: &[&str]| {
+            let mut all = vec!["from-pubkey"];
+            all.extend_from_slice(extra);
+            parse(&args(&all)).and_then(|addr| addr.run())
+        };
+        let compressed = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let report = from_pubkey(&[compressed]).unwrap();
+        assert!(report.starts_with("address:  1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH\n"));
+        assert!(report.contains("version:  0x00\n"));
+        assert!(report.ends_with("hash160:  751e76e8199196d454941c45d1b3a323f1433bd6"));
+
+        let uncompressed = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+                            483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        let report = from_pubkey(&[uncompressed]).unwrap();
+        assert!(report.starts_with("address:  1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm\n"));
+
+        let report = from_pubkey(&["--testnet", compressed]).unwrap();
+        assert!(report.starts_with("address:  mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r\n"));
+        let report = from_pubkey(&["--version-byte", "0x30", compressed]).unwrap();
+        assert!(report.contains("version:  0x30\n"));
+        let address = report.lines().next().unwrap().rsplit(' ').next().unwrap();
+        assert_eq!(b58::decode_check(address).unwrap()[0], 0x30);
+
+        assert!(from_pubkey(&["0579be66"]).is_err());
+        assert!(from_pubkey(&[&compressed.replacen("02", "04", 1)]).is_err());
+        assert!(from_pubkey(&["--version-byte"]).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(&args(&["validate", "1abc"])),
+            Ok(Addr::Validate {
+                address: Some("1abc".into())
+            })
+        );
+        assert_eq!(
+            parse(&args(&["validate"])),
+            Ok(Addr::Validate { address: None })
+        );
+        assert!(parse(&args(&["validate", "a", "b"])).is_err());
+        assert!(parse(&args(&["create"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
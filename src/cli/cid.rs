@@ -0,0 +1,100 @@
+//! `base58 cid`: IPFS version 0 CIDs.
+
+use b58::cid;
+
+use super::value_or_stdin;
+use crate::to_hex;
+
+pub const USAGE: &str = "\
+base58 cid - IPFS version 0 CIDs
+
+USAGE:
+    base58 cid validate [CID]
+
+With no CID, read it from standard input.
+
+COMMANDS:
+    validate    Check a Qm... CID and print its SHA-256 digest
+
+EXAMPLES:
+    base58 cid validate QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG
+";
+
+/// Arguments of `base58 cid`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cid {
+    Validate { cid: Option<String> },
+}
+
+/// Parses the arguments after `cid`
+pub fn parse(args: &[String]) -> Result<Cid, String> {
+    match args {
+        [action, rest @ ..] if action == "validate" => match rest {
+            [] => Ok(Cid::Validate { cid: None }),
+            [cid] => Ok(Cid::Validate {
+                cid: Some(cid.clone()),
+            }),
+            _ => Err("cid validate takes one CID".into()),
+        },
+        [action, ..] => Err(format!("Unknown cid command: {action}")),
+        [] => Err("cid requires a command (validate)".into()),
+    }
+}
+
+impl Cid {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Cid::Validate { cid } => validate(&value_or_stdin(cid)?),
+        }
+    }
+}
+
+/// Describes a valid CID, or explains why it is invalid
+fn validate(input: &str) -> Result<String, String> {
+    let multihash = cid::decode_v0(input).map_err(|e| e.to_string())?;
+    Ok(format!(
+        "cid:     {input}\n\
+         version: 0\n\
+         hash:    sha2-256\n\
+         digest:  {}",
+        to_hex(&multihash[2..])
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let report = validate("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG").unwrap();
+        assert!(report.ends_with(
+            "digest:  9d6c2be50f706953479ab9df2ce3edca90b68053c00b3004b7f0accbe1e8eedf"
+        ));
+
+        let err = validate("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd").unwrap_err();
+        assert!(err.starts_with("Invalid CIDv0 encoding"), "{err}");
+        // 34 bytes, but not a SHA-256 multihash
+        let err = validate(&b58::encode([0x11; 34])).unwrap_err();
+        assert!(err.starts_with("Invalid CIDv0 multihash"), "{err}");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(&args(&["validate", "Qm"])),
+            Ok(Cid::Validate {
+                cid: Some("Qm".into())
+            })
+        );
+        assert_eq!(parse(&args(&["validate"])), Ok(Cid::Validate { cid: None }));
+        assert!(parse(&args(&["validate", "a", "b"])).is_err());
+        assert!(parse(&args(&["encode"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! `base58 id`: random Base58 identifiers.
+
+use b58::{Alphabet, encode_with_alphabet};
+
+use super::random_bytes;
+use crate::parse_alphabet;
+
+pub const USAGE: &str = "\
+base58 id - Random Base58 identifiers
+
+USAGE:
+    base58 id [OPTIONS]
+
+Identifiers come from the operating system's cryptographically secure random
+number generator, one per line.
+
+OPTIONS:
+    -b, --bytes <N>              Random bytes per identifier [default: 16]
+    -n, --count <N>              Number of identifiers [default: 1]
+    -a, --alphabet <ALPHABET>    Alphabet to encode with [default: bitcoin]
+
+EXAMPLES:
+    base58 id
+    base58 id --bytes 32 --count 10
+";
+
+/// Arguments of `base58 id`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Id {
+    bytes: usize,
+    count: usize,
+    alphabet: Alphabet,
+}
+
+/// Parses the arguments after `id`
+pub fn parse(args: &[String]) -> Result<Id, String> {
+    let mut id = Id {
+        bytes: 16,
+        count: 1,
+        alphabet: Alphabet::Bitcoin,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} requires a value"));
+        match arg.as_str() {
+            "-b" | "--bytes" => id.bytes = parse_count(value("--bytes")?)?,
+            "-n" | "--count" => id.count = parse_count(value("--count")?)?,
+            "-a" | "--alphabet" => id.alphabet = parse_alphabet(value("--alphabet")?)?,
+            arg => return Err(format!("Unknown option for id: {arg}")),
+        }
+    }
+    if id.bytes == 0 {
+        return Err("--bytes must be at least 1".into());
+    }
+    Ok(id)
+}
+
+/// Parses a non-negative count
+fn parse_count(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number: {value}"))
+}
+
+impl Id {
+    /// Generates the identifiers, one per line
+    pub fn run(&self) -> Result<String, String> {
+        // One identifier's bytes at a time, so memory does not grow with the count
+        // beyond the output itself
+        let mut random = vec![0u8; self.bytes];
+        let mut output = String::new();
+        for i in 0..self.count {
+            random_bytes(&mut random)?;
+            if i > 0 {
+                output.push('\n');
+            }
+            output.push_str(&encode_with_alphabet(&random, self.alphabet));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_generate() {
+        let id = parse(&args(&["--bytes", "32", "-n", "5", "-a", "flickr"])).unwrap();
+        let output = id.run().unwrap();
+        let ids: Vec<&str> = output.lines().collect();
+        assert_eq!(ids.len(), 5);
+        for id in &ids {
+            let bytes = b58::decode_with_alphabet(id, Alphabet::Flickr).unwrap();
+            assert_eq!(bytes.len(), 32);
+        }
+        // 256 random bits never repeat in practice
+        assert_ne!(ids[0], ids[1]);
+
+        assert_eq!(parse(&[]).unwrap().run().unwrap().lines().count(), 1);
+        assert_eq!(parse(&args(&["--count", "0"])).unwrap().run().unwrap(), "");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&args(&["--bytes", "0"])).is_err());
+        assert!(parse(&args(&["--bytes", "-1"])).is_err());
+        assert!(parse(&args(&["--count"])).is_err());
+        assert!(parse(&args(&["16"])).is_err());
+    }
+}
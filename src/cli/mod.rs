@@ -0,0 +1,119 @@
+//! Subcommands for specific formats built on the library, such as `base58 addr`.
+//!
+//! Unlike the encoding commands, each takes its own arguments after its name, usually
+//! an action and a value, and returns its output for `main` to write to stdout.
+
+pub mod addr;
+pub mod cid;
+pub mod id;
+pub mod solana;
+pub mod ss58;
+pub mod uuid;
+pub mod vanity;
+pub mod wif;
+pub mod xrp;
+
+use b58::wif::Network;
+
+/// A format-specific subcommand with its parsed arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tool {
+    Addr(addr::Addr),
+    Wif(wif::Wif),
+    Uuid(uuid::Uuid),
+    Id(id::Id),
+    Solana(solana::Solana),
+    Ss58(ss58::Ss58),
+    Xrp(xrp::Xrp),
+    Cid(cid::Cid),
+    Vanity(vanity::Vanity),
+}
+
+impl Tool {
+    /// Parses the arguments after a subcommand name, or returns `None` if `name` is not
+    /// a format-specific subcommand
+    pub fn parse(name: &str, args: &[String]) -> Option<Result<Tool, String>> {
+        match name {
+            "addr" => Some(addr::parse(args).map(Tool::Addr)),
+            "wif" => Some(wif::parse(args).map(Tool::Wif)),
+            "uuid" => Some(uuid::parse(args).map(Tool::Uuid)),
+            "id" => Some(id::parse(args).map(Tool::Id)),
+            "solana" => Some(solana::parse(args).map(Tool::Solana)),
+            "ss58" => Some(ss58::parse(args).map(Tool::Ss58)),
+            "xrp" => Some(xrp::parse(args).map(Tool::Xrp)),
+            "cid" => Some(cid::parse(args).map(Tool::Cid)),
+            "vanity" => Some(vanity::parse(args).map(Tool::Vanity)),
+            _ => None,
+        }
+    }
+
+    /// Returns the help text of a format-specific subcommand
+    pub fn usage(name: &str) -> Option<&'static str> {
+        match name {
+            "addr" => Some(addr::USAGE),
+            "wif" => Some(wif::USAGE),
+            "uuid" => Some(uuid::USAGE),
+            "id" => Some(id::USAGE),
+            "solana" => Some(solana::USAGE),
+            "ss58" => Some(ss58::USAGE),
+            "xrp" => Some(xrp::USAGE),
+            "cid" => Some(cid::USAGE),
+            "vanity" => Some(vanity::USAGE),
+            _ => None,
+        }
+    }
+
+    /// Runs the subcommand, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Tool::Addr(addr) => addr.run(),
+            Tool::Wif(wif) => wif.run(),
+            Tool::Uuid(uuid) => uuid.run(),
+            Tool::Id(id) => id.run(),
+            Tool::Solana(solana) => solana.run(),
+            Tool::Ss58(ss58) => ss58.run(),
+            Tool::Xrp(xrp) => xrp.run(),
+            Tool::Cid(cid) => cid.run(),
+            Tool::Vanity(vanity) => vanity.run(),
+        }
+    }
+}
+
+/// Returns `value`, or the trimmed contents of stdin if no value was given
+fn value_or_stdin(value: &Option<String>) -> Result<String, String> {
+    match value {
+        Some(value) => Ok(value.clone()),
+        None => {
+            let input = crate::read_input(&[])?;
+            let input =
+                String::from_utf8(input).map_err(|e| format!("Input is not valid UTF-8: {e}"))?;
+            Ok(input.trim().to_string())
+        }
+    }
+}
+
+/// Returns the name of a Bitcoin network
+fn network_name(network: Network) -> &'static str {
+    match network {
+        Network::Mainnet => "mainnet",
+        Network::Testnet => "testnet",
+    }
+}
+
+/// Fills `buf` from the operating system's cryptographically secure random number
+/// generator
+#[cfg(unix)]
+fn random_bytes(buf: &mut [u8]) -> Result<(), String> {
+    use std::io::Read;
+
+    std::fs::File::open("/dev/urandom")
+        .and_then(|mut urandom| urandom.read_exact(buf))
+        .map_err(|e| format!("Failed to read random bytes: {e}"))
+}
+
+/// Fills `buf` from the operating system's cryptographically secure random number
+/// generator
+#[cfg(not(unix))]
+fn random_bytes(_buf: &mut [u8]) -> Result<(), String> {
+    Err("Random identifiers are only supported on Unix-like systems".into())
+}
@@ -0,0 +1,145 @@
+//! `base58 solana`: converting Solana keypairs between solana-cli's JSON format and the
+//! Base58 string used by wallets.
+
+use b58::{decode, encode};
+
+use crate::read_input;
+
+pub const USAGE: &str = "\
+base58 solana - Solana keypair formats
+
+USAGE:
+    base58 solana to-base58 [FILE]
+    base58 solana to-json [FILE]
+
+A keypair is 64 bytes: the secret key followed by the public key. solana-cli stores
+it as a JSON array of 64 numbers, and wallets import and export it as one Base58
+string. With no FILE, read standard input.
+
+COMMANDS:
+    to-base58    Convert a JSON keypair, such as ~/.config/solana/id.json, to Base58
+    to-json      Convert a Base58 keypair to a JSON array
+
+EXAMPLES:
+    base58 solana to-base58 ~/.config/solana/id.json
+    base58 solana to-json < wallet-key.txt > id.json
+";
+
+/// Number of bytes in a keypair
+const KEYPAIR_LEN: usize = 64;
+
+/// Arguments of `base58 solana`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solana {
+    ToBase58 { file: Option<String> },
+    ToJson { file: Option<String> },
+}
+
+/// Parses the arguments after `solana`
+pub fn parse(args: &[String]) -> Result<Solana, String> {
+    let (action, file) = match args {
+        [action] => (action, None),
+        [action, file] if file == "-" || !file.starts_with('-') => (action, Some(file.clone())),
+        [action, arg] => return Err(format!("Unknown option for solana {action}: {arg}")),
+        [action, ..] => return Err(format!("solana {action} takes one file")),
+        [] => return Err("solana requires a command (to-base58 or to-json)".into()),
+    };
+    match action.as_str() {
+        "to-base58" => Ok(Solana::ToBase58 { file }),
+        "to-json" => Ok(Solana::ToJson { file }),
+        _ => Err(format!("Unknown solana command: {action}")),
+    }
+}
+
+impl Solana {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Solana::ToBase58 { file } => Ok(encode(parse_json(&read_text(file)?)?)),
+            Solana::ToJson { file } => {
+                let keypair = decode(read_text(file)?.trim()).map_err(|e| e.to_string())?;
+                check_len(keypair.len())?;
+                Ok(to_json(&keypair))
+            }
+        }
+    }
+}
+
+/// Reads a file, or stdin if there is none, as text
+fn read_text(file: &Option<String>) -> Result<String, String> {
+    let input = read_input(file.as_slice())?;
+    String::from_utf8(input).map_err(|e| format!("Input is not valid UTF-8: {e}"))
+}
+
+/// Checks that a keypair has the expected length
+fn check_len(len: usize) -> Result<(), String> {
+    if len != KEYPAIR_LEN {
+        return Err(format!("Keypair must be {KEYPAIR_LEN} bytes, found {len}"));
+    }
+    Ok(())
+}
+
+/// Parses a JSON array of 64 byte values
+fn parse_json(input: &str) -> Result<Vec<u8>, String> {
+    let inner = input
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or("Keypair JSON must be an array of numbers")?;
+    let keypair = inner
+        .split(',')
+        .map(|n| {
+            let n = n.trim();
+            n.parse()
+                .map_err(|_| format!("Keypair JSON holds a value that is not a byte: {n}"))
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    check_len(keypair.len())?;
+    Ok(keypair)
+}
+
+/// Formats bytes as a JSON array of numbers, as solana-cli writes keypairs
+fn to_json(bytes: &[u8]) -> String {
+    let numbers: Vec<String> = bytes.iter().map(|b| b.to_string()).collect();
+    format!("[{}]", numbers.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let keypair: Vec<u8> = (0..64).map(|i| (i * 37 + 11) as u8).collect();
+        let json = to_json(&keypair);
+        assert!(json.starts_with("[11,48,85,"));
+        assert_eq!(parse_json(&json).unwrap(), keypair);
+
+        // solana-cli writes no spaces, but pretty-printed files parse too
+        let pretty = format!(
+            "[\n  {}\n]\n",
+            json[1..json.len() - 1].replace(',', ",\n  ")
+        );
+        assert_eq!(parse_json(&pretty).unwrap(), keypair);
+        assert_eq!(encode(parse_json(&json).unwrap()), encode(&keypair));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse_json("[1,2,3]"),
+            Err("Keypair must be 64 bytes, found 3".into())
+        );
+        assert!(parse_json("1,2,3").is_err());
+        assert!(parse_json(&format!("[256{}]", ",0".repeat(63))).is_err());
+        assert!(parse_json("[]").is_err());
+
+        let args = |args: &[&str]| args.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            parse(&args(&["to-json"])),
+            Ok(Solana::ToJson { file: None })
+        );
+        assert!(parse(&args(&["to-json", "--pretty"])).is_err());
+        assert!(parse(&args(&["to-hex"])).is_err());
+    }
+}
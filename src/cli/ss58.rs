@@ -0,0 +1,165 @@
+//! `base58 ss58`: Substrate SS58 addresses.
+
+use b58::ss58;
+
+use super::value_or_stdin;
+use crate::{from_hex, to_hex};
+
+pub const USAGE: &str = "\
+base58 ss58 - Substrate SS58 addresses
+
+USAGE:
+    base58 ss58 encode [--prefix PREFIX] [HEX]
+    base58 ss58 decode [ADDRESS]
+
+With no HEX or ADDRESS, read it from standard input.
+
+COMMANDS:
+    encode    Encode a 32-byte public key given as hex as an address
+    decode    Print the network prefix and public key of an address
+
+OPTIONS:
+    -p, --prefix <PREFIX>    Network prefix, as a number up to 16383 or one of polkadot,
+                             kusama and substrate [default: substrate]
+
+EXAMPLES:
+    base58 ss58 encode --prefix polkadot d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d
+    base58 ss58 decode 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY
+";
+
+/// Networks that can be named instead of giving their prefix
+const NETWORKS: [(&str, u16); 3] = [
+    ("polkadot", ss58::POLKADOT_PREFIX),
+    ("kusama", ss58::KUSAMA_PREFIX),
+    ("substrate", ss58::SUBSTRATE_PREFIX),
+];
+
+/// Arguments of `base58 ss58`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ss58 {
+    Encode { hex: Option<String>, prefix: u16 },
+    Decode { address: Option<String> },
+}
+
+/// Parses the arguments after `ss58`
+pub fn parse(args: &[String]) -> Result<Ss58, String> {
+    let Some((action, rest)) = args.split_first() else {
+        return Err("ss58 requires a command (encode or decode)".into());
+    };
+    let mut prefix = ss58::SUBSTRATE_PREFIX;
+    let mut values = Vec::new();
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "-p" | "--prefix" if action == "encode" => {
+                let value = rest.next().ok_or("--prefix requires a value")?;
+                prefix = parse_prefix(value)?;
+            }
+            arg if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ => values.push(arg.clone()),
+        }
+    }
+    if values.len() > 1 {
+        return Err(format!("ss58 {action} takes one value"));
+    }
+    let value = values.pop();
+
+    match action.as_str() {
+        "encode" => Ok(Ss58::Encode { hex: value, prefix }),
+        "decode" => Ok(Ss58::Decode { address: value }),
+        _ => Err(format!("Unknown ss58 command: {action}")),
+    }
+}
+
+/// Parses a network prefix given as a number or a network name
+fn parse_prefix(value: &str) -> Result<u16, String> {
+    if let Some(&(_, prefix)) = NETWORKS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+    {
+        return Ok(prefix);
+    }
+    match value.parse() {
+        Ok(prefix) if prefix <= ss58::MAX_PREFIX => Ok(prefix),
+        _ => Err(format!("Invalid SS58 network prefix: {value}")),
+    }
+}
+
+impl Ss58 {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Ss58::Encode { hex, prefix } => {
+                let key = from_hex(value_or_stdin(hex)?.as_bytes())?;
+                let key: [u8; 32] = key.try_into().map_err(|key: Vec<u8>| {
+                    format!("Public key must be 32 bytes, found {}", key.len())
+                })?;
+                ss58::encode(*prefix, &key).map_err(|e| e.to_string())
+            }
+            Ss58::Decode { address } => describe(&value_or_stdin(address)?),
+        }
+    }
+}
+
+/// Describes the network prefix and public key of an address
+fn describe(address: &str) -> Result<String, String> {
+    let (prefix, key) = ss58::decode(address).map_err(|e| e.to_string())?;
+    let network = NETWORKS
+        .iter()
+        .find(|&&(_, p)| p == prefix)
+        .map_or(String::new(), |(name, _)| format!(" ({name})"));
+    Ok(format!(
+        "prefix:     {prefix}{network}\n\
+         public key: {}",
+        to_hex(&key)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+    #[test]
+    fn test_round_trip() {
+        let encode = parse(&args(&["encode", ALICE])).unwrap();
+        let address = encode.run().unwrap();
+        assert_eq!(address, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+        assert_eq!(
+            describe(&address).unwrap(),
+            format!("prefix:     42 (substrate)\npublic key: {ALICE}")
+        );
+
+        let encode = parse(&args(&["encode", "-p", "Polkadot", ALICE])).unwrap();
+        assert_eq!(
+            encode.run().unwrap(),
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        let encode = parse(&args(&["encode", "--prefix", "7", ALICE])).unwrap();
+        assert!(
+            describe(&encode.run().unwrap())
+                .unwrap()
+                .starts_with("prefix:     7\n")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse_prefix("16384").is_err());
+        assert!(parse_prefix("westend").is_err());
+        assert_eq!(parse_prefix("16383"), Ok(16383));
+        assert!(parse(&args(&["decode", "--prefix", "0"])).is_err());
+        assert!(parse(&args(&["encode", "--prefix"])).is_err());
+        let short = parse(&args(&["encode", "d435"])).unwrap();
+        assert_eq!(
+            short.run(),
+            Err("Public key must be 32 bytes, found 2".into())
+        );
+        assert!(describe("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_err());
+    }
+}
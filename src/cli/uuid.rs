@@ -0,0 +1,135 @@
+//! `base58 uuid`: short Base58 slugs for UUIDs.
+
+use b58::{decode_uuid, encode_uuid};
+
+use super::value_or_stdin;
+use crate::{from_hex, to_hex};
+
+pub const USAGE: &str = "\
+base58 uuid - Short Base58 slugs for UUIDs
+
+USAGE:
+    base58 uuid encode [UUID]
+    base58 uuid decode [SLUG]
+
+With no UUID or SLUG, read it from standard input.
+
+COMMANDS:
+    encode    Shorten a UUID, with or without hyphens, to a slug of at most 22 characters
+    decode    Expand a slug to the canonical hyphenated UUID
+
+EXAMPLES:
+    base58 uuid encode 67e55044-10b1-426f-9247-bb680e5fe0c8
+    base58 uuid decode Dq7QdGPZBdz9vwjm3jLQSB
+";
+
+/// Arguments of `base58 uuid`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Uuid {
+    Encode { uuid: Option<String> },
+    Decode { slug: Option<String> },
+}
+
+/// Parses the arguments after `uuid`
+pub fn parse(args: &[String]) -> Result<Uuid, String> {
+    let (action, value) = match args {
+        [action] => (action, None),
+        [action, value] if !value.starts_with('-') => (action, Some(value.clone())),
+        [action, arg] => return Err(format!("Unknown option for uuid {action}: {arg}")),
+        [action, ..] => return Err(format!("uuid {action} takes one value")),
+        [] => return Err("uuid requires a command (encode or decode)".into()),
+    };
+    match action.as_str() {
+        "encode" => Ok(Uuid::Encode { uuid: value }),
+        "decode" => Ok(Uuid::Decode { slug: value }),
+        _ => Err(format!("Unknown uuid command: {action}")),
+    }
+}
+
+impl Uuid {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Uuid::Encode { uuid } => Ok(encode_uuid(parse_uuid(&value_or_stdin(uuid)?)?)),
+            Uuid::Decode { slug } => {
+                let bytes = decode_uuid(&value_or_stdin(slug)?).map_err(|e| e.to_string())?;
+                Ok(format_uuid(&bytes))
+            }
+        }
+    }
+}
+
+/// Parses a UUID as 32 hex digits, either plain or hyphenated as 8-4-4-4-12
+fn parse_uuid(input: &str) -> Result<[u8; 16], String> {
+    let invalid = || format!("Invalid UUID: {input}");
+    let digits = match input.len() {
+        32 => input.to_string(),
+        36 => {
+            let hyphens = [8, 13, 18, 23];
+            if hyphens.iter().any(|&i| input.as_bytes()[i] != b'-') {
+                return Err(invalid());
+            }
+            input.replace('-', "")
+        }
+        _ => return Err(invalid()),
+    };
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    from_hex(digits.as_bytes())?
+        .try_into()
+        .map_err(|_| invalid())
+}
+
+/// Formats UUID bytes in the canonical lowercase hyphenated form
+fn format_uuid(bytes: &[u8; 16]) -> String {
+    let hex = to_hex(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let slug = parse(&args(&["encode", uuid])).unwrap().run().unwrap();
+        assert_eq!(slug, "Dq7QdGPZBdz9vwjm3jLQSB");
+        let plain = parse(&args(&["encode", "67E5504410B1426F9247BB680E5FE0C8"]));
+        assert_eq!(plain.unwrap().run().unwrap(), slug);
+        assert_eq!(
+            parse(&args(&["decode", &slug])).unwrap().run().unwrap(),
+            uuid
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for invalid in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e5504410b1-426f-9247-bb680e5fe0c8-",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            "+7e5504410b1426f9247bb680e5fe0c8",
+        ] {
+            assert!(parse_uuid(invalid).is_err(), "{invalid}");
+        }
+        let long = parse(&args(&["decode", "zzzzzzzzzzzzzzzzzzzzzzz"])).unwrap();
+        assert!(long.run().is_err());
+        assert!(parse(&args(&["encode", "-x"])).is_err());
+        assert!(parse(&args(&["encode", "a", "b"])).is_err());
+        assert!(parse(&args(&["shorten"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
@@ -0,0 +1,421 @@
+//! `base58 vanity`: search for payloads whose encoding starts with a pattern.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use b58::{Alphabet, encode_check_with_alphabet, encode_with_alphabet};
+
+use super::random_bytes;
+use crate::{parse_alphabet, parse_byte, to_hex};
+
+pub const USAGE: &str = "\
+base58 vanity - Search for payloads whose encoding starts with a pattern
+
+USAGE:
+    base58 vanity --prefix <PATTERN> [OPTIONS]
+
+Random payloads are encoded until one starts with PATTERN, reporting the attempts
+per second on stderr. Each extra character makes the search about 58 times longer.
+The payloads are random identifiers or hash160s, not keys, and come from a fast
+generator seeded by the operating system; do not use them as secrets.
+
+OPTIONS:
+    -p, --prefix <PATTERN>       Characters the encoding must start with
+    -t, --threads <N>            Threads to search with [default: one per CPU]
+    -b, --bytes <N>              Random bytes per payload [default: 20]
+    -c, --check                  Encode as Base58Check
+        --version-byte <BYTE>    With --check, start each payload with this byte
+    -a, --alphabet <ALPHABET>    Alphabet to encode with [default: bitcoin]
+
+EXAMPLES:
+    base58 vanity --prefix Cafe --threads 8
+    base58 vanity --prefix 1Cafe --version-byte 0x00
+";
+
+/// How often the search reports its progress
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+/// Attempts between checks for another thread's match
+const BATCH: u64 = 1024;
+
+/// Arguments of `base58 vanity`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vanity {
+    prefix: String,
+    threads: usize,
+    bytes: usize,
+    check: bool,
+    version: Option<u8>,
+    alphabet: Alphabet,
+}
+
+/// Parses the arguments after `vanity`
+pub fn parse(args: &[String]) -> Result<Vanity, String> {
+    let mut vanity = Vanity {
+        prefix: String::new(),
+        threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        bytes: 20,
+        check: false,
+        version: None,
+        alphabet: Alphabet::Bitcoin,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} requires a value"));
+        match arg.as_str() {
+            "-p" | "--prefix" => vanity.prefix = value("--prefix")?.clone(),
+            "-t" | "--threads" => vanity.threads = parse_count(value("--threads")?)?,
+            "-b" | "--bytes" => vanity.bytes = parse_count(value("--bytes")?)?,
+            "-c" | "--check" => vanity.check = true,
+            "--version-byte" => {
+                vanity.version = Some(parse_byte(value("--version-byte")?)?);
+                vanity.check = true;
+            }
+            "-a" | "--alphabet" => vanity.alphabet = parse_alphabet(value("--alphabet")?)?,
+            arg => return Err(format!("Unknown option for vanity: {arg}")),
+        }
+    }
+
+    if vanity.prefix.is_empty() {
+        return Err("vanity requires --prefix".into());
+    }
+    if let Some(c) = vanity
+        .prefix
+        .chars()
+        .find(|&c| !c.is_ascii() || !vanity.alphabet.as_bytes().contains(&(c as u8)))
+    {
+        return Err(format!(
+            "'{c}' is not in the alphabet, so no encoding can match"
+        ));
+    }
+    if vanity.threads == 0 || vanity.bytes == 0 {
+        return Err("--threads and --bytes must be at least 1".into());
+    }
+    if !vanity.can_match() {
+        return Err(format!(
+            "No {}-byte payload{} encodes to a string starting with {}, so the search would never end",
+            vanity.bytes,
+            match vanity.version {
+                Some(version) => format!(" with version byte 0x{version:02x}"),
+                None => String::new(),
+            },
+            vanity.prefix
+        ));
+    }
+    Ok(vanity)
+}
+
+/// Parses a non-negative count
+fn parse_count(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number: {value}"))
+}
+
+impl Vanity {
+    /// Returns whether any payload can encode to a string starting with the prefix.
+    ///
+    /// The encoded bytes are the version byte, if any, the random payload and, with
+    /// `--check`, a checksum. The checksum is treated as free, so this rejects
+    /// prefixes that are too long or that a version byte's fixed leading characters
+    /// rule out, but not the rare ones that only particular checksums would allow.
+    fn can_match(&self) -> bool {
+        let len = usize::from(self.version.is_some()) + self.bytes + if self.check { 4 } else { 0 };
+        let mut low = vec![0u8; len];
+        let mut high = vec![0xFF; len];
+        if let Some(version) = self.version {
+            (low[0], high[0]) = (version, version);
+        }
+        let table = self.alphabet.decode_table();
+        let prefix: Vec<u8> = self.prefix.bytes().map(|c| table[c as usize]).collect();
+        can_start_with(&prefix, &low, &high, self.alphabet)
+    }
+
+    /// Searches until a payload matches, returning it with its encoding
+    pub fn run(&self) -> Result<String, String> {
+        let mut seeds = vec![[0u8; 32]; self.threads];
+        for seed in &mut seeds {
+            random_bytes(seed)?;
+        }
+
+        let found = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+        let start = Instant::now();
+        let (encoded, payload) = thread::scope(|scope| {
+            let (sender, receiver) = mpsc::channel();
+            for seed in seeds {
+                let sender = sender.clone();
+                let (found, attempts) = (&found, &attempts);
+                scope.spawn(move || {
+                    if let Some(result) = self.search(Rng::new(seed), found, attempts) {
+                        let _ = sender.send(result);
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut reported = false;
+            let result = loop {
+                match receiver.recv_timeout(REPORT_INTERVAL) {
+                    Ok(result) => break result,
+                    Err(RecvTimeoutError::Timeout) => {
+                        let attempts = attempts.load(Ordering::Relaxed);
+                        eprint!(
+                            "\r{attempts} attempts, {:.0}/s",
+                            rate(attempts, start.elapsed())
+                        );
+                        reported = true;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        unreachable!("searches only stop on a match")
+                    }
+                }
+            };
+            found.store(true, Ordering::Relaxed);
+            if reported {
+                eprintln!();
+            }
+            result
+        });
+
+        let elapsed = start.elapsed();
+        let attempts = attempts.load(Ordering::Relaxed);
+        Ok(format!(
+            "encoded:  {encoded}\n\
+             payload:  {}\n\
+             attempts: {attempts} in {:.1} s ({:.0}/s)",
+            to_hex(&payload),
+            elapsed.as_secs_f64(),
+            rate(attempts, elapsed)
+        ))
+    }
+
+    /// Tries random payloads until one matches or another thread finds a match
+    fn search(
+        &self,
+        mut rng: Rng,
+        found: &AtomicBool,
+        attempts: &AtomicU64,
+    ) -> Option<(String, Vec<u8>)> {
+        // The version byte is part of every payload and is not random
+        let start = usize::from(self.version.is_some());
+        let mut payload = vec![0u8; start + self.bytes];
+        if let Some(version) = self.version {
+            payload[0] = version;
+        }
+
+        while !found.load(Ordering::Relaxed) {
+            for tried in 1..=BATCH {
+                rng.fill(&mut payload[start..]);
+                let encoded = match self.check {
+                    true => encode_check_with_alphabet(&payload, self.alphabet),
+                    false => encode_with_alphabet(&payload, self.alphabet),
+                };
+                if encoded.starts_with(&self.prefix) {
+                    attempts.fetch_add(tried, Ordering::Relaxed);
+                    return Some((encoded, payload[start..].to_vec()));
+                }
+            }
+            attempts.fetch_add(BATCH, Ordering::Relaxed);
+        }
+        None
+    }
+}
+
+/// Returns whether some number from `low` to `high`, big-endian and of the same length,
+/// encodes to a string starting with the digits `prefix`
+fn can_start_with(prefix: &[u8], low: &[u8], high: &[u8], alphabet: Alphabet) -> bool {
+    let len = low.len();
+    let table = alphabet.decode_table();
+    let digits = |bytes: &[u8]| -> Vec<u8> {
+        let encoded = encode_with_alphabet(bytes, alphabet);
+        encoded.bytes().map(|c| table[c as usize]).collect()
+    };
+
+    // Each leading zero byte is a leading zero digit, so a prefix of zero digits matches
+    // any number with at least as many leading zero bytes
+    let k = prefix.len();
+    if k <= len && prefix.iter().all(|&d| d == 0) && low[..k].iter().all(|&b| b == 0) {
+        return true;
+    }
+
+    // Otherwise the prefix ends within the digits of a number with fewer than `k`
+    // leading zero bytes
+    for zeros in 0..k.min(len) {
+        let mut first = vec![0u8; len];
+        first[zeros] = 1;
+        let mut last = vec![0xFF; len];
+        last[..zeros].fill(0);
+        let (from, to) = (low.max(&first[..]), high.min(&last[..]));
+        if from > to {
+            continue;
+        }
+
+        // The numbers in between encode to every digit string from `from` to `to` of
+        // each length, where comparing digit strings of one length compares the numbers
+        let (from, to) = (digits(from), digits(to));
+        for width in from.len()..=to.len() {
+            let mut lower = vec![0u8; width];
+            lower[zeros] = 1;
+            let mut upper = vec![57u8; width];
+            upper[..zeros].fill(0);
+            if width == from.len() {
+                lower = from.clone();
+            }
+            if width == to.len() {
+                upper = to.clone();
+            }
+            if k <= width && &lower[..k] <= prefix && prefix <= &upper[..k] {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Returns the attempts per second
+fn rate(attempts: u64, elapsed: Duration) -> f64 {
+    attempts as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+}
+
+/// The xoshiro256** generator, which is fast and statistically sound but not
+/// cryptographically secure
+struct Rng([u64; 4]);
+
+impl Rng {
+    fn new(seed: [u8; 32]) -> Self {
+        let mut state = [0u64; 4];
+        for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Rng(state)
+    }
+
+    fn next(&mut self) -> u64 {
+        let s = &mut self.0;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Returns the encoding and payload lines of a search's output
+    fn search(args_: &[&str]) -> (String, Vec<u8>) {
+        let output = parse(&args(args_)).unwrap().run().unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        let encoded = lines[0].strip_prefix("encoded:  ").unwrap().to_string();
+        let payload = crate::from_hex(lines[1].strip_prefix("payload:  ").unwrap().as_bytes());
+        assert!(lines[2].starts_with("attempts: "));
+        (encoded, payload.unwrap())
+    }
+
+    #[test]
+    fn test_search() {
+        let (encoded, payload) = search(&["--prefix", "Ca", "-t", "2", "-b", "8"]);
+        assert!(encoded.starts_with("Ca"), "{encoded}");
+        assert_eq!(b58::decode(&encoded).unwrap(), payload);
+        assert_eq!(payload.len(), 8);
+
+        let (encoded, payload) = search(&["-p", "1z", "--version-byte", "0", "-t", "1"]);
+        assert!(encoded.starts_with("1z"), "{encoded}");
+        let decoded = b58::decode_check(&encoded).unwrap();
+        assert_eq!(decoded[0], 0);
+        assert_eq!(decoded[1..], payload);
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new([1; 32]);
+        let mut a = [0u8; 13];
+        let mut b = [0u8; 13];
+        rng.fill(&mut a);
+        rng.fill(&mut b);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&args(&["--prefix", "Cafe0"])).is_err());
+        assert!(parse(&args(&["--prefix", "Café"])).is_err());
+        assert!(parse(&args(&["--prefix", "a", "--threads", "0"])).is_err());
+        assert!(parse(&args(&["--prefix", "a", "--bytes"])).is_err());
+        assert!(parse(&args(&["Cafe"])).is_err());
+        assert!(parse(&args(&["-p", "l", "-a", "flickr"])).is_err());
+    }
+
+    #[test]
+    fn test_impossible_prefixes() {
+        let possible = |args_: &[&str]| parse(&args(args_)).is_ok();
+
+        // Version 0 always encodes to a leading 1, and version 5 to a 3
+        assert!(!possible(&["--prefix", "2", "--version-byte", "0x00"]));
+        assert!(possible(&["--prefix", "1z", "--version-byte", "0x00"]));
+        assert!(!possible(&["--prefix", "1", "--version-byte", "0x05"]));
+        assert!(possible(&["--prefix", "3J", "--version-byte", "0x05"]));
+
+        // One byte encodes to at most 5Q, and leading zero bytes to at most one 1 each
+        assert!(!possible(&["-b", "1", "--prefix", "zzz"]));
+        assert!(!possible(&["-b", "1", "--prefix", "5R"]));
+        assert!(possible(&["-b", "1", "--prefix", "5Q"]));
+        assert!(possible(&["-b", "1", "--prefix", "1"]));
+        assert!(!possible(&["-b", "1", "--prefix", "11"]));
+        assert!(possible(&["-b", "2", "--prefix", "11"]));
+        assert!(possible(&["-b", "2", "--prefix", "1z"]));
+
+        // The checksum adds characters to match against
+        assert!(possible(&["-b", "1", "--check", "--prefix", "zzzz"]));
+        assert!(possible(&["-b", "1", "-a", "ripple", "--prefix", "r"]));
+
+        // Every one- and two-character prefix agrees with trying all payloads
+        let alphabet = Alphabet::Bitcoin;
+        for (low, high) in [
+            ([0, 0], [0xFF, 0xFF]),
+            ([0x00, 0x00], [0x00, 0xFF]),
+            ([7, 0], [7, 0xFF]),
+        ] {
+            let encodings: Vec<String> = (u16::from_be_bytes(low)..=u16::from_be_bytes(high))
+                .map(|n| encode_with_alphabet(n.to_be_bytes(), alphabet))
+                .collect();
+            let prefixes = (0..58u8)
+                .map(|d| vec![d])
+                .chain((0..58 * 58).map(|d| vec![(d / 58) as u8, (d % 58) as u8]));
+            for prefix in prefixes {
+                let text: String = prefix
+                    .iter()
+                    .map(|&d| alphabet.as_bytes()[d as usize] as char)
+                    .collect();
+                let found = encodings.iter().any(|e| e.starts_with(&text));
+                assert_eq!(
+                    can_start_with(&prefix, &low, &high, alphabet),
+                    found,
+                    "{text} in {low:?}..{high:?}"
+                );
+            }
+        }
+
+        let err = parse(&args(&["--prefix", "2", "--version-byte", "0"])).unwrap_err();
+        assert!(err.contains("version byte 0x00"), "{err}");
+    }
+}
@@ -0,0 +1,162 @@
+//! `base58 wif`: private keys in Wallet Import Format.
+
+use b58::wif::{self, Network, PrivateKey};
+
+use super::{network_name, value_or_stdin};
+use crate::{from_hex, to_hex};
+
+pub const USAGE: &str = "\
+base58 wif - Private keys in Wallet Import Format
+
+USAGE:
+    base58 wif encode [OPTIONS] [HEX]
+    base58 wif decode [WIF]
+
+With no HEX or WIF, read it from standard input, which keeps the key out of the
+shell history.
+
+COMMANDS:
+    encode    Convert a 32-byte private key given as hex to WIF
+    decode    Print the private key, compression flag and network of a WIF key
+
+OPTIONS:
+    --uncompressed    Mark the key as belonging to an uncompressed public key
+    --testnet         Encode the key for testnet
+
+EXAMPLES:
+    base58 wif encode < key.hex
+    echo KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617 | base58 wif decode
+";
+
+/// Arguments of `base58 wif`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Wif {
+    Encode {
+        hex: Option<String>,
+        compressed: bool,
+        network: Network,
+    },
+    Decode {
+        wif: Option<String>,
+    },
+}
+
+/// Parses the arguments after `wif`
+pub fn parse(args: &[String]) -> Result<Wif, String> {
+    let Some((action, rest)) = args.split_first() else {
+        return Err("wif requires a command (encode or decode)".into());
+    };
+    let mut compressed = true;
+    let mut network = Network::Mainnet;
+    let mut values = Vec::new();
+    for arg in rest {
+        match arg.as_str() {
+            "--uncompressed" if action == "encode" => compressed = false,
+            "--testnet" if action == "encode" => network = Network::Testnet,
+            arg if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ => values.push(arg.clone()),
+        }
+    }
+    if values.len() > 1 {
+        return Err(format!("wif {action} takes one value"));
+    }
+    let value = values.pop();
+
+    match action.as_str() {
+        "encode" => Ok(Wif::Encode {
+            hex: value,
+            compressed,
+            network,
+        }),
+        "decode" => Ok(Wif::Decode { wif: value }),
+        _ => Err(format!("Unknown wif command: {action}")),
+    }
+}
+
+impl Wif {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Wif::Encode {
+                hex,
+                compressed,
+                network,
+            } => {
+                let secret = from_hex(value_or_stdin(hex)?.as_bytes())?;
+                let secret = secret.try_into().map_err(|secret: Vec<u8>| {
+                    format!("Private key must be 32 bytes, found {}", secret.len())
+                })?;
+                Ok(wif::encode(&PrivateKey {
+                    secret,
+                    compressed: *compressed,
+                    network: *network,
+                }))
+            }
+            Wif::Decode { wif } => describe(&value_or_stdin(wif)?),
+        }
+    }
+}
+
+/// Describes the key held by a WIF string
+fn describe(input: &str) -> Result<String, String> {
+    let key = wif::decode(input).map_err(|e| e.to_string())?;
+    Ok(format!(
+        "secret:     {}\n\
+         compressed: {}\n\
+         network:    {}",
+        to_hex(&key.secret),
+        if key.compressed { "yes" } else { "no" },
+        network_name(key.network)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    const SECRET: &str = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+
+    #[test]
+    fn test_round_trip() {
+        let encode = |extra: &[&str]| {
+            let mut all = vec!["encode", SECRET];
+            all.extend_from_slice(extra);
+            parse(&args(&all)).unwrap().run().unwrap()
+        };
+        assert_eq!(
+            encode(&["--uncompressed"]),
+            "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"
+        );
+        let compressed = encode(&[]);
+        assert_eq!(
+            compressed,
+            "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"
+        );
+
+        let report = describe(&compressed).unwrap();
+        assert!(report.contains(&format!("secret:     {SECRET}\n")));
+        assert!(report.contains("compressed: yes\n"));
+        assert!(report.ends_with("network:    mainnet"));
+
+        let testnet = encode(&["--testnet"]);
+        assert!(describe(&testnet).unwrap().ends_with("network:    testnet"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let short = parse(&args(&["encode", "0c28"])).unwrap();
+        assert_eq!(
+            short.run(),
+            Err("Private key must be 32 bytes, found 2".into())
+        );
+        assert!(describe("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_err());
+        assert!(parse(&args(&["decode", "--testnet"])).is_err());
+        assert!(parse(&args(&["encode", "a", "b"])).is_err());
+        assert!(parse(&args(&["sign"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
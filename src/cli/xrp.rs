@@ -0,0 +1,217 @@
+//! `base58 xrp`: XRP Ledger classic addresses and seeds.
+
+use b58::DecodeError;
+use b58::xrp::{self, XrpError};
+
+use super::value_or_stdin;
+use crate::{from_hex, to_hex};
+
+pub const USAGE: &str = "\
+base58 xrp - XRP Ledger classic addresses and seeds
+
+USAGE:
+    base58 xrp encode [--seed | --node] [HEX]
+    base58 xrp decode [VALUE]
+
+With no HEX or VALUE, read it from standard input, which keeps seeds out of the
+shell history.
+
+COMMANDS:
+    encode    Encode a 20-byte account ID given as hex as a classic address
+    decode    Print the type and payload of a classic address, seed or node public key
+
+OPTIONS:
+    --seed    Encode a 16-byte family seed instead
+    --node    Encode a 33-byte node public key instead
+
+EXAMPLES:
+    base58 xrp decode rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh
+    base58 xrp encode --seed < seed.hex
+";
+
+/// Kinds of value encoded with an XRP type prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Account,
+    Seed,
+    NodePublic,
+}
+
+/// Arguments of `base58 xrp`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Xrp {
+    Encode { hex: Option<String>, kind: Kind },
+    Decode { value: Option<String> },
+}
+
+/// Parses the arguments after `xrp`
+pub fn parse(args: &[String]) -> Result<Xrp, String> {
+    let Some((action, rest)) = args.split_first() else {
+        return Err("xrp requires a command (encode or decode)".into());
+    };
+    let mut kind = Kind::Account;
+    let mut values = Vec::new();
+    for arg in rest {
+        match arg.as_str() {
+            "--seed" if action == "encode" => kind = Kind::Seed,
+            "--node" if action == "encode" => kind = Kind::NodePublic,
+            arg if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ => values.push(arg.clone()),
+        }
+    }
+    if values.len() > 1 {
+        return Err(format!("xrp {action} takes one value"));
+    }
+    let value = values.pop();
+
+    match action.as_str() {
+        "encode" => Ok(Xrp::Encode { hex: value, kind }),
+        "decode" => Ok(Xrp::Decode { value }),
+        _ => Err(format!("Unknown xrp command: {action}")),
+    }
+}
+
+impl Xrp {
+    /// Runs the command, returning its output
+    pub fn run(&self) -> Result<String, String> {
+        match self {
+            Xrp::Encode { hex, kind } => encode(*kind, &from_hex(value_or_stdin(hex)?.as_bytes())?),
+            Xrp::Decode { value } => describe(&value_or_stdin(value)?),
+        }
+    }
+}
+
+/// Encodes a payload of the given kind
+fn encode(kind: Kind, payload: &[u8]) -> Result<String, String> {
+    let wrong_length = |expected: usize| {
+        format!(
+            "Expected a {expected}-byte {}, found {} bytes",
+            kind_name(kind),
+            payload.len()
+        )
+    };
+    match kind {
+        Kind::Account => Ok(xrp::encode_account_id(
+            payload.try_into().map_err(|_| wrong_length(20))?,
+        )),
+        Kind::Seed => Ok(xrp::encode_seed(
+            payload.try_into().map_err(|_| wrong_length(16))?,
+        )),
+        Kind::NodePublic => Ok(xrp::encode_node_public(
+            payload.try_into().map_err(|_| wrong_length(33))?,
+        )),
+    }
+}
+
+/// Describes the type and payload of an encoded value
+fn describe(value: &str) -> Result<String, String> {
+    // Decoding as an account ID reports the actual prefix of anything else
+    let (kind, payload) = match xrp::decode_account_id(value) {
+        Ok(account) => (Kind::Account, account.to_vec()),
+        Err(XrpError::InvalidPrefix {
+            actual: xrp::FAMILY_SEED_PREFIX,
+            ..
+        }) => (
+            Kind::Seed,
+            xrp::decode_seed(value).map_err(describe_error)?.to_vec(),
+        ),
+        Err(XrpError::InvalidPrefix {
+            actual: xrp::NODE_PUBLIC_PREFIX,
+            ..
+        }) => (
+            Kind::NodePublic,
+            xrp::decode_node_public(value)
+                .map_err(describe_error)?
+                .to_vec(),
+        ),
+        Err(XrpError::InvalidPrefix { actual, .. }) => {
+            return Err(format!("Unknown XRP type prefix 0x{actual:02x}"));
+        }
+        Err(e) => return Err(describe_error(e)),
+    };
+    Ok(format!(
+        "type:    {}\n\
+         payload: {}",
+        kind_name(kind),
+        to_hex(&payload)
+    ))
+}
+
+/// Explains a decoding failure, pointing out likely typos
+fn describe_error(e: XrpError) -> String {
+    match e {
+        XrpError::Decode(DecodeError::ChecksumMismatch) => {
+            "Checksum mismatch: the value is mistyped or was not encoded for the XRP Ledger".into()
+        }
+        e => e.to_string(),
+    }
+}
+
+/// Names a kind of value for output and messages
+fn kind_name(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Account => "account ID",
+        Kind::Seed => "family seed",
+        Kind::NodePublic => "node public key",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let encode = parse(&args(&[
+            "encode",
+            "b5f762798a53d543a014caf8b297cff8f2f937e8",
+        ]));
+        let address = encode.unwrap().run().unwrap();
+        assert_eq!(address, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(
+            describe(&address).unwrap(),
+            "type:    account ID\npayload: b5f762798a53d543a014caf8b297cff8f2f937e8"
+        );
+
+        let encode = parse(&args(&[
+            "encode",
+            "--seed",
+            "dedce9ce67b451d852fd4e846fcde31c",
+        ]));
+        let seed = encode.unwrap().run().unwrap();
+        assert_eq!(seed, "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert!(
+            describe(&seed)
+                .unwrap()
+                .starts_with("type:    family seed\n")
+        );
+
+        let key = format!("02{}", "5a".repeat(32));
+        let node = parse(&args(&["encode", "--node", &key]))
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(
+            describe(&node).unwrap(),
+            format!("type:    node public key\npayload: {key}")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = describe("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj").unwrap_err();
+        assert!(err.starts_with("Checksum mismatch"), "{err}");
+        // A Bitcoin address uses a different alphabet, so its checksum fails
+        assert!(describe("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_err());
+        assert_eq!(
+            encode(Kind::Seed, &[0; 20]),
+            Err("Expected a 16-byte family seed, found 20 bytes".into())
+        );
+        assert!(parse(&args(&["decode", "--seed"])).is_err());
+        assert!(parse(&args(&["verify"])).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+//! Constant-time encoding and decoding for secret material.
+//!
+//! The regular codec skips leading zeros, stops at the first invalid character and looks
+//! characters up in a table indexed by their value, so its timing and memory access
+//! pattern depend on the data. These variants always run the full big-integer loops over
+//! fixed-size buffers, select alphabet characters with masks instead of indexing, and
+//! avoid data-dependent branches. Their running time depends only on the input length
+//! and the output length, which is public anyway.
+//!
+//! This is best effort: Rust makes no guarantees about the machine code the compiler
+//! emits, so verify the generated code if your threat model requires it.
+
+use crate::{Alphabet, DecodeError, encoded_len, max_decoded_len, wipe};
+
+/// Encodes bytes as Base58 in constant time using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The secret bytes to encode
+///
+/// # Returns
+///
+/// The same string as [`encode`](crate::encode)
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_ct, encode_ct};
+///
+/// let seed = [0x00, 0x2A, 0xFF, 0x10];
+/// let encoded = encode_ct(&seed);
+/// assert_eq!(encoded, b58::encode(&seed));
+/// assert_eq!(decode_ct(&encoded).unwrap(), seed);
+/// ```
+pub fn encode_ct(input: &[u8]) -> String {
+    encode_ct_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Encodes bytes as Base58 in constant time using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The secret bytes to encode
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Returns
+///
+/// The same string as [`encode_with_alphabet`](crate::encode_with_alphabet)
+pub fn encode_ct_with_alphabet(input: &[u8], alphabet: Alphabet) -> String {
+    let chars = alphabet.as_bytes();
+
+    // Base-58 digits, least significant first
+    let mut digits = vec![0u8; encoded_len(input.len())];
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+    }
+
+    // Digits past the significant ones are zero and map to the zero character
+    let len = leading_count(input, 0) + significant_len(&digits);
+    let encoded = (0..len)
+        .map(|i| select_char(chars, digits[len - 1 - i]) as char)
+        .collect();
+    wipe(&mut digits);
+    encoded
+}
+
+/// Decodes a Base58 string in constant time using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The same result as [`decode`](crate::decode)
+pub fn decode_ct(input: &str) -> Result<Vec<u8>, DecodeError> {
+    decode_ct_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 string in constant time using the specified alphabet.
+///
+/// Every character is processed even after an invalid one is found; only the final
+/// success or failure is branched on.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The same result as [`decode_with_alphabet`](crate::decode_with_alphabet)
+pub fn decode_ct_with_alphabet(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    let chars = alphabet.as_bytes();
+    let bytes = input.as_bytes();
+
+    // Decoded bytes, least significant first
+    let mut num = vec![0u8; max_decoded_len(bytes.len())];
+    let mut invalid_at = bytes.len();
+    for (i, &c) in bytes.iter().enumerate() {
+        let (digit, found) = select_digit(chars, c);
+        let first_invalid =
+            ((!found & 1) as usize & (invalid_at == bytes.len()) as usize).wrapping_neg();
+        invalid_at = (first_invalid & i) | (!first_invalid & invalid_at);
+
+        let mut carry = digit as u32;
+        for byte in num.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+    }
+
+    if invalid_at < bytes.len() {
+        wipe(&mut num);
+        // Every byte before the first invalid one is ASCII, so this is a char boundary
+        let character = input[invalid_at..]
+            .chars()
+            .next()
+            .expect("invalid position is within the input");
+        return Err(DecodeError::InvalidCharacter {
+            character,
+            position: invalid_at,
+        });
+    }
+
+    let len = leading_count(bytes, chars[0]) + significant_len(&num);
+    let decoded = (0..len).map(|i| num[len - 1 - i]).collect();
+    wipe(&mut num);
+    Ok(decoded)
+}
+
+/// Returns 0xFF if `a == b` and 0 otherwise, without branching
+fn ct_eq(a: u8, b: u8) -> u8 {
+    let x = (a ^ b) as u16;
+    (x.wrapping_sub(1) >> 8) as u8
+}
+
+/// Returns the alphabet character for `digit`, reading every alphabet entry
+fn select_char(chars: &[u8], digit: u8) -> u8 {
+    chars
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (k, &ch)| acc | (ch & ct_eq(k as u8, digit)))
+}
+
+/// Returns the digit value of `c` and 0xFF if it is in the alphabet, reading every entry
+fn select_digit(chars: &[u8], c: u8) -> (u8, u8) {
+    chars
+        .iter()
+        .enumerate()
+        .fold((0, 0), |(digit, found), (k, &ch)| {
+            let eq = ct_eq(ch, c);
+            (digit | (k as u8 & eq), found | eq)
+        })
+}
+
+/// Counts the leading bytes equal to `value`, reading every byte
+fn leading_count(bytes: &[u8], value: u8) -> usize {
+    let mut still_leading = 1;
+    let mut count = 0;
+    for &b in bytes {
+        still_leading &= (ct_eq(b, value) & 1) as usize;
+        count += still_leading;
+    }
+    count
+}
+
+/// Returns the number of digits up to the most significant nonzero one, reading every digit
+fn significant_len(digits: &[u8]) -> usize {
+    let mut len = 0;
+    for (j, &d) in digits.iter().enumerate() {
+        let nonzero = ((!ct_eq(d, 0) & 1) as usize).wrapping_neg();
+        len = (nonzero & (j + 1)) | (!nonzero & len);
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ct_eq() {
+        for a in 0..=255u8 {
+            for b in [0, 1, 57, 128, 255] {
+                assert_eq!(ct_eq(a, b), if a == b { 0xFF } else { 0 });
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_regular_codec() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            &[0],
+            &[0, 0, 0],
+            &[0, 0, 1, 2, 3],
+            b"Hello, World!",
+            &[0xFF; 64],
+        ];
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            for input in inputs {
+                let encoded = encode_ct_with_alphabet(input, alphabet);
+                assert_eq!(encoded, crate::encode_with_alphabet(input, alphabet));
+                assert_eq!(decode_ct_with_alphabet(&encoded, alphabet).unwrap(), input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reports_first_invalid_character() {
+        for input in ["9Ajd0vzO", "11O", "9Ajd€vzr", "0"] {
+            assert_eq!(decode_ct(input), crate::decode(input));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Formatting bytes as Base58 without building a `String`.
+
+use crate::{Alphabet, encode_slice_with_alphabet, encoded_len};
+
+/// Largest input, in bytes, that is formatted entirely on the stack.
+const STACK_INPUT_LEN: usize = 128;
+
+/// Formats bytes as Base58 through [`core::fmt::Display`].
+///
+/// Inputs of up to 128 bytes, which covers keys, hashes, signatures and addresses, are
+/// encoded into a stack buffer and never allocate. Longer inputs fall back to
+/// [`encode_with_alphabet`](crate::encode_with_alphabet) when the `std` feature is
+/// enabled; without it they fail with [`core::fmt::Error`].
+///
+/// Width, fill and alignment flags are honored.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, Base58Display};
+///
+/// let key = b"Hello";
+/// assert_eq!(format!("{}", Base58Display(key, Alphabet::Bitcoin)), "9Ajdvzr");
+/// assert_eq!(format!("[{:>9}]", Base58Display(key, Alphabet::Bitcoin)), "[  9Ajdvzr]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base58Display<'a>(pub &'a [u8], pub Alphabet);
+
+impl core::fmt::Display for Base58Display<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Base58Display(input, alphabet) = *self;
+
+        if input.len() <= STACK_INPUT_LEN {
+            let mut buf = [0u8; encoded_len(STACK_INPUT_LEN)];
+            let len = encode_slice_with_alphabet(input, &mut buf, alphabet)
+                .map_err(|_| core::fmt::Error)?;
+            let s = core::str::from_utf8(&buf[..len]).map_err(|_| core::fmt::Error)?;
+            return f.pad(s);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            f.pad(&crate::encode_with_alphabet(input, alphabet))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Err(core::fmt::Error)
+        }
+    }
+}
+
+/// Encodes bytes as Base58 directly into a [`core::fmt::Write`] such as a `String` or a
+/// `Formatter`.
+///
+/// This appends to existing output, for example while building a log line or URL, without
+/// the intermediate `String` that [`encode_with_alphabet`](crate::encode_with_alphabet)
+/// returns. Input size limits without the `std` feature are as for [`Base58Display`].
+///
+/// # Arguments
+///
+/// * `input` - The bytes to encode
+/// * `alphabet` - The alphabet to use for encoding
+/// * `out` - The writer that receives the Base58 output
+///
+/// # Returns
+///
+/// `Ok(())`, or the error returned by `out`
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, encode_to_fmt};
+///
+/// let mut url = String::from("https://example.com/tx/");
+/// encode_to_fmt(b"Hello", Alphabet::Bitcoin, &mut url).unwrap();
+/// assert_eq!(url, "https://example.com/tx/9Ajdvzr");
+/// ```
+pub fn encode_to_fmt<T, W>(input: T, alphabet: Alphabet, out: &mut W) -> core::fmt::Result
+where
+    T: AsRef<[u8]>,
+    W: core::fmt::Write + ?Sized,
+{
+    write!(out, "{}", Base58Display(input.as_ref(), alphabet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_encode() {
+        for len in [0, 1, 32, STACK_INPUT_LEN, STACK_INPUT_LEN + 1, 500] {
+            let input: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+            for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+                assert_eq!(
+                    Base58Display(&input, alphabet).to_string(),
+                    crate::encode_with_alphabet(&input, alphabet)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leading_zeros_and_padding() {
+        assert_eq!(
+            format!("{}", Base58Display(&[0, 0, 1], Alphabet::Bitcoin)),
+            "112"
+        );
+        assert_eq!(
+            format!("{:-<6}", Base58Display(&[0, 0, 1], Alphabet::Ripple)),
+            "rrp---"
+        );
+    }
+
+    #[test]
+    fn test_encode_to_fmt_appends() {
+        let mut out = String::from("id=");
+        encode_to_fmt([0, 0, 1], Alphabet::Bitcoin, &mut out).unwrap();
+        encode_to_fmt(vec![0xFF; 200], Alphabet::Flickr, &mut out).unwrap();
+        assert_eq!(
+            out,
+            format!(
+                "id=112{}",
+                crate::encode_with_alphabet([0xFF; 200], Alphabet::Flickr)
+            )
+        );
+    }
+}
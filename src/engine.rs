@@ -0,0 +1,410 @@
+//! Reusable Base58 configurations.
+
+use crate::{
+    Alphabet, DecodeError, decode_cb58, decode_check_with_alphabet, decode_with_alphabet,
+    encode_cb58, encode_check_with_alphabet, encode_with_alphabet,
+};
+
+/// The checksum an engine appends when encoding and verifies when decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Checksum {
+    /// Plain Base58 without a checksum.
+    #[default]
+    None,
+    /// Base58Check: the first four bytes of the double SHA-256 of the payload.
+    Base58Check,
+    /// CB58: the last four bytes of the single SHA-256 of the payload. CB58 is only
+    /// defined for the Bitcoin alphabet, so the engine's alphabet is ignored.
+    Cb58,
+}
+
+/// How an engine treats leading zero bytes when decoding.
+///
+/// Each leading zero character of a Base58 string decodes to a zero byte. Some legacy
+/// systems drop those bytes or never produce them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeadingZeros {
+    /// Keep a zero byte for every leading zero character.
+    #[default]
+    Preserve,
+    /// Remove leading zero bytes from the decoded payload.
+    Strip,
+    /// Fail with `DecodeError::LeadingZeros` if the decoded payload starts with a zero
+    /// byte.
+    Reject,
+}
+
+/// A reusable Base58 configuration.
+///
+/// An engine bundles the alphabet, checksum mode and size limits so they can be chosen
+/// once, stored in a `const`, and passed around instead of repeating arguments at each
+/// call site. The free functions such as [`encode`](crate::encode) and
+/// [`decode_check`](crate::decode_check) behave like the predefined engines.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, Base58Engine, Checksum};
+///
+/// const RIPPLE_CHECK: Base58Engine = Base58Engine::new(Alphabet::Ripple)
+///     .with_checksum(Checksum::Base58Check)
+///     .with_max_decoded_len(64);
+///
+/// let encoded = RIPPLE_CHECK.encode(b"payload");
+/// assert_eq!(RIPPLE_CHECK.decode(&encoded).unwrap(), b"payload");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58Engine {
+    alphabet: Alphabet,
+    checksum: Checksum,
+    max_decoded_len: Option<usize>,
+    ignore_whitespace: bool,
+    leading_zeros: LeadingZeros,
+}
+
+impl Base58Engine {
+    /// Plain Base58 with the Bitcoin alphabet, like [`encode`](crate::encode).
+    pub const BITCOIN: Base58Engine = Base58Engine::new(Alphabet::Bitcoin);
+    /// Plain Base58 with the Ripple alphabet.
+    pub const RIPPLE: Base58Engine = Base58Engine::new(Alphabet::Ripple);
+    /// Plain Base58 with the Flickr alphabet.
+    pub const FLICKR: Base58Engine = Base58Engine::new(Alphabet::Flickr);
+    /// Base58Check with the Bitcoin alphabet, like [`encode_check`](crate::encode_check).
+    pub const BITCOIN_CHECK: Base58Engine =
+        Base58Engine::new(Alphabet::Bitcoin).with_checksum(Checksum::Base58Check);
+
+    /// Creates an engine for the given alphabet, without a checksum or size limit.
+    pub const fn new(alphabet: Alphabet) -> Self {
+        Base58Engine {
+            alphabet,
+            checksum: Checksum::None,
+            max_decoded_len: None,
+            ignore_whitespace: false,
+            leading_zeros: LeadingZeros::Preserve,
+        }
+    }
+
+    /// Returns a copy of the engine using the given checksum mode.
+    pub const fn with_checksum(mut self, checksum: Checksum) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Returns a copy of the engine that rejects inputs decoding to more than `limit`
+    /// payload bytes.
+    ///
+    /// Inputs that are certain to exceed the limit are rejected from their length alone,
+    /// before any big-integer arithmetic is done.
+    pub const fn with_max_decoded_len(mut self, limit: usize) -> Self {
+        self.max_decoded_len = Some(limit);
+        self
+    }
+
+    /// Returns a copy of the engine that skips ASCII whitespace, including newlines,
+    /// when decoding.
+    ///
+    /// This lets wrapped or copy-pasted strings decode without stripping them first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use b58::Base58Engine;
+    ///
+    /// let engine = Base58Engine::BITCOIN.with_ignore_whitespace(true);
+    /// assert_eq!(engine.decode(" 9Ajd\n vzr\r\n").unwrap(), b"Hello");
+    /// ```
+    pub const fn with_ignore_whitespace(mut self, ignore: bool) -> Self {
+        self.ignore_whitespace = ignore;
+        self
+    }
+
+    /// Returns a copy of the engine that handles leading zero bytes according to `policy`
+    /// when decoding.
+    ///
+    /// With a checksum, the policy applies to the payload after the checksum is verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use b58::{Base58Engine, DecodeError, LeadingZeros};
+    ///
+    /// let strip = Base58Engine::BITCOIN.with_leading_zeros(LeadingZeros::Strip);
+    /// assert_eq!(strip.decode("11Ldp").unwrap(), [1, 2, 3]);
+    ///
+    /// let reject = Base58Engine::BITCOIN.with_leading_zeros(LeadingZeros::Reject);
+    /// assert_eq!(reject.decode("11Ldp"), Err(DecodeError::LeadingZeros));
+    /// ```
+    pub const fn with_leading_zeros(mut self, policy: LeadingZeros) -> Self {
+        self.leading_zeros = policy;
+        self
+    }
+
+    /// Returns the engine's alphabet.
+    pub const fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Returns the engine's checksum mode.
+    pub const fn checksum(&self) -> Checksum {
+        self.checksum
+    }
+
+    /// Returns the engine's decoded size limit, if any.
+    pub const fn max_decoded_len(&self) -> Option<usize> {
+        self.max_decoded_len
+    }
+
+    /// Returns whether the engine skips ASCII whitespace when decoding.
+    pub const fn ignore_whitespace(&self) -> bool {
+        self.ignore_whitespace
+    }
+
+    /// Returns the engine's leading zero policy.
+    pub const fn leading_zeros(&self) -> LeadingZeros {
+        self.leading_zeros
+    }
+
+    /// Encodes bytes with the engine's alphabet and checksum.
+    pub fn encode(&self, input: &[u8]) -> String {
+        match self.checksum {
+            Checksum::None => encode_with_alphabet(input, self.alphabet),
+            Checksum::Base58Check => encode_check_with_alphabet(input, self.alphabet),
+            Checksum::Cb58 => encode_cb58(input),
+        }
+    }
+
+    /// Decodes a string with the engine's alphabet, checksum and size limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The Base58 string to decode
+    ///
+    /// # Returns
+    ///
+    /// The decoded payload with any checksum verified and removed, or a `DecodeError`.
+    /// `DecodeError::InputTooLarge` is returned if the payload exceeds the size limit.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        if self.ignore_whitespace && input.bytes().any(|b| b.is_ascii_whitespace()) {
+            let stripped: String = input.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+            return self.decode_stripped(&stripped).map_err(|e| match e {
+                DecodeError::InvalidCharacter {
+                    character,
+                    position,
+                } => DecodeError::InvalidCharacter {
+                    character,
+                    position: original_position(input, position),
+                },
+                e => e,
+            });
+        }
+        self.decode_stripped(input)
+    }
+
+    /// Decodes input that contains no whitespace to skip
+    fn decode_stripped(&self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        if let Some(limit) = self.max_decoded_len {
+            let checksum_len = match self.checksum {
+                Checksum::None => 0,
+                Checksum::Base58Check | Checksum::Cb58 => crate::CHECKSUM_LEN,
+            };
+            let zero = match self.checksum {
+                Checksum::Cb58 => Alphabet::Bitcoin.as_bytes()[0],
+                _ => self.alphabet.as_bytes()[0],
+            };
+            // Stripped zero bytes do not count toward the limit
+            let counted = match self.leading_zeros {
+                LeadingZeros::Strip => input.trim_start_matches(zero as char),
+                _ => input,
+            };
+            if decoded_len_lower_bound(counted, zero) > limit.saturating_add(checksum_len) {
+                return Err(DecodeError::InputTooLarge);
+            }
+        }
+
+        let mut decoded = match self.checksum {
+            Checksum::None => decode_with_alphabet(input, self.alphabet)?,
+            Checksum::Base58Check => decode_check_with_alphabet(input, self.alphabet)?,
+            Checksum::Cb58 => decode_cb58(input)?,
+        };
+
+        match self.leading_zeros {
+            LeadingZeros::Preserve => {}
+            LeadingZeros::Strip => {
+                let zeros = decoded.iter().take_while(|&&b| b == 0).count();
+                decoded.drain(..zeros);
+            }
+            LeadingZeros::Reject => {
+                if decoded.first() == Some(&0) {
+                    return Err(DecodeError::LeadingZeros);
+                }
+            }
+        }
+
+        match self.max_decoded_len {
+            Some(limit) if decoded.len() > limit => Err(DecodeError::InputTooLarge),
+            _ => Ok(decoded),
+        }
+    }
+}
+
+impl Default for Base58Engine {
+    fn default() -> Self {
+        Base58Engine::BITCOIN
+    }
+}
+
+/// Maps a byte offset in `input` with ASCII whitespace removed back to an offset in `input`
+fn original_position(input: &str, stripped_position: usize) -> usize {
+    input
+        .char_indices()
+        .filter(|(_, c)| !c.is_ascii_whitespace())
+        .nth(stripped_position)
+        .map_or(input.len(), |(i, _)| i)
+}
+
+/// Returns a lower bound on the decoded length of `input` without decoding it.
+///
+/// Each leading `zero` character decodes to one byte. The remaining `n` characters are a
+/// number of at least 58^(n - 1), which needs more than (n - 1) * log(58) / log(256)
+/// bytes, and log(58) / log(256) > 0.732.
+fn decoded_len_lower_bound(input: &str, zero: u8) -> usize {
+    let bytes = input.as_bytes();
+    let leading = bytes.iter().take_while(|&&b| b == zero).count();
+    match bytes.len() - leading {
+        0 => leading,
+        rest => leading + (rest - 1) * 732 / 1000 + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predefined_engines_match_free_functions() {
+        let data = b"\x00\x00Hello, World!";
+        assert_eq!(Base58Engine::BITCOIN.encode(data), crate::encode(data));
+        assert_eq!(
+            Base58Engine::RIPPLE.encode(data),
+            encode_with_alphabet(data, Alphabet::Ripple)
+        );
+        assert_eq!(
+            Base58Engine::BITCOIN_CHECK.encode(data),
+            crate::encode_check(data)
+        );
+        assert_eq!(Base58Engine::default().decode("9Ajdvzr").unwrap(), b"Hello");
+    }
+
+    #[test]
+    fn test_checksum_modes() {
+        for checksum in [Checksum::None, Checksum::Base58Check, Checksum::Cb58] {
+            let engine = Base58Engine::new(Alphabet::Flickr).with_checksum(checksum);
+            let encoded = engine.encode(b"payload");
+            assert_eq!(engine.decode(&encoded).unwrap(), b"payload");
+        }
+
+        let cb58 = Base58Engine::BITCOIN.with_checksum(Checksum::Cb58);
+        assert_eq!(cb58.encode(b"Hello"), "vSxRbq4dWoKv");
+        assert_eq!(
+            Base58Engine::BITCOIN_CHECK.decode("vSxRbq4dWoKv"),
+            Err(DecodeError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let engine = Base58Engine::BITCOIN.with_max_decoded_len(5);
+        assert_eq!(engine.decode("9Ajdvzr").unwrap(), b"Hello");
+        assert_eq!(
+            engine.decode(&crate::encode(b"Hello!")),
+            Err(DecodeError::InputTooLarge)
+        );
+        // Rejected from the input length alone, without decoding
+        assert_eq!(
+            engine.decode(&"z".repeat(10_000)),
+            Err(DecodeError::InputTooLarge)
+        );
+
+        let check = Base58Engine::BITCOIN_CHECK.with_max_decoded_len(5);
+        assert_eq!(
+            check.decode(&crate::encode_check(b"Hello")).unwrap(),
+            b"Hello"
+        );
+    }
+
+    #[test]
+    fn test_ignore_whitespace() {
+        let wrapped = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqv\n\tbTLvyTJ \r\n";
+        assert_eq!(
+            Base58Engine::BITCOIN_CHECK.decode(wrapped),
+            Err(DecodeError::InvalidCharacter {
+                character: '\n',
+                position: 44
+            })
+        );
+
+        let lenient = Base58Engine::BITCOIN_CHECK.with_ignore_whitespace(true);
+        assert_eq!(
+            lenient.decode(wrapped).unwrap(),
+            crate::decode_check("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap()
+        );
+
+        let lenient = Base58Engine::BITCOIN.with_ignore_whitespace(true);
+        assert_eq!(lenient.decode(" \n ").unwrap(), b"");
+        // Positions refer to the input as given, not the stripped string
+        assert_eq!(
+            lenient.decode("9A jd\n0vzr"),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 6
+            })
+        );
+        // Only ASCII whitespace is skipped
+        assert_eq!(
+            lenient.decode("9Ajd\u{00a0}vzr"),
+            Err(DecodeError::InvalidCharacter {
+                character: '\u{00a0}',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_leading_zeros_policy() {
+        let encoded = crate::encode([0, 0, 7, 0]);
+        let engine = Base58Engine::BITCOIN;
+        assert_eq!(engine.leading_zeros(), LeadingZeros::Preserve);
+        assert_eq!(engine.decode(&encoded).unwrap(), [0, 0, 7, 0]);
+
+        let strip = engine.with_leading_zeros(LeadingZeros::Strip);
+        assert_eq!(strip.decode(&encoded).unwrap(), [7, 0]);
+        assert_eq!(strip.decode("111").unwrap(), b"");
+        // Stripped bytes are not counted against the size limit
+        let limited = strip.with_max_decoded_len(2);
+        assert_eq!(
+            limited
+                .decode(&format!("{}{encoded}", "1".repeat(50)))
+                .unwrap(),
+            [7, 0]
+        );
+
+        let reject = engine.with_leading_zeros(LeadingZeros::Reject);
+        assert_eq!(reject.decode(&encoded), Err(DecodeError::LeadingZeros));
+        assert_eq!(reject.decode("Ldp").unwrap(), [1, 2, 3]);
+
+        // With a checksum the policy applies to the verified payload
+        let check = Base58Engine::BITCOIN_CHECK.with_leading_zeros(LeadingZeros::Strip);
+        assert_eq!(check.decode(&crate::encode_check([0, 9])).unwrap(), [9]);
+    }
+
+    #[test]
+    fn test_lower_bound_is_never_too_large() {
+        for len in 0..200 {
+            for fill in [0x01, 0xFF] {
+                let data: Vec<u8> = (0..len).map(|i| if i < 3 { 0 } else { fill }).collect();
+                let encoded = crate::encode(&data);
+                assert!(decoded_len_lower_bound(&encoded, b'1') <= data.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! A fixed-size byte array displayed and parsed as Base58.
+
+use crate::{Alphabet, Base58Display, DecodeError, decode_array};
+
+/// A `[u8; N]` that displays as Base58 and parses only from strings decoding to exactly
+/// `N` bytes.
+///
+/// This gives hashes, keys and other fixed-length values a distinct type without
+/// writing the conversions by hand. The Bitcoin alphabet is used throughout, and the type
+/// works without the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use b58::FixedBase58;
+///
+/// type Hash = FixedBase58<5>;
+///
+/// let hash: Hash = "9Ajdvzr".parse().unwrap();
+/// assert_eq!(hash.as_bytes(), b"Hello");
+/// assert_eq!(hash.to_string(), "9Ajdvzr");
+///
+/// // Strings of the wrong length are rejected
+/// assert!("9Ajdvzr".parse::<FixedBase58<4>>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedBase58<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedBase58<N> {
+    /// Number of bytes in the value.
+    pub const LEN: usize = N;
+
+    /// Creates a value from its raw bytes.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        FixedBase58(bytes)
+    }
+
+    /// Returns the raw bytes.
+    pub const fn to_bytes(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns a reference to the raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Default for FixedBase58<N> {
+    fn default() -> Self {
+        FixedBase58([0; N])
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedBase58<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        FixedBase58(bytes)
+    }
+}
+
+impl<const N: usize> From<FixedBase58<N>> for [u8; N] {
+    fn from(value: FixedBase58<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBase58<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedBase58<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Base58Display(&self.0, Alphabet::Bitcoin), f)
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedBase58<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FixedBase58({self})")
+    }
+}
+
+impl<const N: usize> core::str::FromStr for FixedBase58<N> {
+    type Err = DecodeError;
+
+    /// Parses a Base58 string, failing unless it decodes to exactly `N` bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_array(s).map(FixedBase58)
+    }
+}
+
+/// Serializes like [`serde::as_base58`](crate::serde::as_base58).
+#[cfg(feature = "serde")]
+impl<const N: usize> ::serde::Serialize for FixedBase58<N> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::as_base58::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserializes like [`serde::as_base58`](crate::serde::as_base58), failing unless
+/// exactly `N` bytes are decoded.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> ::serde::Deserialize<'de> for FixedBase58<N> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde::as_base58::deserialize(deserializer).map(FixedBase58)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let value = FixedBase58::new([0, 0, 1, 2, 3]);
+        let s = value.to_string();
+        assert_eq!(s, "11Ldp");
+        assert_eq!(s.parse::<FixedBase58<5>>().unwrap(), value);
+        assert_eq!(format!("{value:?}"), "FixedBase58(11Ldp)");
+        assert_eq!(<[u8; 5]>::from(value), [0, 0, 1, 2, 3]);
+        assert_eq!(FixedBase58::<64>::LEN, 64);
+    }
+
+    #[test]
+    fn test_length_enforced() {
+        assert_eq!(
+            "11Ldp".parse::<FixedBase58<4>>(),
+            Err(DecodeError::InvalidLength {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert!("11Ldp".parse::<FixedBase58<6>>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let value: FixedBase58<5> = serde_json::from_str(r#""9Ajdvzr""#).unwrap();
+        assert_eq!(value.as_bytes(), b"Hello");
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""9Ajdvzr""#);
+        assert!(serde_json::from_str::<FixedBase58<4>>(r#""9Ajdvzr""#).is_err());
+    }
+}
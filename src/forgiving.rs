@@ -0,0 +1,140 @@
+//! Decoding that corrects visually ambiguous characters.
+
+use crate::{Alphabet, DecodeError, decode_with_alphabet};
+
+/// Characters that are easily mistaken for each other, in order of preference.
+const LOOKALIKES: [(char, &[char]); 6] = [
+    ('0', &['o', 'O']),
+    ('O', &['o', '0']),
+    ('o', &['O', '0']),
+    ('I', &['1', 'l', 'i']),
+    ('l', &['1', 'I', 'i']),
+    ('1', &['l', 'I', 'i']),
+];
+
+/// A character replaced by [`decode_forgiving`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    /// Byte offset of the character in the input.
+    pub offset: usize,
+    /// The character found in the input.
+    pub from: char,
+    /// The alphabet character it was replaced with.
+    pub to: char,
+}
+
+/// Decodes a Base58 string, replacing characters that are not in the alphabet with
+/// look-alikes that are.
+///
+/// Base58 alphabets leave out characters such as `0`, `O`, `I` and `l` because they are
+/// easy to confuse, but people still type them when copying strings from paper. This
+/// maps each such character to the most likely alphabet character (for the Bitcoin
+/// alphabet `0` and `O` become `o`, and `I` and `l` become `1`) and reports every
+/// replacement so the caller can ask the user to confirm. Pair it with a checksum to
+/// catch wrong guesses.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes and the substitutions made, or `DecodeError::InvalidCharacter` if a
+/// character is neither in the alphabet nor a look-alike of one that is
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_forgiving, Alphabet, Substitution};
+///
+/// let (bytes, subs) = decode_forgiving("9AjdvzI", Alphabet::Bitcoin).unwrap();
+/// assert_eq!(bytes, b58::decode("9Ajdvz1").unwrap());
+/// assert_eq!(subs, [Substitution { offset: 6, from: 'I', to: '1' }]);
+/// ```
+pub fn decode_forgiving(
+    input: &str,
+    alphabet: Alphabet,
+) -> Result<(Vec<u8>, Vec<Substitution>), DecodeError> {
+    let table = alphabet.decode_table();
+    let in_alphabet = |c: char| c.is_ascii() && table[c as usize] != 255;
+
+    let mut corrected = String::with_capacity(input.len());
+    let mut substitutions = Vec::new();
+    for (offset, c) in input.char_indices() {
+        if in_alphabet(c) {
+            corrected.push(c);
+            continue;
+        }
+
+        let replacement = LOOKALIKES
+            .iter()
+            .find(|(from, _)| *from == c)
+            .and_then(|(_, candidates)| candidates.iter().copied().find(|&to| in_alphabet(to)))
+            .ok_or(DecodeError::InvalidCharacter {
+                character: c,
+                position: offset,
+            })?;
+        corrected.push(replacement);
+        substitutions.push(Substitution {
+            offset,
+            from: c,
+            to: replacement,
+        });
+    }
+
+    let bytes = decode_with_alphabet(&corrected, alphabet)?;
+    Ok((bytes, substitutions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_input_is_unchanged() {
+        let (bytes, subs) = decode_forgiving("9Ajdvzr", Alphabet::Bitcoin).unwrap();
+        assert_eq!(bytes, b"Hello");
+        assert!(subs.is_empty());
+    }
+
+    #[test]
+    fn test_substitutions_per_alphabet() {
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let (bytes, subs) = decode_forgiving("0OIl", alphabet).unwrap();
+            assert_eq!(bytes, decode_with_alphabet("oo11", alphabet).unwrap());
+            let pairs: Vec<_> = subs.iter().map(|s| (s.offset, s.from, s.to)).collect();
+            assert_eq!(
+                pairs,
+                [(0, '0', 'o'), (1, 'O', 'o'), (2, 'I', '1'), (3, 'l', '1')]
+            );
+        }
+    }
+
+    #[test]
+    fn test_mistyped_address() {
+        // An address with its leading '1' mistyped as 'l' decodes to the original bytes
+        let typed = "lBvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        let (bytes, subs) = decode_forgiving(typed, Alphabet::Bitcoin).unwrap();
+        assert_eq!(subs.len(), 1);
+        assert_eq!(crate::encode(&bytes), "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+    }
+
+    #[test]
+    fn test_unmappable_characters() {
+        assert_eq!(
+            decode_forgiving("9Ajd+vzr", Alphabet::Bitcoin),
+            Err(DecodeError::InvalidCharacter {
+                character: '+',
+                position: 4
+            })
+        );
+        assert_eq!(
+            decode_forgiving("é", Alphabet::Bitcoin),
+            Err(DecodeError::InvalidCharacter {
+                character: 'é',
+                position: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Stateful codecs for input that arrives in pieces or is processed in bulk.
+
+use crate::baseconv::{limbs_to_bytes, mul_add};
+use crate::{Alphabet, DecodeError, decode_digit, wipe};
+
+/// Decodes Base58 that arrives in chunks, such as reads from a network socket.
+///
+/// Each character is folded into the result as it is pushed, so only the decoded value is
+/// kept between chunks rather than the whole string. Chunks may split the input anywhere,
+/// and the result is the same as [`decode_with_alphabet`](crate::decode_with_alphabet) of
+/// the concatenated chunks.
+///
+/// For whole strings, [`decode`](Decoder::decode) reuses the decoder's work and output
+/// buffers, so decoding many strings with one decoder allocates only while the buffers
+/// grow.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, Decoder};
+///
+/// let mut decoder = Decoder::new(Alphabet::Bitcoin);
+/// decoder.push_str("JxF12Tr")?;
+/// decoder.push_str("wUP45BMd")?;
+/// assert_eq!(decoder.finish(), b"Hello World");
+///
+/// assert_eq!(decoder.decode("9Ajdvzr")?, b"Hello");
+/// # Ok::<(), b58::DecodeError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    alphabet: Alphabet,
+    leading_zeros: usize,
+    /// The value of the digits after the leading zeros, as little-endian 32-bit limbs
+    num: Vec<u32>,
+    position: usize,
+    out: Vec<u8>,
+}
+
+impl Decoder {
+    /// Creates a decoder for the specified alphabet.
+    pub fn new(alphabet: Alphabet) -> Self {
+        Decoder {
+            alphabet,
+            leading_zeros: 0,
+            num: Vec::new(),
+            position: 0,
+            out: Vec::new(),
+        }
+    }
+
+    /// Returns the decoder's alphabet.
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Decodes the next chunk of input.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk` - The next part of the Base58 string
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `DecodeError::InvalidCharacter` with the character's byte offset from
+    /// the start of the first chunk. The decoder holds a partial value after an error and
+    /// should be [`reset`](Decoder::reset) before reuse.
+    pub fn push_str(&mut self, chunk: &str) -> Result<(), DecodeError> {
+        let decode_table = self.alphabet.decode_table();
+        for (i, c) in chunk.char_indices() {
+            let digit = decode_digit(decode_table, c, self.position + i)?;
+
+            // Leading zero characters map directly to zero bytes
+            if digit == 0 && self.num.is_empty() {
+                self.leading_zeros += 1;
+                continue;
+            }
+            mul_add(&mut self.num, 58, digit as u32);
+        }
+        self.position += chunk.len();
+        Ok(())
+    }
+
+    /// Returns the decoded bytes and resets the decoder for new input.
+    pub fn finish(&mut self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.leading_zeros + self.num.len() * 4);
+        self.write_output(&mut out);
+        self.reset();
+        out
+    }
+
+    /// Decodes a whole string into the decoder's output buffer.
+    ///
+    /// Any pushed input is discarded first. The returned bytes stay valid until the next
+    /// call that changes the decoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The Base58 string to decode
+    ///
+    /// # Returns
+    ///
+    /// The decoded bytes, or `DecodeError::InvalidCharacter` if a character is not in the
+    /// alphabet
+    pub fn decode(&mut self, input: &str) -> Result<&[u8], DecodeError> {
+        self.reset();
+        if let Err(e) = self.push_str(input) {
+            self.reset();
+            return Err(e);
+        }
+
+        let mut out = core::mem::take(&mut self.out);
+        wipe(&mut out);
+        out.clear();
+        self.write_output(&mut out);
+        self.out = out;
+        self.reset();
+        Ok(&self.out)
+    }
+
+    /// Appends the decoded value to `out`
+    fn write_output(&self, out: &mut Vec<u8>) {
+        out.resize(out.len() + self.leading_zeros, 0);
+        limbs_to_bytes(&self.num, out);
+    }
+
+    /// Discards any pushed input.
+    pub fn reset(&mut self) {
+        wipe(&mut self.num);
+        self.num.clear();
+        self.leading_zeros = 0;
+        self.position = 0;
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new(Alphabet::Bitcoin)
+    }
+}
+
+/// Encodes bytes that arrive in chunks as plain Base58.
+///
+/// Each byte is folded into the Base58 digits as it is pushed, so the work is spread over
+/// the pushes and only the digits are kept between them. No output can be produced before
+/// [`finish`](Encoder::finish), because every digit depends on the last byte; use
+/// [`Base58EncodeWriter`](crate::Base58EncodeWriter) when output must be written while the
+/// input is still arriving.
+///
+/// For whole inputs, [`encode`](Encoder::encode) reuses the encoder's work and output
+/// buffers, so encoding many inputs with one encoder allocates only while the buffers
+/// grow.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, Encoder};
+///
+/// let mut encoder = Encoder::new(Alphabet::Bitcoin);
+/// encoder.push_bytes(b"Hello");
+/// encoder.push_bytes(b" World");
+/// assert_eq!(encoder.finish(), "JxF12TrwUP45BMd");
+///
+/// assert_eq!(encoder.encode(b"Hello"), "9Ajdvzr");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    alphabet: Alphabet,
+    leading_zeros: usize,
+    /// Base58 digit values, least significant first
+    digits: Vec<u8>,
+    out: String,
+}
+
+impl Encoder {
+    /// Creates an encoder for the specified alphabet.
+    pub fn new(alphabet: Alphabet) -> Self {
+        Encoder {
+            alphabet,
+            leading_zeros: 0,
+            digits: Vec::new(),
+            out: String::new(),
+        }
+    }
+
+    /// Returns the encoder's alphabet.
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Encodes the next chunk of input.
+    pub fn push_bytes(&mut self, chunk: &[u8]) {
+        for &byte in chunk {
+            // Leading zero bytes map directly to the zero character
+            if byte == 0 && self.digits.is_empty() {
+                self.leading_zeros += 1;
+                continue;
+            }
+
+            // Multiply by 256 and add the byte
+            let mut carry = byte as u32;
+            for digit in self.digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                self.digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+    }
+
+    /// Returns the encoded string and resets the encoder for new input.
+    pub fn finish(&mut self) -> String {
+        let mut out = String::with_capacity(self.leading_zeros + self.digits.len());
+        self.write_output(&mut out);
+        self.reset();
+        out
+    }
+
+    /// Encodes a whole input into the encoder's output buffer.
+    ///
+    /// Any pushed input is discarded first. The returned string stays valid until the next
+    /// call that changes the encoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The bytes to encode
+    ///
+    /// # Returns
+    ///
+    /// The Base58 encoded string
+    pub fn encode(&mut self, input: &[u8]) -> &str {
+        self.reset();
+        self.push_bytes(input);
+
+        let mut out = core::mem::take(&mut self.out);
+        out.clear();
+        self.write_output(&mut out);
+        self.out = out;
+        self.reset();
+        &self.out
+    }
+
+    /// Appends the encoded characters to `out`
+    fn write_output(&self, out: &mut String) {
+        let alphabet = self.alphabet.as_bytes();
+        out.extend(core::iter::repeat_n(
+            alphabet[0] as char,
+            self.leading_zeros,
+        ));
+        out.extend(
+            self.digits
+                .iter()
+                .rev()
+                .map(|&d| alphabet[d as usize] as char),
+        );
+    }
+
+    /// Discards any pushed input.
+    pub fn reset(&mut self) {
+        wipe(&mut self.digits);
+        self.digits.clear();
+        self.leading_zeros = 0;
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder::new(Alphabet::Bitcoin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_decode() {
+        let input: Vec<u8> = [0, 0]
+            .into_iter()
+            .chain((1..200).map(|i| i as u8))
+            .collect();
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let encoded = crate::encode_with_alphabet(&input, alphabet);
+            for split in [1, 2, 7, encoded.len()] {
+                let mut decoder = Decoder::new(alphabet);
+                for chunk in encoded.as_bytes().chunks(split) {
+                    decoder
+                        .push_str(core::str::from_utf8(chunk).unwrap())
+                        .unwrap();
+                }
+                assert_eq!(decoder.finish(), input, "split {split}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_cases_and_reuse() {
+        let mut decoder = Decoder::default();
+        assert_eq!(decoder.finish(), b"");
+        decoder.push_str("111").unwrap();
+        assert_eq!(decoder.finish(), [0, 0, 0]);
+
+        decoder.push_str("11L").unwrap();
+        assert_eq!(
+            decoder.push_str("d0p"),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 4
+            })
+        );
+        decoder.reset();
+        decoder.push_str("Ldp").unwrap();
+        assert_eq!(decoder.finish(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_encoder_matches_encode() {
+        let input: Vec<u8> = [0, 0, 0]
+            .into_iter()
+            .chain((0..300).map(|i| (i * 7 + 1) as u8))
+            .collect();
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            for split in [1, 2, 7, input.len()] {
+                let mut encoder = Encoder::new(alphabet);
+                for chunk in input.chunks(split) {
+                    encoder.push_bytes(chunk);
+                }
+                let encoded = encoder.finish();
+                assert_eq!(encoded, crate::encode_with_alphabet(&input, alphabet));
+            }
+        }
+
+        let mut encoder = Encoder::default();
+        assert_eq!(encoder.finish(), "");
+        encoder.push_bytes(&[0, 0]);
+        assert_eq!(encoder.finish(), "11");
+        encoder.push_bytes(&[0, 1, 2, 3]);
+        encoder.reset();
+        encoder.push_bytes(&[1, 2, 3]);
+        assert_eq!(encoder.finish(), "Ldp");
+    }
+
+    #[test]
+    fn test_reusable_encode_and_decode() {
+        let mut encoder = Encoder::new(Alphabet::Flickr);
+        let mut decoder = Decoder::new(Alphabet::Flickr);
+        for len in (0..64).rev() {
+            let input: Vec<u8> = (0..len).map(|i| (i * 29 + len) as u8).collect();
+            let encoded = encoder.encode(&input).to_owned();
+            assert_eq!(
+                encoded,
+                crate::encode_with_alphabet(&input, Alphabet::Flickr)
+            );
+            assert_eq!(decoder.decode(&encoded).unwrap(), input);
+        }
+
+        // Pushed input is discarded, and an error leaves the decoder usable
+        decoder.push_str("zzz").unwrap();
+        assert_eq!(
+            decoder.decode("1l"),
+            Err(DecodeError::InvalidCharacter {
+                character: 'l',
+                position: 1
+            })
+        );
+        assert_eq!(decoder.decode("12").unwrap(), [0, 1]);
+        encoder.push_bytes(b"zzz");
+        assert_eq!(encoder.encode(&[0, 1]), "12");
+    }
+}
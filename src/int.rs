@@ -0,0 +1,159 @@
+//! Encoding integers directly, without going through a byte slice.
+
+use crate::{Alphabet, DecodeError, decode_digit};
+
+/// Largest number of Base58 digits in a `u128`.
+#[cfg(feature = "std")]
+const U128_MAX_DIGITS: usize = 22;
+
+/// Encodes a `u64` as Base58 using the default Bitcoin alphabet.
+///
+/// The integer is treated as a big-endian number without leading zero bytes, so the
+/// result matches [`encode`](crate::encode) of its minimal byte representation. Zero is
+/// encoded as the zero character rather than an empty string.
+///
+/// # Arguments
+///
+/// * `n` - The integer to encode
+///
+/// # Returns
+///
+/// A Base58 encoded string
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_u64, encode_u64};
+///
+/// assert_eq!(encode_u64(0), "1");
+/// assert_eq!(encode_u64(57), "z");
+/// assert_eq!(decode_u64(&encode_u64(u64::MAX)).unwrap(), u64::MAX);
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_u64(n: u64) -> String {
+    encode_u128_with_alphabet(n as u128, Alphabet::Bitcoin)
+}
+
+/// Encodes a `u64` as Base58 using the specified alphabet.
+#[cfg(feature = "std")]
+pub fn encode_u64_with_alphabet(n: u64, alphabet: Alphabet) -> String {
+    encode_u128_with_alphabet(n as u128, alphabet)
+}
+
+/// Encodes a `u128` as Base58 using the default Bitcoin alphabet.
+///
+/// See [`encode_u64`] for how the integer is represented.
+#[cfg(feature = "std")]
+pub fn encode_u128(n: u128) -> String {
+    encode_u128_with_alphabet(n, Alphabet::Bitcoin)
+}
+
+/// Encodes a `u128` as Base58 using the specified alphabet.
+#[cfg(feature = "std")]
+pub fn encode_u128_with_alphabet(mut n: u128, alphabet: Alphabet) -> String {
+    let chars = alphabet.as_bytes();
+    let mut buf = [0u8; U128_MAX_DIGITS];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = chars[(n % 58) as usize];
+        n /= 58;
+        if n == 0 {
+            break;
+        }
+    }
+    buf[start..].iter().map(|&b| b as char).collect()
+}
+
+/// Decodes a Base58 string into a `u64` using the default Bitcoin alphabet.
+///
+/// Leading zero characters are allowed and do not change the value.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded integer, `DecodeError::EmptyInput` if the input is empty, or
+/// `DecodeError::Overflow` if the value does not fit in a `u64`
+pub fn decode_u64(input: &str) -> Result<u64, DecodeError> {
+    decode_u64_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 string into a `u64` using the specified alphabet.
+pub fn decode_u64_with_alphabet(input: &str, alphabet: Alphabet) -> Result<u64, DecodeError> {
+    decode_u128_with_alphabet(input, alphabet)?
+        .try_into()
+        .map_err(|_| DecodeError::Overflow)
+}
+
+/// Decodes a Base58 string into a `u128` using the default Bitcoin alphabet.
+///
+/// See [`decode_u64`] for the accepted input.
+pub fn decode_u128(input: &str) -> Result<u128, DecodeError> {
+    decode_u128_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 string into a `u128` using the specified alphabet.
+pub fn decode_u128_with_alphabet(input: &str, alphabet: Alphabet) -> Result<u128, DecodeError> {
+    if input.is_empty() {
+        return Err(DecodeError::EmptyInput);
+    }
+
+    let decode_table = alphabet.decode_table();
+    let mut n: u128 = 0;
+    for (i, c) in input.char_indices() {
+        let digit = decode_digit(decode_table, c, i)?;
+        n = n
+            .checked_mul(58)
+            .and_then(|n| n.checked_add(digit as u128))
+            .ok_or(DecodeError::Overflow)?;
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_byte_encoding() {
+        for n in [1u64, 57, 58, 255, 256, 0xDEAD_BEEF, u64::MAX] {
+            let bytes = n.to_be_bytes();
+            let first = bytes.iter().position(|&b| b != 0).unwrap();
+            assert_eq!(encode_u64(n), crate::encode(&bytes[first..]));
+            assert_eq!(decode_u64(&encode_u64(n)).unwrap(), n);
+        }
+        assert_eq!(
+            encode_u128(u128::MAX),
+            crate::encode(u128::MAX.to_be_bytes())
+        );
+        assert_eq!(encode_u128(u128::MAX).len(), U128_MAX_DIGITS);
+    }
+
+    #[test]
+    fn test_zero_and_leading_zeros() {
+        assert_eq!(encode_u64(0), "1");
+        assert_eq!(decode_u64("1").unwrap(), 0);
+        assert_eq!(decode_u64("1112").unwrap(), 1);
+        assert_eq!(encode_u64_with_alphabet(0, Alphabet::Ripple), "r");
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(decode_u64(""), Err(DecodeError::EmptyInput));
+        assert_eq!(
+            decode_u64(&encode_u128(u64::MAX as u128 + 1)),
+            Err(DecodeError::Overflow)
+        );
+        assert_eq!(decode_u128(&"z".repeat(23)), Err(DecodeError::Overflow));
+        assert_eq!(
+            decode_u128_with_alphabet("abc0", Alphabet::Flickr),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 3
+            })
+        );
+    }
+}
@@ -1,7 +1,11 @@
-//! A Base58 encoding/decoding library with no external dependencies.
+//! A Base58 encoding/decoding library with no dependencies in its default build.
+//!
+//! The optional `serde`, `rayon`, `tokio`, `uuid` and `zeroize` features pull in those
+//! crates for their integrations.
 //!
 //! This library provides encoding and decoding functionality for Base58 format
-//! with support for multiple alphabets including Bitcoin (default), Ripple, and Flickr.
+//! with support for multiple alphabets including Bitcoin (default), Ripple, and Flickr,
+//! as well as user-defined alphabets.
 //!
 //! # Examples
 //!
@@ -19,9 +23,159 @@
 //! let decoded_ripple = decode_with_alphabet(&encoded_ripple, Alphabet::Ripple).unwrap();
 //! assert_eq!(data, decoded_ripple.as_slice());
 //! ```
+//!
+//! # Features
+//!
+//! * `std` (enabled by default) - Allocating APIs that return `String` and `Vec<u8>`.
+//!   Disable default features for an allocation-free `no_std` build that exposes only
+//!   the slice-based APIs such as [`encode_slice`], [`decode_slice`] and [`decode_array`].
+//! * `macros` - The [`b58!`] and [`b58_encode!`] macros for compile-time checked
+//!   Base58 literals.
+//! * `rayon` - [`par_encode_batch`] and [`par_decode_batch`], which spread batches
+//!   over the rayon thread pool, and [`monero::par_encode`] for large block-wise inputs.
+//! * `serde` - The [`serde::as_base58`] and [`serde::as_base58_check`] helpers for
+//!   `#[serde(with = "...")]` byte fields.
+//! * `simd` - Vectorized alphabet lookups for bulk encoding and decoding, using AVX2
+//!   when the CPU supports it and NEON on aarch64.
+//! * `tokio` - Async versions of [`Base58EncodeWriter`] and [`Base58DecodeReader`] in
+//!   the [`tokio`] module.
+//! * `uuid` - Implements [`ToBase58`] for `uuid::Uuid`, to pair with [`encode_uuid`]
+//!   and [`decode_uuid`].
+//! * `zeroize` - Wipes intermediate buffers after use and adds [`decode_secret`], which
+//!   returns key material in a buffer that is wiped on drop.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Decodes a Base58 string literal into a `[u8; N]` array at compile time.
+///
+/// Invalid characters are reported as compile errors.
+///
+/// # Examples
+///
+/// ```
+/// const HELLO: [u8; 5] = b58::b58!("9Ajdvzr");
+/// assert_eq!(&HELLO, b"Hello");
+/// ```
+#[cfg(feature = "macros")]
+pub use b58_macros::b58;
+
+/// Encodes a byte string literal into a Base58 `&'static str` at compile time.
+///
+/// # Examples
+///
+/// ```
+/// const HELLO: &str = b58::b58_encode!(b"Hello");
+/// assert_eq!(HELLO, "9Ajdvzr");
+/// ```
+#[cfg(feature = "macros")]
+pub use b58_macros::b58_encode;
+
+#[cfg(feature = "std")]
+pub mod address;
+#[cfg(feature = "std")]
+pub mod baseconv;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+pub mod bip32;
+#[cfg(feature = "std")]
+mod blake2b;
+#[cfg(feature = "std")]
+mod check;
+#[cfg(feature = "std")]
+pub mod cid;
+#[cfg(feature = "std")]
+mod ct;
+mod display;
+#[cfg(feature = "std")]
+mod engine;
+mod fixed;
+#[cfg(feature = "std")]
+mod forgiving;
+#[cfg(feature = "std")]
+mod incremental;
+mod int;
+#[cfg(feature = "std")]
+pub mod monero;
+#[cfg(feature = "std")]
+mod ordered;
+#[cfg(feature = "std")]
+mod ripemd160;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+mod sha256;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod solana;
+mod specialized;
+#[cfg(feature = "std")]
+pub mod ss58;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+mod string;
+#[cfg(feature = "std")]
+pub mod tezos;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(feature = "std")]
+mod traits;
+mod uuid;
+pub mod version;
+#[cfg(feature = "std")]
+pub mod wif;
+#[cfg(feature = "std")]
+pub mod xrp;
+
+#[cfg(feature = "std")]
+pub use batch::{
+    decode_batch, decode_batch_with_alphabet, encode_batch, encode_batch_with_alphabet,
+};
+#[cfg(feature = "rayon")]
+pub use batch::{
+    par_decode_batch, par_decode_batch_with_alphabet, par_encode_batch,
+    par_encode_batch_with_alphabet,
+};
+#[cfg(feature = "std")]
+pub use check::{
+    CHECKSUM_LEN, decode_cb58, decode_check, decode_check_with_alphabet, decode_versioned,
+    encode_cb58, encode_check, encode_check_with_alphabet, encode_versioned, validate_check,
+    validate_check_with_alphabet,
+};
+#[cfg(feature = "std")]
+pub use ct::{decode_ct, decode_ct_with_alphabet, encode_ct, encode_ct_with_alphabet};
+pub use display::{Base58Display, encode_to_fmt};
+#[cfg(feature = "std")]
+pub use engine::{Base58Engine, Checksum, LeadingZeros};
+pub use fixed::FixedBase58;
+#[cfg(feature = "std")]
+pub use forgiving::{Substitution, decode_forgiving};
+#[cfg(feature = "std")]
+pub use incremental::{Decoder, Encoder};
+pub use int::{decode_u64, decode_u64_with_alphabet, decode_u128, decode_u128_with_alphabet};
+#[cfg(feature = "std")]
+pub use int::{encode_u64, encode_u64_with_alphabet, encode_u128, encode_u128_with_alphabet};
+#[cfg(feature = "std")]
+pub use ordered::{
+    decode_fixed_width, decode_fixed_width_with_alphabet, encode_fixed_width,
+    encode_fixed_width_with_alphabet, fixed_width_len,
+};
+pub use specialized::{decode25, decode32, decode64};
+#[cfg(feature = "std")]
+pub use specialized::{encode25, encode32, encode64};
+#[cfg(feature = "std")]
+pub use stream::{Base58DecodeReader, Base58EncodeWriter};
+#[cfg(feature = "std")]
+pub use string::Base58String;
+#[cfg(feature = "std")]
+pub use traits::{FromBase58, ToBase58};
+pub use uuid::decode_uuid;
+#[cfg(feature = "std")]
+pub use uuid::encode_uuid;
 
 /// Enum representing different Base58 alphabets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Alphabet {
     /// Bitcoin alphabet: 123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz
     #[default]
@@ -30,36 +184,232 @@ pub enum Alphabet {
     Ripple,
     /// Flickr alphabet: 123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ
     Flickr,
+    /// A user-defined alphabet, validated by [`AlphabetDef::new`].
+    ///
+    /// The definition is borrowed for `'static` so `Alphabet` stays `Copy`. Store it in a
+    /// `static` or leak it if it is only known at runtime.
+    Custom(&'static AlphabetDef),
 }
 
 impl Alphabet {
     /// Returns the alphabet string for the given alphabet variant.
-    pub fn as_bytes(&self) -> &'static [u8] {
+    pub const fn as_bytes(&self) -> &'static [u8] {
         match self {
             Alphabet::Bitcoin => b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
             Alphabet::Ripple => b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz",
             Alphabet::Flickr => b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+            Alphabet::Custom(def) => &def.alphabet,
         }
     }
 
     /// Returns the decode table for the given alphabet variant.
-    pub fn decode_table(&self) -> [u8; 256] {
-        let mut table = [255u8; 256];
-        let alphabet = self.as_bytes();
+    ///
+    /// The table maps each byte to its digit value, or 255 if the byte is not part of
+    /// the alphabet. Tables are built at compile time, so this is a cheap lookup.
+    pub fn decode_table(&self) -> &'static [u8; 256] {
+        match self {
+            Alphabet::Bitcoin => &BITCOIN_DECODE_TABLE,
+            Alphabet::Ripple => &RIPPLE_DECODE_TABLE,
+            Alphabet::Flickr => &FLICKR_DECODE_TABLE,
+            Alphabet::Custom(def) => &def.decode_table,
+        }
+    }
+
+    /// Looks up a built-in alphabet by name.
+    ///
+    /// Names are case-insensitive. `btc` and `xrp` are accepted as aliases for `bitcoin`
+    /// and `ripple`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The alphabet name: `bitcoin`, `ripple` or `flickr`
+    ///
+    /// # Returns
+    ///
+    /// The alphabet, or a `ParseAlphabetError` if the name is not recognized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use b58::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::from_name("Ripple"), Ok(Alphabet::Ripple));
+    /// assert_eq!("btc".parse(), Ok(Alphabet::Bitcoin));
+    /// assert!(Alphabet::from_name("base64").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, ParseAlphabetError> {
+        const NAMES: [(&str, Alphabet); 5] = [
+            ("bitcoin", Alphabet::Bitcoin),
+            ("btc", Alphabet::Bitcoin),
+            ("ripple", Alphabet::Ripple),
+            ("xrp", Alphabet::Ripple),
+            ("flickr", Alphabet::Flickr),
+        ];
+        NAMES
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, alphabet)| alphabet)
+            .ok_or(ParseAlphabetError)
+    }
+}
+
+impl core::str::FromStr for Alphabet {
+    type Err = ParseAlphabetError;
+
+    /// Parses a built-in alphabet name, as [`Alphabet::from_name`] does.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Alphabet::from_name(s)
+    }
+}
+
+/// Error returned when parsing an unknown alphabet name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseAlphabetError;
+
+impl core::fmt::Display for ParseAlphabetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown alphabet (expected bitcoin, ripple or flickr)")
+    }
+}
+
+impl core::error::Error for ParseAlphabetError {}
+
+static BITCOIN_DECODE_TABLE: [u8; 256] = build_decode_table(Alphabet::Bitcoin.as_bytes());
+static RIPPLE_DECODE_TABLE: [u8; 256] = build_decode_table(Alphabet::Ripple.as_bytes());
+static FLICKR_DECODE_TABLE: [u8; 256] = build_decode_table(Alphabet::Flickr.as_bytes());
+
+/// Builds the decode table for an alphabet of unique ASCII characters
+const fn build_decode_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut table = [255u8; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// A validated, user-defined Base58 alphabet.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_with_alphabet, encode_with_alphabet, Alphabet, AlphabetDef};
+///
+/// let def = AlphabetDef::new(b"ABCDEFGHJKLMNPQRSTUVWXYZ123456789abcdefghijkmnopqrstuvwxyz").unwrap();
+/// let alphabet = Alphabet::Custom(Box::leak(Box::new(def)));
+///
+/// let encoded = encode_with_alphabet(b"Hello", alphabet);
+/// assert_eq!(decode_with_alphabet(&encoded, alphabet).unwrap(), b"Hello");
+/// ```
+///
+/// Because [`AlphabetDef::new`] is a `const fn`, an alphabet defined as a constant is
+/// validated at compile time and its decode table is stored in the binary:
+///
+/// ```
+/// use b58::{encode_with_alphabet, Alphabet, AlphabetDef};
+///
+/// const REVERSED: AlphabetDef =
+///     match AlphabetDef::new(b"zyxwvutsrqponmkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA987654321") {
+///         Ok(def) => def,
+///         Err(_) => panic!("invalid alphabet"),
+///     };
+/// const ALPHABET: Alphabet = Alphabet::Custom(&REVERSED);
+///
+/// assert_eq!(encode_with_alphabet(&[0, 57], ALPHABET), "z1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlphabetDef {
+    alphabet: [u8; 58],
+    decode_table: [u8; 256],
+}
+
+impl AlphabetDef {
+    /// Creates a custom alphabet from 58 characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - The 58 characters, in digit order
+    ///
+    /// # Returns
+    ///
+    /// The validated alphabet, or an `AlphabetError` if a character is not printable
+    /// ASCII or appears more than once
+    pub const fn new(alphabet: &[u8; 58]) -> Result<Self, AlphabetError> {
         let mut i = 0;
         while i < alphabet.len() {
-            table[alphabet[i] as usize] = i as u8;
+            let c = alphabet[i];
+            if !c.is_ascii() {
+                return Err(AlphabetError::NonAsciiCharacter(c));
+            }
+            if !c.is_ascii_graphic() {
+                return Err(AlphabetError::NonPrintableCharacter(c as char));
+            }
+            let mut j = 0;
+            while j < i {
+                if alphabet[j] == c {
+                    return Err(AlphabetError::DuplicateCharacter(c as char));
+                }
+                j += 1;
+            }
             i += 1;
         }
-        table
+        let decode_table = build_decode_table(alphabet);
+
+        Ok(AlphabetDef {
+            alphabet: *alphabet,
+            decode_table,
+        })
+    }
+}
+
+/// Error type for invalid custom alphabets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// A character appears more than once in the alphabet.
+    DuplicateCharacter(char),
+    /// The alphabet contains a byte outside the ASCII range.
+    NonAsciiCharacter(u8),
+    /// The alphabet contains whitespace or an ASCII control character.
+    NonPrintableCharacter(char),
+    /// The alphabet has too few characters to form a radix.
+    InvalidLength(usize),
+}
+
+impl core::fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AlphabetError::DuplicateCharacter(c) => {
+                write!(f, "Duplicate character in alphabet: '{c}'")
+            }
+            AlphabetError::NonAsciiCharacter(b) => {
+                write!(f, "Non-ASCII byte in alphabet: 0x{b:02x}")
+            }
+            AlphabetError::InvalidLength(len) => {
+                write!(f, "Invalid alphabet length: {len}")
+            }
+            AlphabetError::NonPrintableCharacter(c) => {
+                write!(
+                    f,
+                    "Non-printable character in alphabet: '{}'",
+                    c.escape_debug()
+                )
+            }
+        }
     }
 }
 
+impl core::error::Error for AlphabetError {}
+
 /// Encodes a byte slice into a Base58 string using the default Bitcoin alphabet.
 ///
+/// Any type that can be viewed as bytes is accepted, such as `&[u8]`, `&[u8; N]`,
+/// `Vec<u8>`, `String` or `&str`.
+///
 /// # Arguments
 ///
-/// * `input` - The byte slice to encode
+/// * `input` - The bytes to encode
 ///
 /// # Returns
 ///
@@ -73,8 +423,11 @@ impl Alphabet {
 /// let data = b"Hello";
 /// let encoded = encode(data);
 /// assert_eq!(encoded, "9Ajdvzr");
+/// assert_eq!(encode(String::from("Hello")), encoded);
+/// assert_eq!(encode(vec![0u8, 1]), "12");
 /// ```
-pub fn encode(input: &[u8]) -> String {
+#[cfg(feature = "std")]
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
     encode_with_alphabet(input, Alphabet::Bitcoin)
 }
 
@@ -82,7 +435,7 @@ pub fn encode(input: &[u8]) -> String {
 ///
 /// # Arguments
 ///
-/// * `input` - The byte slice to encode
+/// * `input` - The bytes to encode
 /// * `alphabet` - The alphabet to use for encoding
 ///
 /// # Returns
@@ -97,230 +450,934 @@ pub fn encode(input: &[u8]) -> String {
 /// let data = b"Hello";
 /// let encoded = encode_with_alphabet(data, Alphabet::Ripple);
 /// ```
-pub fn encode_with_alphabet(input: &[u8], alphabet: Alphabet) -> String {
-    if input.is_empty() {
-        return String::new();
-    }
-
-    // Count leading zeros
-    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
-
-    // Skip leading zeros for calculation
-    let significant_bytes = &input[leading_zeros..];
+#[cfg(feature = "std")]
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(input: T, alphabet: Alphabet) -> String {
+    let mut result = String::new();
+    encode_into(input, &mut result, alphabet);
+    result
+}
 
-    if significant_bytes.is_empty() {
-        // All zeros
-        let zero_char = alphabet.as_bytes()[0] as char;
-        return zero_char.to_string().repeat(leading_zeros);
-    }
+/// Encodes a byte slice into an existing `String` using the specified alphabet.
+///
+/// The previous contents of `out` are cleared, but its allocation is kept, so a single
+/// buffer can be reused across many calls.
+///
+/// # Arguments
+///
+/// * `input` - The bytes to encode
+/// * `out` - The string that receives the Base58 output
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_into, Alphabet};
+///
+/// let mut buf = String::new();
+/// encode_into(b"Hello", &mut buf, Alphabet::Bitcoin);
+/// assert_eq!(buf, "9Ajdvzr");
+///
+/// // The same allocation is reused for the next value
+/// encode_into(&[0, 0, 1, 2, 3], &mut buf, Alphabet::Bitcoin);
+/// assert_eq!(buf, "11Ldp");
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_into<T: AsRef<[u8]>>(input: T, out: &mut String, alphabet: Alphabet) {
+    baseconv::encode_into(input.as_ref(), alphabet.as_bytes(), out);
+}
 
-    // For larger numbers, use a different approach
-    let mut result = encode_big_int(significant_bytes, alphabet);
+/// Returns an upper bound on the Base58 encoded length of `input_len` bytes.
+///
+/// Each byte carries log(256)/log(58) ≈ 1.37 Base58 digits, so the bound is
+/// `⌈input_len × 1.38⌉`. Leading zero bytes encode to a single character each, which
+/// stays within the same bound.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_slice, encoded_len};
+///
+/// let data = [0xFFu8; 32];
+/// let mut buf = vec![0u8; encoded_len(data.len())];
+/// let len = encode_slice(&data, &mut buf).unwrap();
+/// assert!(len <= buf.len());
+/// ```
+pub const fn encoded_len(input_len: usize) -> usize {
+    (input_len as u128 * 138).div_ceil(100) as usize
+}
 
-    // Add leading zero characters for leading zeros
-    let zero_char = alphabet.as_bytes()[0] as char;
-    for _ in 0..leading_zeros {
-        result.insert(0, zero_char);
-    }
+/// Returns an upper bound on the decoded length of a Base58 string of `input_len` bytes.
+///
+/// Every leading zero character decodes to a whole zero byte, so a string made up
+/// entirely of zero characters decodes to `input_len` bytes; this is the tightest
+/// bound that holds without inspecting the input.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_slice, max_decoded_len};
+///
+/// let input = "72k1xXWG59fYdzSNoA";
+/// let mut buf = vec![0u8; max_decoded_len(input.len())];
+/// let len = decode_slice(input, &mut buf).unwrap();
+/// assert_eq!(&buf[..len], b"Hello, World!");
+/// ```
+pub const fn max_decoded_len(input_len: usize) -> usize {
+    input_len
+}
 
-    result
+/// Encodes a byte slice into a caller-provided buffer using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The byte slice to encode
+/// * `out` - The buffer that receives the ASCII Base58 output
+///
+/// # Returns
+///
+/// The number of bytes written to `out`, or `EncodeError::BufferTooSmall` if the
+/// encoded output does not fit
+///
+/// # Examples
+///
+/// ```
+/// use b58::encode_slice;
+///
+/// let mut buf = [0u8; 16];
+/// let len = encode_slice(b"Hello", &mut buf).unwrap();
+/// assert_eq!(&buf[..len], b"9Ajdvzr");
+/// ```
+pub const fn encode_slice(input: &[u8], out: &mut [u8]) -> Result<usize, EncodeError> {
+    encode_slice_with_alphabet(input, out, Alphabet::Bitcoin)
 }
 
-/// Encodes using big integer arithmetic with Vec<u8> for arbitrary precision
-fn encode_big_int(input: &[u8], alphabet: Alphabet) -> String {
-    let mut num = input.to_vec();
-    let mut encoded = Vec::new();
+/// Encodes a byte slice into a caller-provided buffer using the specified alphabet.
+///
+/// No allocation is performed: the buffer itself is used as scratch space for the
+/// conversion. Its contents are unspecified if an error is returned. A buffer of
+/// [`encoded_len`] bytes is always large enough.
+///
+/// # Arguments
+///
+/// * `input` - The byte slice to encode
+/// * `out` - The buffer that receives the ASCII Base58 output
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Returns
+///
+/// The number of bytes written to `out`, or `EncodeError::BufferTooSmall` if the
+/// encoded output does not fit
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_slice_with_alphabet, Alphabet};
+///
+/// let mut buf = [0u8; 16];
+/// let len = encode_slice_with_alphabet(b"Hello", &mut buf, Alphabet::Ripple).unwrap();
+/// assert_eq!(&buf[..len], b"9wjdvzi");
+/// ```
+pub const fn encode_slice_with_alphabet(
+    input: &[u8],
+    out: &mut [u8],
+    alphabet: Alphabet,
+) -> Result<usize, EncodeError> {
+    // Written with `while` loops so it can run in const contexts, see `encode_const`
     let alphabet_bytes = alphabet.as_bytes();
 
-    // Convert to base58 using long division
-    while !is_zero(&num) {
-        let remainder = divide_by_58(&mut num);
-        encoded.push(alphabet_bytes[remainder]);
+    // Leading zero bytes map directly to the first alphabet character
+    let mut leading_zeros = 0;
+    while leading_zeros < input.len() && input[leading_zeros] == 0 {
+        if leading_zeros == out.len() {
+            return Err(EncodeError::BufferTooSmall);
+        }
+        out[leading_zeros] = alphabet_bytes[0];
+        leading_zeros += 1;
     }
 
-    if encoded.is_empty() {
-        encoded.push(alphabet_bytes[0]);
+    let (_, digits) = out.split_at_mut(leading_zeros);
+
+    // Accumulate base58 digits in place, least significant first
+    let mut len = 0;
+    let mut i = leading_zeros;
+    while i < input.len() {
+        let mut carry = input[i] as u32;
+        let mut j = 0;
+        while j < len {
+            carry += (digits[j] as u32) << 8;
+            digits[j] = (carry % 58) as u8;
+            carry /= 58;
+            j += 1;
+        }
+
+        while carry > 0 {
+            if len == digits.len() {
+                return Err(EncodeError::BufferTooSmall);
+            }
+            digits[len] = (carry % 58) as u8;
+            len += 1;
+            carry /= 58;
+        }
+        i += 1;
     }
 
-    encoded.reverse();
-    String::from_utf8(encoded).unwrap()
-}
+    // Put the most significant digit first and map digits to characters
+    let mut j = 0;
+    while j < len / 2 {
+        let tmp = digits[j];
+        digits[j] = digits[len - 1 - j];
+        digits[len - 1 - j] = tmp;
+        j += 1;
+    }
+    let mut j = 0;
+    while j < len {
+        digits[j] = alphabet_bytes[digits[j] as usize];
+        j += 1;
+    }
 
-/// Check if a big integer (as Vec<u8>) is zero
-fn is_zero(num: &[u8]) -> bool {
-    num.iter().all(|&b| b == 0)
+    Ok(leading_zeros + len)
 }
 
-/// Divide a big integer by 58 and return the remainder
-fn divide_by_58(num: &mut [u8]) -> usize {
-    let mut remainder = 0u16;
+/// Encodes a fixed-size array at compile time using the default Bitcoin alphabet.
+///
+/// `OUT` must be exactly the encoded length of `input`. This is checked when the
+/// function is evaluated, so using it to initialize a `const` turns a mismatch into a
+/// compile-time error.
+///
+/// # Panics
+///
+/// Panics if the encoded length of `input` is not `OUT`.
+///
+/// # Examples
+///
+/// ```
+/// use b58::encode_const;
+///
+/// const HELLO: [u8; 7] = encode_const(*b"Hello");
+/// const HELLO_STR: &str = match core::str::from_utf8(&HELLO) {
+///     Ok(s) => s,
+///     Err(_) => unreachable!(),
+/// };
+/// assert_eq!(HELLO_STR, "9Ajdvzr");
+/// ```
+pub const fn encode_const<const IN: usize, const OUT: usize>(input: [u8; IN]) -> [u8; OUT] {
+    encode_const_with_alphabet(input, Alphabet::Bitcoin)
+}
 
-    for byte in num.iter_mut() {
-        let temp = remainder * 256 + *byte as u16;
-        *byte = (temp / 58) as u8;
-        remainder = temp % 58;
+/// Encodes a fixed-size array at compile time using the specified alphabet.
+///
+/// `OUT` must be exactly the encoded length of `input`. This is checked when the
+/// function is evaluated, so using it to initialize a `const` turns a mismatch into a
+/// compile-time error.
+///
+/// # Panics
+///
+/// Panics if the encoded length of `input` is not `OUT`.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode_const_with_alphabet, Alphabet};
+///
+/// const HELLO: [u8; 7] = encode_const_with_alphabet(*b"Hello", Alphabet::Ripple);
+/// assert_eq!(&HELLO, b"9wjdvzi");
+/// ```
+pub const fn encode_const_with_alphabet<const IN: usize, const OUT: usize>(
+    input: [u8; IN],
+    alphabet: Alphabet,
+) -> [u8; OUT] {
+    let mut out = [0u8; OUT];
+    match encode_slice_with_alphabet(&input, &mut out, alphabet) {
+        Ok(len) if len == OUT => out,
+        Ok(_) => panic!("OUT is larger than the encoded length"),
+        Err(_) => panic!("OUT is smaller than the encoded length"),
     }
+}
+
+/// Error type for Base58 encoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The output buffer is too small to hold the encoded data.
+    BufferTooSmall,
+}
 
-    remainder as usize
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EncodeError::BufferTooSmall => write!(f, "Output buffer is too small"),
+        }
+    }
 }
 
+impl core::error::Error for EncodeError {}
+
 /// Error type for Base58 decoding failures.
+///
+/// New variants may be added as features grow, so matches need a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DecodeError {
     /// Invalid character encountered during decoding.
-    InvalidCharacter(char),
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+        /// The byte offset of the character in the input. Every character before it is
+        /// ASCII, so this is also its character index.
+        position: usize,
+    },
     /// Input string is empty.
     EmptyInput,
     /// Numeric overflow during decoding.
     Overflow,
+    /// The output buffer is too small to hold the decoded data.
+    BufferTooSmall,
+    /// The decoded data does not have the expected length.
+    InvalidLength {
+        /// The number of bytes that was expected.
+        expected: usize,
+        /// The number of bytes that was decoded.
+        actual: usize,
+    },
+    /// The Base58Check checksum is missing or does not match the payload.
+    ChecksumMismatch,
+    /// The decoded data would exceed the configured size limit.
+    InputTooLarge,
+    /// The input has leading zero characters, which the decoder was configured to reject.
+    LeadingZeros,
+    /// Reading the input failed with an I/O error of this kind.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
 }
 
-impl std::fmt::Display for DecodeError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            DecodeError::InvalidCharacter(c) => write!(f, "Invalid character: '{c}'"),
+            DecodeError::InvalidCharacter {
+                character,
+                position,
+            } => write!(f, "Invalid character '{character}' at position {position}"),
             DecodeError::EmptyInput => write!(f, "Input string is empty"),
             DecodeError::Overflow => write!(f, "Numeric overflow during decoding"),
+            DecodeError::BufferTooSmall => write!(f, "Output buffer is too small"),
+            DecodeError::InvalidLength { expected, actual } => {
+                write!(f, "Invalid length: expected {expected} bytes, got {actual}")
+            }
+            DecodeError::ChecksumMismatch => write!(f, "Checksum mismatch"),
+            DecodeError::InputTooLarge => write!(f, "Input exceeds the size limit"),
+            DecodeError::LeadingZeros => write!(f, "Leading zero characters are not allowed"),
+            #[cfg(feature = "std")]
+            DecodeError::Io(kind) => write!(f, "I/O error: {kind}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Decodes a Base58 string into a byte vector using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// A `Result` containing the decoded bytes on success, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::decode;
+///
+/// let encoded = "9Ajdvzr";
+/// let decoded = decode(encoded).unwrap();
+/// assert_eq!(decoded, b"Hello");
+/// ```
+#[cfg(feature = "std")]
+pub fn decode(input: &str) -> Result<Vec<u8>, DecodeError> {
+    decode_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 string into a byte vector using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// A `Result` containing the decoded bytes on success, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_with_alphabet, Alphabet};
+///
+/// let encoded = "9Ajdvzr";
+/// let decoded = decode_with_alphabet(encoded, Alphabet::Bitcoin).unwrap();
+/// assert_eq!(decoded, b"Hello");
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_with_alphabet(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    let mut result = Vec::new();
+    decode_into(input, &mut result, alphabet)?;
+    Ok(result)
+}
+
+/// Decodes Base58 held as raw bytes, such as a network buffer or a memory-mapped file.
+///
+/// Every alphabet character is ASCII, so the input is decoded byte by byte without first
+/// being checked as UTF-8 or copied into a `String`.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 characters as bytes
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes, or `DecodeError::InvalidCharacter` if a byte is not an alphabet
+/// character. Its position is a byte offset, and the character is the one starting at that
+/// offset, or U+FFFD if the input is not valid UTF-8 there.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_bytes, Alphabet, DecodeError};
+///
+/// assert_eq!(decode_bytes(b"9Ajdvzr", Alphabet::Bitcoin).unwrap(), b"Hello");
+/// assert_eq!(
+///     decode_bytes(b"9Aj\xFF", Alphabet::Bitcoin),
+///     Err(DecodeError::InvalidCharacter { character: '\u{FFFD}', position: 3 })
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_bytes(input: &[u8], alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    let mut result = Vec::new();
+    baseconv::decode_into(
+        input,
+        alphabet.as_bytes(),
+        alphabet.decode_table(),
+        &mut result,
+    )?;
+    Ok(result)
+}
+
+/// Decodes a Base58 string, rejecting inputs that would decode to more than
+/// `max_decoded_len` bytes.
+///
+/// Decoding takes time quadratic in the input length, so inputs that are certain to
+/// exceed the limit are rejected from their length alone before any arithmetic is done.
+/// Use this when decoding untrusted input.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
+/// * `max_decoded_len` - The largest number of decoded bytes to accept
+///
+/// # Returns
+///
+/// The decoded bytes, or `DecodeError::InputTooLarge` if they would exceed the limit
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_with_limit, Alphabet, DecodeError};
+///
+/// assert_eq!(decode_with_limit("9Ajdvzr", Alphabet::Bitcoin, 5).unwrap(), b"Hello");
+/// assert_eq!(
+///     decode_with_limit(&"z".repeat(1_000_000), Alphabet::Bitcoin, 64),
+///     Err(DecodeError::InputTooLarge)
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_with_limit(
+    input: &str,
+    alphabet: Alphabet,
+    max_decoded_len: usize,
+) -> Result<Vec<u8>, DecodeError> {
+    Base58Engine::new(alphabet)
+        .with_max_decoded_len(max_decoded_len)
+        .decode(input)
+}
+
+/// Reads a whole Base58 string from a reader and decodes it.
+///
+/// Trailing newline characters are removed before decoding, so files and piped input
+/// can be decoded as they are.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the Base58 string, such as a file or socket
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes, `DecodeError::Io` if reading fails, or another `DecodeError` if
+/// the input is invalid. Input that is not UTF-8 is reported as
+/// `DecodeError::InvalidCharacter` with the replacement character `U+FFFD`.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, decode_from_reader};
+///
+/// let file = "9Ajdvzr\n".as_bytes();
+/// assert_eq!(decode_from_reader(file, Alphabet::Bitcoin).unwrap(), b"Hello");
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_from_reader<R: std::io::Read>(
+    reader: R,
+    alphabet: Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let input = read_input(reader, None)?;
+    decode_with_alphabet(trim_newlines(&input), alphabet)
+}
+
+/// Reads a whole Base58 string from a reader and decodes it, rejecting input that would
+/// decode to more than `max_decoded_len` bytes.
+///
+/// Reading stops as soon as the input is too long to decode within the limit, so an
+/// untrusted reader cannot make this buffer an unbounded amount of data.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the Base58 string
+/// * `alphabet` - The alphabet to use for decoding
+/// * `max_decoded_len` - The largest number of decoded bytes to accept
+///
+/// # Returns
+///
+/// The decoded bytes, or `DecodeError::InputTooLarge` if they would exceed the limit
+#[cfg(feature = "std")]
+pub fn decode_from_reader_with_limit<R: std::io::Read>(
+    reader: R,
+    alphabet: Alphabet,
+    max_decoded_len: usize,
+) -> Result<Vec<u8>, DecodeError> {
+    let input = read_input(reader, Some(max_decoded_len))?;
+    decode_with_limit(trim_newlines(&input), alphabet, max_decoded_len)
+}
+
+/// Reads all of `reader` as UTF-8, stopping early once the input is longer than any
+/// encoding of `max_decoded_len` bytes plus a trailing line ending
+#[cfg(feature = "std")]
+fn read_input<R: std::io::Read>(
+    mut reader: R,
+    max_decoded_len: Option<usize>,
+) -> Result<String, DecodeError> {
+    use std::io::Read;
+
+    let mut bytes = Vec::new();
+    match max_decoded_len {
+        Some(limit) => {
+            let max_input_len = encoded_len(limit).saturating_add(2);
+            let read = reader
+                .take(max_input_len as u64 + 1)
+                .read_to_end(&mut bytes)
+                .map_err(|e| DecodeError::Io(e.kind()))?;
+            if read > max_input_len {
+                return Err(DecodeError::InputTooLarge);
+            }
+        }
+        None => {
+            reader
+                .read_to_end(&mut bytes)
+                .map_err(|e| DecodeError::Io(e.kind()))?;
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|e| DecodeError::InvalidCharacter {
+        character: char::REPLACEMENT_CHARACTER,
+        position: e.utf8_error().valid_up_to(),
+    })
+}
+
+/// Removes trailing `\n` and `\r\n` line endings
+#[cfg(feature = "std")]
+fn trim_newlines(input: &str) -> &str {
+    input.trim_end_matches(['\n', '\r'])
+}
+
+/// Decodes a Base58 secret, such as a private key or seed, using the default Bitcoin
+/// alphabet.
+///
+/// Decoding runs in constant time like [`decode_ct`] and the result is wiped from memory
+/// when it is dropped.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded bytes in a [`zeroize::Zeroizing`] buffer, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::decode_secret;
+///
+/// let key = decode_secret("9Ajdvzr").unwrap();
+/// assert_eq!(key.as_slice(), b"Hello");
+/// ```
+#[cfg(feature = "zeroize")]
+pub fn decode_secret(input: &str) -> Result<zeroize::Zeroizing<Vec<u8>>, DecodeError> {
+    decode_secret_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 secret using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes in a [`zeroize::Zeroizing`] buffer, or a `DecodeError` on failure
+#[cfg(feature = "zeroize")]
+pub fn decode_secret_with_alphabet(
+    input: &str,
+    alphabet: Alphabet,
+) -> Result<zeroize::Zeroizing<Vec<u8>>, DecodeError> {
+    decode_ct_with_alphabet(input, alphabet).map(zeroize::Zeroizing::new)
+}
+
+/// Decodes a Base58 string into an existing byte vector using the specified alphabet.
+///
+/// The previous contents of `out` are cleared, but its allocation is kept, so a single
+/// buffer can be reused across many calls. If decoding fails, `out` is left empty.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `out` - The vector that receives the decoded bytes
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// `Ok(())` on success, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_into, Alphabet};
+///
+/// let mut buf = Vec::new();
+/// decode_into("9Ajdvzr", &mut buf, Alphabet::Bitcoin).unwrap();
+/// assert_eq!(buf, b"Hello");
+///
+/// // The same allocation is reused for the next value
+/// decode_into("11Ldp", &mut buf, Alphabet::Bitcoin).unwrap();
+/// assert_eq!(buf, [0, 0, 1, 2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_into(input: &str, out: &mut Vec<u8>, alphabet: Alphabet) -> Result<(), DecodeError> {
+    baseconv::decode_into(
+        input.as_bytes(),
+        alphabet.as_bytes(),
+        alphabet.decode_table(),
+        out,
+    )
+}
+
+/// Decodes a Base58 string into a caller-provided buffer using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `out` - The buffer that receives the decoded bytes
+///
+/// # Returns
+///
+/// The number of bytes written to `out`, or a `DecodeError` on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::decode_slice;
+///
+/// let mut buf = [0u8; 8];
+/// let len = decode_slice("9Ajdvzr", &mut buf).unwrap();
+/// assert_eq!(&buf[..len], b"Hello");
+/// ```
+pub fn decode_slice(input: &str, out: &mut [u8]) -> Result<usize, DecodeError> {
+    decode_slice_with_alphabet(input, out, Alphabet::Bitcoin)
+}
+
+/// Decodes a Base58 string into a caller-provided buffer using the specified alphabet.
+///
+/// No allocation is performed: the buffer itself is used as scratch space for the
+/// conversion. Its contents are unspecified if an error is returned. A buffer of
+/// [`max_decoded_len`] bytes is always large enough.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+/// * `out` - The buffer that receives the decoded bytes
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The number of bytes written to `out`, or a `DecodeError` on failure. If the decoded
+/// data does not fit, `DecodeError::BufferTooSmall` is returned, possibly before the
+/// rest of the input has been checked for invalid characters.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_slice_with_alphabet, Alphabet};
+///
+/// let mut buf = [0u8; 8];
+/// let len = decode_slice_with_alphabet("9wjdvzi", &mut buf, Alphabet::Ripple).unwrap();
+/// assert_eq!(&buf[..len], b"Hello");
+/// ```
+pub fn decode_slice_with_alphabet(
+    input: &str,
+    out: &mut [u8],
+    alphabet: Alphabet,
+) -> Result<usize, DecodeError> {
+    let decode_table = alphabet.decode_table();
+    let zero_char = alphabet.as_bytes()[0];
+
+    // Count leading zero characters
+    let leading_zeros = input.bytes().take_while(|&b| b == zero_char).count();
+    if leading_zeros > out.len() {
+        return Err(DecodeError::BufferTooSmall);
+    }
+
+    let (zeros, bytes) = out.split_at_mut(leading_zeros);
+    zeros.fill(0);
+
+    // Accumulate bytes in place, least significant first
+    let mut len = 0;
+    for (i, c) in input[leading_zeros..].char_indices() {
+        let digit = decode_digit(decode_table, c, leading_zeros + i)?;
+
+        let mut carry = digit as u32;
+        for byte in bytes[..len].iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            if len == bytes.len() {
+                return Err(DecodeError::BufferTooSmall);
+            }
+            bytes[len] = carry as u8;
+            len += 1;
+            carry >>= 8;
         }
     }
+
+    // Put the most significant byte first
+    bytes[..len].reverse();
+
+    Ok(leading_zeros + len)
+}
+
+/// Decodes a Base58 string into a fixed-size array using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded array, or `DecodeError::InvalidLength` if the decoded data is not
+/// exactly `N` bytes long
+///
+/// # Examples
+///
+/// ```
+/// use b58::decode_array;
+///
+/// let bytes: [u8; 5] = decode_array("9Ajdvzr").unwrap();
+/// assert_eq!(&bytes, b"Hello");
+/// assert!(decode_array::<4>("9Ajdvzr").is_err());
+/// ```
+pub fn decode_array<const N: usize>(input: &str) -> Result<[u8; N], DecodeError> {
+    decode_array_with_alphabet(input, Alphabet::Bitcoin)
 }
 
-impl std::error::Error for DecodeError {}
-
-/// Decodes a Base58 string into a byte vector using the default Bitcoin alphabet.
+/// Decodes a Base58 string into a fixed-size array using the specified alphabet.
 ///
 /// # Arguments
 ///
 /// * `input` - The Base58 string to decode
+/// * `alphabet` - The alphabet to use for decoding
 ///
 /// # Returns
 ///
-/// A `Result` containing the decoded bytes on success, or a `DecodeError` on failure
+/// The decoded array, or `DecodeError::InvalidLength` if the decoded data is not
+/// exactly `N` bytes long. Without the `std` feature the length of an oversized
+/// payload cannot be determined, and `DecodeError::BufferTooSmall` is returned instead.
 ///
 /// # Examples
 ///
 /// ```
-/// use b58::decode;
+/// use b58::{decode_array_with_alphabet, Alphabet};
 ///
-/// let encoded = "9Ajdvzr";
-/// let decoded = decode(encoded).unwrap();
-/// assert_eq!(decoded, b"Hello");
+/// let bytes: [u8; 5] = decode_array_with_alphabet("9wjdvzi", Alphabet::Ripple).unwrap();
+/// assert_eq!(&bytes, b"Hello");
 /// ```
-pub fn decode(input: &str) -> Result<Vec<u8>, DecodeError> {
-    decode_with_alphabet(input, Alphabet::Bitcoin)
+pub fn decode_array_with_alphabet<const N: usize>(
+    input: &str,
+    alphabet: Alphabet,
+) -> Result<[u8; N], DecodeError> {
+    let mut out = [0u8; N];
+    match decode_slice_with_alphabet(input, &mut out, alphabet) {
+        Ok(len) if len == N => Ok(out),
+        Ok(len) => Err(DecodeError::InvalidLength {
+            expected: N,
+            actual: len,
+        }),
+        #[cfg(feature = "std")]
+        Err(DecodeError::BufferTooSmall) => {
+            // Too long for the array: decode fully to report the actual length
+            let decoded = decode_with_alphabet(input, alphabet)?;
+            Err(DecodeError::InvalidLength {
+                expected: N,
+                actual: decoded.len(),
+            })
+        }
+        Err(e) => Err(e),
+    }
 }
 
-/// Decodes a Base58 string into a byte vector using the specified alphabet.
+/// Checks that every character of a Base58 string is in the specified alphabet.
 ///
 /// # Arguments
 ///
-/// * `input` - The Base58 string to decode
-/// * `alphabet` - The alphabet to use for decoding
+/// * `input` - The Base58 string to check
+/// * `alphabet` - The alphabet the string should use
 ///
 /// # Returns
 ///
-/// A `Result` containing the decoded bytes on success, or a `DecodeError` on failure
+/// `true` if [`decode_with_alphabet`] would succeed for `input`
 ///
 /// # Examples
 ///
 /// ```
-/// use b58::{decode_with_alphabet, Alphabet};
+/// use b58::{is_valid, Alphabet};
 ///
-/// let encoded = "9Ajdvzr";
-/// let decoded = decode_with_alphabet(encoded, Alphabet::Bitcoin).unwrap();
-/// assert_eq!(decoded, b"Hello");
+/// assert!(is_valid("9Ajdvzr", Alphabet::Bitcoin));
+/// assert!(!is_valid("9Ajdvzr0", Alphabet::Bitcoin));
 /// ```
-pub fn decode_with_alphabet(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
-    if input.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    let zero_char = alphabet.as_bytes()[0] as char;
-
-    // Count leading zero characters
-    let leading_zeros = input.chars().take_while(|&c| c == zero_char).count();
-
-    // Skip leading zero characters for calculation
-    let significant_chars: String = input.chars().skip(leading_zeros).collect();
-
-    if significant_chars.is_empty() {
-        // All zero characters
-        return Ok(vec![0; leading_zeros]);
-    }
-
-    // Decode using big integer arithmetic
-    let mut result = decode_big_int(&significant_chars, alphabet)?;
-
-    // Add leading zeros for leading zero characters
-    for _ in 0..leading_zeros {
-        result.insert(0, 0);
-    }
+pub fn is_valid(input: &str, alphabet: Alphabet) -> bool {
+    validate_with_alphabet(input, alphabet).is_ok()
+}
 
-    Ok(result)
+/// Checks that every character of a Base58 string is in the default Bitcoin alphabet.
+///
+/// Only character membership is checked: no numeric conversion is done and nothing is
+/// allocated, so this is much cheaper than decoding when the bytes are not needed.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to check
+///
+/// # Returns
+///
+/// `Ok(())`, or the same `DecodeError::InvalidCharacter` that [`decode`] would return
+///
+/// # Examples
+///
+/// ```
+/// use b58::{validate, DecodeError};
+///
+/// assert_eq!(validate("9Ajdvzr"), Ok(()));
+/// assert_eq!(
+///     validate("9AjOvzr"),
+///     Err(DecodeError::InvalidCharacter { character: 'O', position: 3 })
+/// );
+/// ```
+pub fn validate(input: &str) -> Result<(), DecodeError> {
+    validate_with_alphabet(input, Alphabet::Bitcoin)
 }
 
-/// Decodes using big integer arithmetic with Vec<u8> for arbitrary precision
-fn decode_big_int(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
-    let mut num = vec![0u8];
+/// Checks that every character of a Base58 string is in the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to check
+/// * `alphabet` - The alphabet the string should use
+///
+/// # Returns
+///
+/// `Ok(())`, or the same `DecodeError::InvalidCharacter` that [`decode_with_alphabet`]
+/// would return
+pub fn validate_with_alphabet(input: &str, alphabet: Alphabet) -> Result<(), DecodeError> {
     let decode_table = alphabet.decode_table();
-
-    for c in input.chars() {
-        let c_val = c as u32;
-        if c_val >= 256 {
-            return Err(DecodeError::InvalidCharacter(c));
-        }
-
-        let digit = decode_table[c_val as usize];
-        if digit == 255 {
-            return Err(DecodeError::InvalidCharacter(c));
-        }
-
-        // Multiply by 58 and add digit
-        multiply_by_58(&mut num);
-        add_digit(&mut num, digit);
-    }
-
-    // Remove leading zeros
-    while num.len() > 1 && num[0] == 0 {
-        num.remove(0);
+    // Alphabet characters are ASCII, so the first invalid byte starts a character
+    match input.bytes().position(|b| decode_table[b as usize] == 255) {
+        None => Ok(()),
+        Some(position) => Err(DecodeError::InvalidCharacter {
+            character: input[position..].chars().next().unwrap_or_default(),
+            position,
+        }),
     }
-
-    Ok(num)
 }
 
-/// Multiply a big integer by 58
-fn multiply_by_58(num: &mut Vec<u8>) {
-    let mut carry = 0u16;
-
-    for byte in num.iter_mut().rev() {
-        let temp = *byte as u16 * 58 + carry;
-        *byte = (temp % 256) as u8;
-        carry = temp / 256;
+/// Looks up the digit value of the character at byte offset `position`
+fn decode_digit(decode_table: &[u8; 256], c: char, position: usize) -> Result<u8, DecodeError> {
+    match decode_table.get(c as usize) {
+        Some(&digit) if digit != 255 => Ok(digit),
+        _ => Err(DecodeError::InvalidCharacter {
+            character: c,
+            position,
+        }),
     }
+}
 
-    while carry > 0 {
-        num.insert(0, (carry % 256) as u8);
-        carry /= 256;
-    }
+/// Overwrites a scratch buffer that may hold key material when the `zeroize` feature is enabled
+#[cfg(feature = "std")]
+pub(crate) fn wipe<T: Scratch>(buf: &mut Vec<T>) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(buf);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = buf;
 }
 
-/// Add a single digit to a big integer
-fn add_digit(num: &mut Vec<u8>, digit: u8) {
-    let mut carry = digit as u16;
+/// Overwrites a fixed-size scratch buffer when the `zeroize` feature is enabled
+#[cfg(feature = "std")]
+pub(crate) fn wipe_slice<T: Scratch>(buf: &mut [T]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(buf);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = buf;
+}
 
-    for byte in num.iter_mut().rev() {
-        let temp = *byte as u16 + carry;
-        *byte = (temp % 256) as u8;
-        carry = temp / 256;
-        if carry == 0 {
-            break;
-        }
-    }
+/// Element types of scratch buffers that [`wipe`] can clear
+#[cfg(feature = "zeroize")]
+pub(crate) trait Scratch: zeroize::DefaultIsZeroes {}
+/// Element types of scratch buffers that [`wipe`] can clear
+#[cfg(all(feature = "std", not(feature = "zeroize")))]
+pub(crate) trait Scratch {}
+
+#[cfg(feature = "std")]
+impl Scratch for u8 {}
+#[cfg(feature = "std")]
+impl Scratch for u32 {}
+#[cfg(feature = "std")]
+impl Scratch for u64 {}
+
+/// Formats bytes as lowercase hex, for comparing against test vectors
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
 
-    while carry > 0 {
-        num.insert(0, (carry % 256) as u8);
-        carry /= 256;
-    }
+/// Parses a hex test vector into bytes
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
 }
 
 #[cfg(test)]
@@ -329,17 +1386,17 @@ mod tests {
 
     #[test]
     fn test_encode_empty() {
-        assert_eq!(encode(&[]), "");
+        assert_eq!(encode([]), "");
     }
 
     #[test]
     fn test_encode_single_zero() {
-        assert_eq!(encode(&[0]), "1");
+        assert_eq!(encode([0]), "1");
     }
 
     #[test]
     fn test_encode_multiple_zeros() {
-        assert_eq!(encode(&[0, 0, 0]), "111");
+        assert_eq!(encode([0, 0, 0]), "111");
     }
 
     #[test]
@@ -354,7 +1411,126 @@ mod tests {
 
     #[test]
     fn test_encode_with_leading_zeros() {
-        assert_eq!(encode(&[0, 0, 1, 2, 3]), "11Ldp");
+        assert_eq!(encode([0, 0, 1, 2, 3]), "11Ldp");
+    }
+
+    #[test]
+    fn test_many_leading_zeros() {
+        // Leading zeros are handled in linear time, so this stays fast
+        let mut input = vec![0u8; 200_000];
+        input.push(1);
+        let encoded = encode(&input);
+        assert_eq!(encoded.len(), 200_001);
+        assert!(encoded[..200_000].bytes().all(|b| b == b'1'));
+        assert!(encoded.ends_with('2'));
+        assert_eq!(decode(&encoded).unwrap(), input);
+    }
+
+    #[test]
+    fn test_encode_into_reuses_buffer() {
+        let mut buf = String::from("stale contents");
+        encode_into(b"Hello", &mut buf, Alphabet::Bitcoin);
+        assert_eq!(buf, "9Ajdvzr");
+
+        let capacity = buf.capacity();
+        encode_into([0, 0, 1, 2, 3], &mut buf, Alphabet::Bitcoin);
+        assert_eq!(buf, "11Ldp");
+        assert_eq!(buf.capacity(), capacity);
+
+        encode_into([], &mut buf, Alphabet::Bitcoin);
+        assert_eq!(buf, "");
+    }
+
+    #[test]
+    fn test_encode_slice_matches_encode() {
+        let test_cases = vec![
+            vec![],
+            vec![0],
+            vec![0, 0, 1, 2, 3],
+            vec![255, 254, 253],
+            b"Hello, World!".to_vec(),
+            (0..=255).collect::<Vec<u8>>(),
+        ];
+
+        for input in test_cases {
+            let mut buf = [0u8; 512];
+            let len = encode_slice(&input, &mut buf).unwrap();
+            assert_eq!(&buf[..len], encode(&input).as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_encode_slice_buffer_too_small() {
+        let mut exact = [0u8; 7];
+        assert_eq!(encode_slice(b"Hello", &mut exact), Ok(7));
+        assert_eq!(&exact, b"9Ajdvzr");
+
+        let mut short = [0u8; 6];
+        assert_eq!(
+            encode_slice(b"Hello", &mut short),
+            Err(EncodeError::BufferTooSmall)
+        );
+
+        let mut tiny = [0u8; 2];
+        assert_eq!(
+            encode_slice(&[0, 0, 0], &mut tiny),
+            Err(EncodeError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_encoded_len_is_upper_bound() {
+        for len in 0..=300 {
+            let worst = vec![0xFF; len];
+            assert!(encode(&worst).len() <= encoded_len(len), "len {len}");
+
+            let mut zeros = vec![0; len];
+            zeros.push(0xFF);
+            assert!(encode(&zeros).len() <= encoded_len(len + 1), "len {len}");
+        }
+    }
+
+    #[test]
+    fn test_max_decoded_len_is_upper_bound() {
+        for len in 0..=100 {
+            assert_eq!(
+                decode(&"1".repeat(len)).unwrap().len(),
+                max_decoded_len(len)
+            );
+            assert!(decode(&"z".repeat(len)).unwrap().len() <= max_decoded_len(len));
+        }
+    }
+
+    #[test]
+    fn test_encode_const() {
+        const HELLO: [u8; 7] = encode_const(*b"Hello");
+        const ZEROS: [u8; 5] = encode_const([0, 0, 1, 2, 3]);
+        const EMPTY: [u8; 0] = encode_const([]);
+        assert_eq!(&HELLO, b"9Ajdvzr");
+        assert_eq!(&ZEROS, b"11Ldp");
+        assert_eq!(&EMPTY, b"");
+
+        let key = [0xAB; 32];
+        let encoded: [u8; 44] = encode_const(key);
+        assert_eq!(&encoded, encode(key).as_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "OUT is larger than the encoded length")]
+    fn test_encode_const_wrong_length() {
+        let _: [u8; 8] = encode_const(*b"Hello");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_macros() {
+        const HELLO: [u8; 5] = crate::b58!("9Ajdvzr");
+        const ZEROS: [u8; 5] = crate::b58!("11Ldp");
+        const ENCODED: &str = crate::b58_encode!(b"\x00\x00\x01\x02\x03");
+        assert_eq!(&HELLO, b"Hello");
+        assert_eq!(ZEROS, [0, 0, 1, 2, 3]);
+        assert_eq!(ENCODED, "11Ldp");
+        assert_eq!(crate::b58_encode!(b"Hello, World!"), "72k1xXWG59fYdzSNoA");
     }
 
     #[test]
@@ -387,10 +1563,171 @@ mod tests {
         assert_eq!(decode("11Ldp").unwrap(), vec![0, 0, 1, 2, 3]);
     }
 
+    #[test]
+    fn test_decode_into_reuses_buffer() {
+        let mut buf = vec![9, 9, 9, 9, 9, 9, 9, 9];
+        decode_into("9Ajdvzr", &mut buf, Alphabet::Bitcoin).unwrap();
+        assert_eq!(buf, b"Hello");
+
+        let capacity = buf.capacity();
+        decode_into("11Ldp", &mut buf, Alphabet::Bitcoin).unwrap();
+        assert_eq!(buf, vec![0, 0, 1, 2, 3]);
+        assert_eq!(buf.capacity(), capacity);
+
+        decode_into("", &mut buf, Alphabet::Bitcoin).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_into_clears_on_error() {
+        let mut buf = vec![1, 2, 3];
+        assert_eq!(
+            decode_into("11Ldp0", &mut buf, Alphabet::Bitcoin),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 5
+            })
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_bytes() {
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let encoded = encode_with_alphabet([0, 0, 1, 2, 3, 255], alphabet);
+            assert_eq!(
+                decode_bytes(encoded.as_bytes(), alphabet),
+                decode_with_alphabet(&encoded, alphabet)
+            );
+        }
+        assert_eq!(decode_bytes(b"", Alphabet::Bitcoin).unwrap(), b"");
+
+        // Non-ASCII input reports the same character and position as the string API
+        assert_eq!(
+            decode_bytes("1é".as_bytes(), Alphabet::Bitcoin),
+            decode("1é")
+        );
+        assert_eq!(
+            decode_bytes(b"11\xC3", Alphabet::Bitcoin),
+            Err(DecodeError::InvalidCharacter {
+                character: char::REPLACEMENT_CHARACTER,
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_slice_matches_decode() {
+        let test_cases = vec![
+            vec![],
+            vec![0],
+            vec![0, 0, 1, 2, 3],
+            vec![255, 254, 253],
+            b"Hello, World!".to_vec(),
+            (0..=255).collect::<Vec<u8>>(),
+        ];
+
+        for original in test_cases {
+            let encoded = encode(&original);
+            let mut buf = [0xAAu8; 512];
+            let len = decode_slice(&encoded, &mut buf).unwrap();
+            assert_eq!(&buf[..len], original.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_decode_slice_buffer_too_small() {
+        let mut exact = [0u8; 5];
+        assert_eq!(decode_slice("9Ajdvzr", &mut exact), Ok(5));
+        assert_eq!(&exact, b"Hello");
+
+        let mut short = [0u8; 4];
+        assert_eq!(
+            decode_slice("9Ajdvzr", &mut short),
+            Err(DecodeError::BufferTooSmall)
+        );
+
+        let mut tiny = [0u8; 2];
+        assert_eq!(
+            decode_slice("111", &mut tiny),
+            Err(DecodeError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_decode_slice_invalid_character() {
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            decode_slice("9Ajd€vzr", &mut buf),
+            Err(DecodeError::InvalidCharacter {
+                character: '€',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_matches_decode() {
+        let cases = [
+            "", "1", "9Ajdvzr", "9Ajdvzr0", "1l", "9Aé", "zzzzI", "11Ldp\n",
+        ];
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            for input in cases {
+                assert_eq!(
+                    validate_with_alphabet(input, alphabet),
+                    decode_with_alphabet(input, alphabet).map(drop),
+                    "{input}"
+                );
+                assert_eq!(
+                    is_valid(input, alphabet),
+                    validate_with_alphabet(input, alphabet).is_ok()
+                );
+            }
+        }
+        assert_eq!(validate("9Ajdvzr"), Ok(()));
+    }
+
+    #[test]
+    fn test_decode_array() {
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let encoded = encode(key);
+        assert_eq!(decode_array::<32>(&encoded), Ok(key));
+        assert_eq!(decode_array::<3>("111"), Ok([0, 0, 0]));
+        assert_eq!(decode_array::<0>(""), Ok([]));
+    }
+
+    #[test]
+    fn test_decode_array_invalid_length() {
+        assert_eq!(
+            decode_array::<6>("9Ajdvzr"),
+            Err(DecodeError::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            decode_array::<4>("9Ajdvzr"),
+            Err(DecodeError::InvalidLength {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            decode_array::<4>("9Ajdvzr0"),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 7
+            })
+        );
+    }
+
     #[test]
     fn test_decode_invalid_character() {
         match decode("9Ajdvzr0") {
-            Err(DecodeError::InvalidCharacter('0')) => {}
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 7,
+            }) => {}
             _ => panic!("Expected InvalidCharacter error"),
         }
     }
@@ -398,11 +1735,147 @@ mod tests {
     #[test]
     fn test_decode_invalid_character_unicode() {
         match decode("9Ajdvzr€") {
-            Err(DecodeError::InvalidCharacter('€')) => {}
+            Err(DecodeError::InvalidCharacter {
+                character: '€',
+                position: 7,
+            }) => {}
             _ => panic!("Expected InvalidCharacter error"),
         }
     }
 
+    #[test]
+    fn test_decode_with_limit() {
+        let data = [0u8, 0, 0xFF, 0xFF, 0xFF];
+        let encoded = encode(data);
+        assert_eq!(
+            decode_with_limit(&encoded, Alphabet::Bitcoin, 5).unwrap(),
+            data
+        );
+        assert_eq!(
+            decode_with_limit(&encoded, Alphabet::Bitcoin, 4),
+            Err(DecodeError::InputTooLarge)
+        );
+        // Leading zeros count toward the limit
+        assert_eq!(
+            decode_with_limit("111", Alphabet::Bitcoin, 2),
+            Err(DecodeError::InputTooLarge)
+        );
+        assert_eq!(decode_with_limit("", Alphabet::Bitcoin, 0).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_decode_from_reader() {
+        let read = |input: &[u8]| decode_from_reader(input, Alphabet::Bitcoin);
+        assert_eq!(read(b"9Ajdvzr").unwrap(), b"Hello");
+        assert_eq!(read(b"9Ajdvzr\r\n").unwrap(), b"Hello");
+        assert_eq!(read(b"").unwrap(), b"");
+        assert_eq!(
+            read(b"9Aj\xFFdvzr"),
+            Err(DecodeError::InvalidCharacter {
+                character: char::REPLACEMENT_CHARACTER,
+                position: 3
+            })
+        );
+
+        struct Failing;
+        impl std::io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+        assert_eq!(
+            decode_from_reader(Failing, Alphabet::Bitcoin),
+            Err(DecodeError::Io(std::io::ErrorKind::BrokenPipe))
+        );
+    }
+
+    #[test]
+    fn test_decode_from_reader_with_limit() {
+        let encoded = format!("{}\n", encode([0xFF; 32]));
+        let read =
+            |limit| decode_from_reader_with_limit(encoded.as_bytes(), Alphabet::Bitcoin, limit);
+        assert_eq!(read(32).unwrap(), [0xFF; 32]);
+        assert_eq!(read(31), Err(DecodeError::InputTooLarge));
+
+        // An endless reader is cut off rather than buffered
+        let endless = std::io::repeat(b'z');
+        assert_eq!(
+            decode_from_reader_with_limit(endless, Alphabet::Bitcoin, 1024),
+            Err(DecodeError::InputTooLarge)
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_decode_secret() {
+        let secret = [0u8, 0, 0xDE, 0xAD, 0xBE, 0xEF];
+        let encoded = encode(secret);
+        assert_eq!(*decode_secret(&encoded).unwrap(), secret);
+        assert_eq!(
+            *decode_secret_with_alphabet(
+                &encode_with_alphabet(secret, Alphabet::Flickr),
+                Alphabet::Flickr
+            )
+            .unwrap(),
+            secret
+        );
+        assert!(decode_secret("0").is_err());
+    }
+
+    #[test]
+    fn test_wipe() {
+        let mut buf = vec![1u8, 2, 3];
+        wipe(&mut buf);
+        #[cfg(feature = "zeroize")]
+        assert!(buf.is_empty());
+        #[cfg(not(feature = "zeroize"))]
+        assert_eq!(buf, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_decode_error_display() {
+        let cases = [
+            (DecodeError::EmptyInput, "Input string is empty"),
+            (DecodeError::Overflow, "Numeric overflow during decoding"),
+            (DecodeError::BufferTooSmall, "Output buffer is too small"),
+            (
+                DecodeError::InvalidLength {
+                    expected: 32,
+                    actual: 31,
+                },
+                "Invalid length: expected 32 bytes, got 31",
+            ),
+            (DecodeError::ChecksumMismatch, "Checksum mismatch"),
+            (DecodeError::InputTooLarge, "Input exceeds the size limit"),
+            (
+                DecodeError::LeadingZeros,
+                "Leading zero characters are not allowed",
+            ),
+            (
+                DecodeError::Io(std::io::ErrorKind::UnexpectedEof),
+                "I/O error: unexpected end of file",
+            ),
+        ];
+        for (err, message) in cases {
+            assert_eq!(err.to_string(), message);
+            assert!(core::error::Error::source(&err).is_none());
+        }
+    }
+
+    #[test]
+    fn test_invalid_character_position() {
+        // Positions count from the start of the input, including leading zeros
+        let err = decode("111O").unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::InvalidCharacter {
+                character: 'O',
+                position: 3
+            }
+        );
+        assert_eq!(err.to_string(), "Invalid character 'O' at position 3");
+    }
+
     #[test]
     fn test_roundtrip_random_data() {
         let test_cases = vec![
@@ -493,9 +1966,9 @@ mod tests {
         // Trying to decode with wrong alphabet should fail (in most cases)
         // Note: This might not always fail due to overlapping characters, but it's worth testing
         let result = decode_with_alphabet(&bitcoin_encoded, Alphabet::Ripple);
-        if result.is_ok() {
+        if let Ok(decoded) = result {
             // If it doesn't fail, the result should be different from original
-            assert_ne!(result.unwrap(), data);
+            assert_ne!(decoded, data);
         }
     }
 
@@ -541,11 +2014,111 @@ mod tests {
         }
     }
 
+    const REVERSED: &[u8; 58] = b"zyxwvutsrqponmkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA987654321";
+
+    #[test]
+    fn test_custom_alphabet_roundtrip() {
+        let def: &'static AlphabetDef = Box::leak(Box::new(AlphabetDef::new(REVERSED).unwrap()));
+        let alphabet = Alphabet::Custom(def);
+
+        for original in [vec![], vec![0, 0, 1], b"Hello, World!".to_vec()] {
+            let encoded = encode_with_alphabet(&original, alphabet);
+            assert!(encoded.bytes().all(|c| REVERSED.contains(&c)));
+            let decoded = decode_with_alphabet(&encoded, alphabet).unwrap();
+            assert_eq!(original, decoded);
+        }
+        assert_eq!(encode_with_alphabet([0, 0], alphabet), "zz");
+    }
+
+    #[test]
+    fn test_custom_alphabet_matches_builtin() {
+        let def = AlphabetDef::new(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+            .unwrap();
+        let alphabet = Alphabet::Custom(Box::leak(Box::new(def)));
+        assert_eq!(encode_with_alphabet(b"Hello", alphabet), "9Ajdvzr");
+        assert_eq!(alphabet.decode_table(), Alphabet::Bitcoin.decode_table());
+    }
+
+    #[test]
+    fn test_custom_alphabet_rejects_duplicates() {
+        let mut chars = *REVERSED;
+        chars[57] = b'z';
+        assert_eq!(
+            AlphabetDef::new(&chars),
+            Err(AlphabetError::DuplicateCharacter('z'))
+        );
+    }
+
+    #[test]
+    fn test_custom_alphabet_rejects_non_ascii() {
+        let mut chars = *REVERSED;
+        chars[10] = 0xC3;
+        assert_eq!(
+            AlphabetDef::new(&chars),
+            Err(AlphabetError::NonAsciiCharacter(0xC3))
+        );
+    }
+
+    #[test]
+    fn test_custom_alphabet_rejects_non_printable() {
+        for c in [b' ', b'\n', 0x7F] {
+            let mut chars = *REVERSED;
+            chars[10] = c;
+            assert_eq!(
+                AlphabetDef::new(&chars),
+                Err(AlphabetError::NonPrintableCharacter(c as char))
+            );
+        }
+        assert_eq!(
+            AlphabetError::NonPrintableCharacter('\n').to_string(),
+            "Non-printable character in alphabet: '\\n'"
+        );
+    }
+
+    #[test]
+    fn test_custom_alphabet_in_const() {
+        const DEF: AlphabetDef = match AlphabetDef::new(REVERSED) {
+            Ok(def) => def,
+            Err(_) => panic!("invalid alphabet"),
+        };
+        const ALPHABET: Alphabet = Alphabet::Custom(&DEF);
+        assert_eq!(ALPHABET.decode_table()[b'z' as usize], 0);
+        assert_eq!(decode_with_alphabet("z1", ALPHABET).unwrap(), [0, 57]);
+    }
+
     #[test]
     fn test_alphabet_default() {
         assert_eq!(Alphabet::default(), Alphabet::Bitcoin);
     }
 
+    #[test]
+    fn test_decode_tables() {
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let table = alphabet.decode_table();
+            assert!(core::ptr::eq(table, alphabet.decode_table()));
+            for (i, &c) in alphabet.as_bytes().iter().enumerate() {
+                assert_eq!(table[c as usize], i as u8);
+            }
+            assert_eq!(table.iter().filter(|&&d| d != 255).count(), 58);
+        }
+    }
+
+    #[test]
+    fn test_alphabet_from_name() {
+        for (name, alphabet) in [
+            ("bitcoin", Alphabet::Bitcoin),
+            ("BTC", Alphabet::Bitcoin),
+            ("Ripple", Alphabet::Ripple),
+            ("xrp", Alphabet::Ripple),
+            ("FLICKR", Alphabet::Flickr),
+        ] {
+            assert_eq!(Alphabet::from_name(name), Ok(alphabet));
+            assert_eq!(name.parse::<Alphabet>(), Ok(alphabet));
+        }
+        assert_eq!(Alphabet::from_name(""), Err(ParseAlphabetError));
+        assert_eq!("bitcoins".parse::<Alphabet>(), Err(ParseAlphabetError));
+    }
+
     #[test]
     fn test_alphabet_as_bytes() {
         assert_eq!(
@@ -1,122 +1,2182 @@
+mod cli;
+
+use std::borrow::Cow;
 use std::env;
+use std::fs;
 use std::io::{self, Read, Write};
 use std::process;
+use std::time::{Duration, Instant};
+
+use cli::Tool;
 
-use b58::{Alphabet, DecodeError, decode_with_alphabet, encode_with_alphabet};
+use b58::{
+    Alphabet, AlphabetDef, Base58DecodeReader, Base58EncodeWriter, DecodeError,
+    decode_check_with_alphabet, decode_with_alphabet, encode_check_with_alphabet,
+    encode_with_alphabet,
+};
 
 fn print_usage() {
     eprintln!("base58 - Base58 encoding and decoding utility");
     eprintln!();
     eprintln!("USAGE:");
-    eprintln!("    base58 [OPTIONS]");
+    eprintln!("    base58 [OPTIONS] [FILE...]");
+    eprintln!("    base58 <COMMAND> [OPTIONS] [FILE...]");
+    eprintln!("    base58 <FORMAT> <ACTION> [ARGS...]");
+    eprintln!();
+    eprintln!("With no FILE, or when FILE is -, read standard input. Each FILE is processed");
+    eprintln!("separately. Option values may be attached as in --alphabet=ripple or -w76,");
+    eprintln!("short flags may be combined as in -dn, and -- ends the options.");
+    eprintln!();
+    eprintln!("COMMANDS:");
+    eprintln!("    encode                       Encode input as Base58 (the default)");
+    eprintln!("    decode                       Decode Base58 input");
+    eprintln!("    check                        Verify the checksum of Base58Check input");
+    eprintln!("    inspect                      Describe Base58 input and what it decodes to");
+    eprintln!("    transcode                    Re-encode Base58 input with another alphabet");
+    eprintln!("    help                         Show this help message");
+    eprintln!();
+    eprintln!("FORMATS (see base58 <FORMAT> --help):");
+    eprintln!("    addr                         Legacy Bitcoin addresses");
+    eprintln!("    wif                          Private keys in Wallet Import Format");
+    eprintln!("    uuid                         Short Base58 slugs for UUIDs");
+    eprintln!("    id                           Random Base58 identifiers");
+    eprintln!("    solana                       Solana keypair formats");
+    eprintln!("    ss58                         Substrate SS58 addresses");
+    eprintln!("    xrp                          XRP Ledger classic addresses and seeds");
+    eprintln!("    cid                          IPFS version 0 CIDs");
+    eprintln!("    vanity                       Search for encodings that start with a pattern");
     eprintln!();
     eprintln!("OPTIONS:");
     eprintln!("    -d, --decode                 Decode Base58 input (default: encode)");
     eprintln!(
         "    -a, --alphabet <ALPHABET>    Specify alphabet (bitcoin, ripple, flickr) [default: bitcoin]"
     );
+    eprintln!("                                 custom:<CHARS> uses the 58 characters CHARS, and");
+    eprintln!("                                 @FILE reads them from FILE");
+    eprintln!(
+        "                                 auto tries bitcoin, ripple, then flickr when decoding"
+    );
+    eprintln!("        --from <ALPHABET>        With transcode, the alphabet of the input");
+    eprintln!("        --to <ALPHABET>          With transcode, the alphabet of the output");
+    eprintln!("        --from base64            Read the bytes to encode as Base64");
+    eprintln!("        --to base64              Write decoded bytes as Base64");
+    eprintln!("    -c, --check                  Append (encode) or verify and remove (decode) a");
+    eprintln!("                                 Base58Check checksum");
+    eprintln!("        --format <FORMAT>        plain, or check for the same as --check");
+    eprintln!("                                 [default: plain]");
+    eprintln!(
+        "        --version-byte <BYTE>    With --check, prepend (encode) or verify and strip"
+    );
+    eprintln!("                                 (decode) this version byte, e.g. 0x00");
+    eprintln!(
+        "    -i, --ignore-garbage         When decoding, skip characters that are not in the"
+    );
+    eprintln!("                                 alphabet instead of failing");
+    eprintln!("        --hex-in                 Read the bytes to encode as hex");
+    eprintln!("        --hex-out                Write decoded bytes as hex");
+    eprintln!("    -l, --lines                  Treat each input line as a separate value");
+    eprintln!("    -0, --null                   Like --lines, with records ending in NUL bytes");
+    eprintln!(
+        "    -w, --wrap <COLS>            Wrap encoded lines after COLS characters (0 disables"
+    );
+    eprintln!("                                 wrapping) [default: 0]");
+    eprintln!("    -n, --no-newline             Do not write a newline after the output");
+    eprintln!("        --stream                 Encode or decode in constant memory, using the");
+    eprintln!("                                 block-wise format of b58::monero rather than");
+    eprintln!("                                 plain Base58");
+    eprintln!("        --progress               With --stream, show the amount converted and the");
+    eprintln!("                                 throughput on stderr");
+    eprintln!("        --secret                 Read one value from the terminal without echoing");
+    eprintln!("                                 it, keeping it out of shell history and ps");
+    eprintln!("        --validate               Only check that the input decodes, printing OK");
+    eprintln!(
+        "        --json                   Write each result as a JSON object on its own line"
+    );
+    eprintln!(
+        "        --tag                    Start each result with the name of its file, or add"
+    );
+    eprintln!("                                 it to JSON objects as \"file\"");
+    eprintln!(
+        "    -q, --quiet                  Write no output or error messages; the exit status"
+    );
+    eprintln!("                                 is 0 on success, 1 for invalid input and 2 for");
+    eprintln!("                                 usage errors");
+    eprintln!("    -o, --output <FILE>          Write output to FILE instead of stdout");
+    eprintln!("        --max-size <BYTES>       Refuse inputs larger than BYTES, which may end in");
+    eprintln!("                                 K, M or G; 0 disables the limit [default: 64M,");
+    eprintln!("                                 or none with --stream]");
     eprintln!("    -h, --help                   Show this help message");
+    eprintln!("        --version                Print the version");
+    eprintln!();
+    eprintln!("ENVIRONMENT:");
+    eprintln!("    BASE58_ALPHABET              Default for --alphabet; auto applies only when");
+    eprintln!("                                 decoding");
+    eprintln!("    BASE58_FORMAT                Default for --format");
     eprintln!();
     eprintln!("EXAMPLES:");
     eprintln!("    printf 'Hello, World!' | base58");
+    eprintln!("    printf '72k1xXWG59fYdzSNoA' | base58 decode");
     eprintln!("    printf '72k1xXWG59fYdzSNoA' | base58 -d");
-    eprintln!("    base58 --alphabet ripple < input.txt");
-    eprintln!("    base58 -d --alphabet bitcoin < encoded.txt");
+    eprintln!("    base58 decode -o image.png image.b58");
+    eprintln!("    base58 --wrap 76 image.png");
+    eprintln!("    base58 --stream disk.img > disk.b58 && base58 -d --stream disk.b58 > disk.img");
+    eprintln!("    base58 --stream --progress -o disk.b58 disk.img");
+    eprintln!("    base58 --max-size 256M backup.tar");
+    eprintln!("    AUTH=$(base58 -n < key.bin)");
+    eprintln!("    if base58 --validate --quiet id.txt; then echo valid; fi");
+    eprintln!("    base58 encode --alphabet ripple input.txt");
+    eprintln!("    export BASE58_ALPHABET=ripple BASE58_FORMAT=check");
+    eprintln!("    base58 --alphabet @alphabet.txt input.txt");
+    eprintln!("    echo rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh | base58 check --alphabet auto");
+    eprintln!("    printf '1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2' | base58 check --version-byte 0x00");
+    eprintln!("    base58 encode --check --version-byte 0x00 hash160.bin");
+    eprintln!("    echo 00ff10 | base58 --hex-in");
+    eprintln!("    base58 --hex-in --secret -o key.b58");
+    eprintln!("    echo 1LQo | base58 -d --hex-out");
+    eprintln!("    echo SGVsbG8= | base58 --from base64");
+    eprintln!("    base58 check --lines addresses.txt");
+    eprintln!("    sed -n 's/.*address=//p' app.log | base58 check -l -i");
+    eprintln!("    base58 --tag keys/*.bin");
+    eprintln!("    base58 decode --lines --hex-out --json ids.txt | jq .output");
+    eprintln!("    find keys -type f -print0 | xargs -0 cat | base58 -0");
+    eprintln!("    printf '1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2' | base58 inspect");
+    eprintln!("    base58 transcode --from ripple --to bitcoin --lines ids.txt");
 }
 
-fn parse_alphabet(alphabet_str: &str) -> Result<Alphabet, String> {
-    match alphabet_str.to_lowercase().as_str() {
-        "bitcoin" | "btc" => Ok(Alphabet::Bitcoin),
-        "ripple" | "xrp" => Ok(Alphabet::Ripple),
-        "flickr" => Ok(Alphabet::Flickr),
-        _ => Err(format!(
-            "Unknown alphabet: {alphabet_str}. Valid options: bitcoin, ripple, flickr"
-        )),
+/// What the binary does with its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Command {
+    #[default]
+    Encode,
+    Decode,
+    Check,
+    Inspect,
+    Transcode,
+    Validate,
+}
+
+impl Command {
+    /// Looks up a subcommand by name
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "encode" => Some(Command::Encode),
+            "decode" => Some(Command::Decode),
+            "check" => Some(Command::Check),
+            "inspect" => Some(Command::Inspect),
+            "transcode" => Some(Command::Transcode),
+            _ => None,
+        }
     }
 }
 
-fn read_stdin() -> Result<Vec<u8>, io::Error> {
-    let mut buffer = Vec::new();
-    io::stdin().read_to_end(&mut buffer)?;
-    Ok(buffer)
+/// Settings parsed from the command line
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Options {
+    command: Command,
+    alphabet: Alphabet,
+    /// Alphabet that `transcode` encodes its output with
+    target: Alphabet,
+    /// Whether to find which built-in alphabet decodes each value
+    detect_alphabet: bool,
+    /// Whether to drop characters outside the alphabet before decoding
+    ignore_garbage: bool,
+    /// Whether values carry a Base58Check checksum
+    check: bool,
+    /// Version byte that Base58Check payloads start with
+    version: Option<u8>,
+    /// Whether the bytes to encode are given as hex
+    hex_in: bool,
+    /// Whether decoded bytes are written as hex
+    hex_out: bool,
+    /// Whether the bytes to encode are given as Base64
+    base64_in: bool,
+    /// Whether decoded bytes are written as Base64
+    base64_out: bool,
+    /// The byte ending each record when every line or NUL-terminated record is a
+    /// separate value
+    delimiter: Option<u8>,
+    /// Characters per line of encoded output, or 0 for one line
+    wrap: usize,
+    /// Whether to leave out the newline after the output
+    no_newline: bool,
+    /// Whether to write nothing and report only through the exit status
+    quiet: bool,
+    /// Whether to describe each result as a JSON object
+    json: bool,
+    /// Whether to start each result with the name of its input
+    tag: bool,
+    /// Whether to use the block-wise format, reading and writing as the data flows
+    stream: bool,
+    /// Whether to report progress on stderr while streaming
+    progress: bool,
+    /// Whether to read the one value from the terminal without echoing it
+    secret: bool,
+    /// Largest input to read, or `None` for no limit
+    max_size: Option<u64>,
+    /// Input files, where `-` is stdin; empty means stdin
+    files: Vec<String>,
+    /// Output file, or stdout if `None`
+    output: Option<String>,
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+/// The result of parsing the command line
+#[derive(Debug, PartialEq, Eq)]
+enum Parsed {
+    Help,
+    Version,
+    /// Help for a format-specific subcommand
+    Usage(&'static str),
+    Run(Options),
+    Tool(Tool),
+}
 
-    let mut decode_mode = false;
-    let mut alphabet = Alphabet::Bitcoin;
-    let mut i = 1;
+/// Defaults taken from the environment for options not given on the command line
+#[derive(Debug, Clone, Default)]
+struct EnvDefaults {
+    /// `BASE58_ALPHABET`, a value for `--alphabet`
+    alphabet: Option<String>,
+    /// `BASE58_FORMAT`, a value for `--format`
+    format: Option<String>,
+}
 
-    while i < args.len() {
-        match args[i].as_str() {
-            "-d" | "--decode" => decode_mode = true,
-            "-h" | "--help" => {
-                print_usage();
-                process::exit(0);
+impl EnvDefaults {
+    /// Reads the defaults, ignoring variables that are unset or empty
+    fn from_env() -> Self {
+        let var = |name| env::var(name).ok().filter(|value| !value.is_empty());
+        EnvDefaults {
+            alphabet: var("BASE58_ALPHABET"),
+            format: var("BASE58_FORMAT"),
+        }
+    }
+}
+
+/// Parses a `--format` value, returning whether it means Base58Check
+fn parse_format(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "plain" => Ok(false),
+        "check" => Ok(true),
+        _ => Err(format!("Unknown format: {value} (expected plain or check)")),
+    }
+}
+
+/// Options that take a value, which may also be attached as `--name=VALUE` or `-xVALUE`
+const VALUE_OPTIONS: [&str; 11] = [
+    "-a",
+    "--alphabet",
+    "--from",
+    "--to",
+    "--format",
+    "--version-byte",
+    "-w",
+    "--wrap",
+    "-o",
+    "--output",
+    "--max-size",
+];
+
+/// Largest input read without `--max-size`, beyond which the quadratic conversion
+/// would take minutes
+const DEFAULT_MAX_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Parses a size in bytes, optionally with a K, M or G suffix for KiB, MiB or GiB
+fn parse_size(value: &str) -> Result<u64, String> {
+    let digits = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(digits);
+    let shift = match unit.to_ascii_lowercase().as_str() {
+        "" => 0,
+        "k" | "kib" => 10,
+        "m" | "mib" => 20,
+        "g" | "gib" => 30,
+        _ => return Err(format!("Invalid size: {value}")),
+    };
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(1 << shift))
+        .ok_or_else(|| format!("Invalid size: {value}"))
+}
+
+/// Rewrites arguments to one option or value each: attached values are separated and
+/// combined short flags such as `-dn` are split up. Values of options and everything
+/// after `--` are kept as they are.
+fn split_flags(args: &[String]) -> Vec<String> {
+    let mut split = Vec::with_capacity(args.len());
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            split.push(arg.clone());
+            split.extend(args.cloned());
+            break;
+        }
+        if let Some((name, value)) = arg.split_once('=') {
+            if name.starts_with("--") && VALUE_OPTIONS.contains(&name) {
+                split.push(name.into());
+                split.push(value.into());
+                continue;
+            }
+        }
+        if arg.starts_with("--") || !arg.starts_with('-') || arg.len() <= 2 {
+            split.push(arg.clone());
+            if VALUE_OPTIONS.contains(&arg.as_str()) {
+                split.extend(args.next().cloned());
+            }
+            continue;
+        }
+
+        // Short flags up to the first that takes a value, which is the rest of the
+        // argument or else the next one
+        for (i, c) in arg.char_indices().skip(1) {
+            let flag = format!("-{c}");
+            let takes_value = VALUE_OPTIONS.contains(&flag.as_str());
+            split.push(flag);
+            if takes_value {
+                match &arg[i + c.len_utf8()..] {
+                    "" => split.extend(args.next().cloned()),
+                    value => split.push(value.into()),
+                }
+                break;
+            }
+        }
+    }
+    split
+}
+
+/// Parses the arguments after the program name. A leading subcommand name selects the
+/// command; without one, the bare flag form encodes, or decodes with `-d`. Options that
+/// are not given fall back to `defaults`.
+fn parse_args(args: &[String], defaults: &EnvDefaults) -> Result<Parsed, String> {
+    if args.first().is_some_and(|first| first == "help") {
+        return Ok(Parsed::Help);
+    }
+    if let Some((name, rest)) = args.split_first() {
+        if let Some(usage) = Tool::usage(name) {
+            if rest.iter().any(|arg| arg == "-h" || arg == "--help") {
+                return Ok(Parsed::Usage(usage));
+            }
+            if let Some(tool) = Tool::parse(name, rest) {
+                return tool.map(Parsed::Tool);
             }
-            "-a" | "--alphabet" => {
-                if i + 1 >= args.len() {
-                    eprintln!("Error: --alphabet requires a value");
-                    process::exit(1);
+        }
+    }
+    let args = split_flags(args);
+    let subcommand = args.first().and_then(|first| Command::from_name(first));
+    let rest = &args[subcommand.map_or(0, |_| 1)..];
+
+    let mut decode_flag = false;
+    let mut validate_flag = false;
+    let mut alphabet_flag = false;
+    let mut max_size = None;
+    let mut from_flag = false;
+    let mut to_flag = false;
+    // Whether values carry a checksum, if `--check` or `--format` said so
+    let mut format = None;
+    let mut options = Options::default();
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "-d" | "--decode" => decode_flag = true,
+            "-h" | "--help" => return Ok(Parsed::Help),
+            "--version" => return Ok(Parsed::Version),
+            "-a" | "--alphabet" | "--from" => {
+                let value = rest
+                    .next()
+                    .ok_or_else(|| format!("{arg} requires a value"))?;
+                if arg == "--from" && value.eq_ignore_ascii_case("base64") {
+                    options.base64_in = true;
+                    continue;
                 }
-                i += 1;
-                match parse_alphabet(&args[i]) {
-                    Ok(a) => alphabet = a,
-                    Err(e) => {
-                        eprintln!("Error: {e}");
-                        process::exit(1);
-                    }
+                alphabet_flag = true;
+                from_flag |= arg == "--from";
+                options.detect_alphabet = value.eq_ignore_ascii_case("auto");
+                if !options.detect_alphabet {
+                    options.alphabet = parse_alphabet(value)?;
                 }
             }
-            arg if arg.starts_with("-") => {
-                eprintln!("Error: Unknown option: {arg}");
-                print_usage();
-                process::exit(1);
+            "--to" => {
+                let value = rest.next().ok_or("--to requires a value")?;
+                if value.eq_ignore_ascii_case("base64") {
+                    options.base64_out = true;
+                } else {
+                    options.target = parse_alphabet(value)?;
+                    to_flag = true;
+                }
             }
-            _ => {
-                eprintln!("Error: Unexpected argument: {}", args[i]);
-                process::exit(1);
+            "-c" | "--check" => format = Some(true),
+            "--format" => {
+                let value = rest.next().ok_or("--format requires a value")?;
+                format = Some(parse_format(value)?);
+            }
+            "-i" | "--ignore-garbage" => options.ignore_garbage = true,
+            "--version-byte" => {
+                let value = rest.next().ok_or("--version-byte requires a value")?;
+                options.version = Some(parse_byte(value)?);
             }
+            "--hex-in" => options.hex_in = true,
+            "--hex-out" => options.hex_out = true,
+            "-l" | "--lines" => options.delimiter = Some(b'\n'),
+            "-0" | "--null" => options.delimiter = Some(0),
+            "-w" | "--wrap" => {
+                let value = rest.next().ok_or("--wrap requires a value")?;
+                options.wrap = value
+                    .parse()
+                    .map_err(|_| format!("Invalid line width: {value}"))?;
+            }
+            "-n" | "--no-newline" => options.no_newline = true,
+            "--validate" => validate_flag = true,
+            "-q" | "--quiet" => options.quiet = true,
+            "--json" => options.json = true,
+            "--tag" => options.tag = true,
+            "--stream" => options.stream = true,
+            "--max-size" => {
+                let value = rest.next().ok_or("--max-size requires a value")?;
+                max_size = Some(parse_size(value)?);
+            }
+            "--progress" => options.progress = true,
+            "--secret" => options.secret = true,
+            "-o" | "--output" => {
+                options.output = Some(rest.next().ok_or("--output requires a value")?.clone());
+            }
+            "--" => {
+                options.files.extend(rest.cloned());
+                break;
+            }
+            "-" => options.files.push(arg.clone()),
+            arg if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ => options.files.push(arg.clone()),
         }
-        i += 1;
     }
 
-    let input = match read_stdin() {
-        Ok(data) => data,
-        Err(e) => {
-            eprintln!("Error reading input: {e}");
-            process::exit(1);
+    options.command = match (subcommand, decode_flag) {
+        (None, false) => Command::Encode,
+        (None, true) | (Some(Command::Decode), _) => Command::Decode,
+        (Some(command), false) => command,
+        (Some(_), true) => return Err("--decode can only be used without a command".into()),
+    };
+    if validate_flag {
+        options.command = match subcommand {
+            None | Some(Command::Decode) => Command::Validate,
+            Some(_) => return Err("--validate can only be used when decoding".into()),
+        };
+    }
+    // Flags override the environment, which overrides the built-in defaults
+    match &defaults.alphabet {
+        Some(_) if alphabet_flag => {}
+        // A default of auto applies only to the commands that decode
+        Some(value) if value.eq_ignore_ascii_case("auto") => {
+            options.detect_alphabet = options.command != Command::Encode;
+        }
+        Some(value) => {
+            options.alphabet =
+                parse_alphabet(value).map_err(|e| format!("BASE58_ALPHABET: {e}"))?;
+        }
+        None => {}
+    }
+    if let (None, None, Some(value)) = (format, options.version, &defaults.format) {
+        format = Some(parse_format(value).map_err(|e| format!("BASE58_FORMAT: {e}"))?);
+    }
+    if options.version.is_some() && format == Some(false) {
+        return Err("--version-byte cannot be used with --format plain".into());
+    }
+    options.check = format.unwrap_or(false) || options.version.is_some();
+
+    if options.command == Command::Transcode {
+        // Base64 on either side makes transcoding plain encoding or decoding
+        options.command = match (options.base64_in, options.base64_out) {
+            _ if !to_flag && !options.base64_out => {
+                return Err("transcode requires --to".into());
+            }
+            (false, false) => Command::Transcode,
+            (true, false) => {
+                options.alphabet = options.target;
+                Command::Encode
+            }
+            (false, true) => Command::Decode,
+            (true, true) => return Err("--from and --to cannot both be base64".into()),
+        };
+    } else if from_flag || to_flag {
+        return Err(
+            "--from and --to can only be used with transcode, or to read or write base64".into(),
+        );
+    }
+    if options.detect_alphabet && options.command == Command::Encode {
+        return Err("--alphabet auto can only be used when decoding".into());
+    }
+    if options.ignore_garbage && options.command == Command::Encode {
+        return Err("--ignore-garbage can only be used when decoding".into());
+    }
+    if options.hex_in && options.command != Command::Encode {
+        return Err("--hex-in can only be used when encoding".into());
+    }
+    if options.hex_out && options.command != Command::Decode {
+        return Err("--hex-out can only be used when decoding".into());
+    }
+    if options.base64_in && (options.command != Command::Encode || options.hex_in) {
+        return Err("--from base64 can only be used when encoding, without --hex-in".into());
+    }
+    if options.base64_out && (options.command != Command::Decode || options.hex_out) {
+        return Err("--to base64 can only be used when decoding, without --hex-out".into());
+    }
+    if options.wrap > 0 && options.delimiter.is_some() {
+        return Err("--wrap cannot be used with --lines or --null".into());
+    }
+    if options.no_newline && options.delimiter.is_some() {
+        return Err("--no-newline cannot be used with --lines or --null".into());
+    }
+    if options.no_newline && options.json {
+        return Err("--no-newline cannot be used with --json".into());
+    }
+    let raw_output = options.command == Command::Decode && !options.hex_out && !options.base64_out;
+    // JSON strings and tagged lines are text, which raw bytes would be garbled into
+    if options.json && raw_output {
+        return Err(
+            "--json cannot be used with raw decoded bytes; add --hex-out or --to base64".into(),
+        );
+    }
+    if options.tag && raw_output {
+        return Err(
+            "--tag cannot be used with raw decoded bytes; add --hex-out or --to base64".into(),
+        );
+    }
+    if options.secret && !options.files.is_empty() {
+        return Err("--secret reads from the terminal and cannot be used with files".into());
+    }
+    if options.secret && (options.stream || options.delimiter.is_some()) {
+        return Err("--secret cannot be used with --stream, --lines or --null".into());
+    }
+    // Streaming takes constant memory and linear time, so only whole inputs are limited
+    options.max_size = match (max_size, options.stream) {
+        (Some(_), true) => return Err("--max-size cannot be used with --stream".into()),
+        (Some(0), false) | (None, true) => None,
+        (Some(max_size), false) => Some(max_size),
+        (None, false) => Some(DEFAULT_MAX_SIZE),
+    };
+    if options.progress && !options.stream {
+        return Err("--progress can only be used with --stream".into());
+    }
+    if options.stream {
+        if !matches!(options.command, Command::Encode | Command::Decode) {
+            return Err("--stream can only be used when encoding or decoding".into());
+        }
+        if options.detect_alphabet || options.alphabet != Alphabet::Bitcoin {
+            return Err("--stream only supports the bitcoin alphabet".into());
+        }
+        let conversions = options.hex_in || options.hex_out || options.base64_in;
+        if options.check || conversions || options.base64_out {
+            return Err("--stream cannot be used with --check, hex or base64".into());
         }
+        if options.delimiter.is_some() || options.wrap > 0 || options.json {
+            return Err("--stream cannot be used with --lines, --null, --wrap or --json".into());
+        }
+        if options.ignore_garbage {
+            return Err("--stream cannot be used with --ignore-garbage".into());
+        }
+    }
+    Ok(Parsed::Run(options))
+}
+
+/// Parses an alphabet name, `custom:` followed by 58 characters, or `@` followed by the
+/// name of a file holding them
+pub(crate) fn parse_alphabet(value: &str) -> Result<Alphabet, String> {
+    let chars = if let Some(chars) = value.strip_prefix("custom:") {
+        chars.to_string()
+    } else if let Some(path) = value.strip_prefix('@') {
+        let chars = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+        chars.trim().to_string()
+    } else {
+        return value.parse().map_err(|e| format!("{e}: {value}"));
     };
 
-    if decode_mode {
-        let input_str = match String::from_utf8(input) {
-            Ok(s) => s.trim().to_string(),
-            Err(e) => {
-                eprintln!("Error: Input is not valid UTF-8: {e}");
-                process::exit(1);
+    let chars: &[u8; 58] = chars.as_bytes().try_into().map_err(|_| {
+        format!(
+            "Custom alphabet must have 58 characters, found {}",
+            chars.chars().count()
+        )
+    })?;
+    let def = AlphabetDef::new(chars).map_err(|e| e.to_string())?;
+    // The alphabet is needed until the process exits
+    Ok(Alphabet::Custom(Box::leak(Box::new(def))))
+}
+
+/// Parses a byte given in decimal or as hex with a `0x` prefix
+pub(crate) fn parse_byte(value: &str) -> Result<u8, String> {
+    let (digits, radix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => (hex, 16),
+        None => (value, 10),
+    };
+    let invalid = || format!("Invalid byte value: {value}");
+    // from_str_radix alone would also take a leading + sign
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(invalid());
+    }
+    u8::from_str_radix(digits, radix).map_err(|_| invalid())
+}
+
+/// Reads and concatenates the input files, or stdin if there are none
+pub(crate) fn read_input(files: &[String]) -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    if files.is_empty() {
+        io::stdin()
+            .read_to_end(&mut buffer)
+            .map_err(|e| format!("Failed to read input: {e}"))?;
+    }
+    for file in files {
+        let read = if file == "-" {
+            io::stdin().read_to_end(&mut buffer).map(|_| ())
+        } else {
+            fs::read(file).map(|data| buffer.extend_from_slice(&data))
+        };
+        read.map_err(|e| format!("{file}: {e}"))?;
+    }
+    Ok(buffer)
+}
+
+/// Prompts on the terminal and reads one line with echo turned off, so the value
+/// appears neither on screen nor in the arguments that `ps` shows
+#[cfg(unix)]
+fn read_secret(prompt: &str) -> Result<Vec<u8>, String> {
+    use std::io::BufRead;
+
+    let mut tty = fs::File::options()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .map_err(|e| format!("--secret needs a terminal: {e}"))?;
+    let set_echo = |tty: &fs::File, on: bool| {
+        let status = process::Command::new("stty")
+            .arg(if on { "echo" } else { "-echo" })
+            .stdin(tty.try_clone()?)
+            .status()?;
+        match status.success() {
+            true => Ok(()),
+            false => Err(io::Error::other("stty failed")),
+        }
+    };
+    let tty_error = |e: io::Error| format!("Failed to read from the terminal: {e}");
+
+    write!(tty, "{prompt}").map_err(tty_error)?;
+    set_echo(&tty, false).map_err(tty_error)?;
+    let mut line = Vec::new();
+    let read = io::BufReader::new(&tty).read_until(b'\n', &mut line);
+    // Echo is restored even if the read failed, and the newline typed is not echoed
+    let restored = set_echo(&tty, true);
+    writeln!(tty).map_err(tty_error)?;
+    read.and(restored).map_err(tty_error)?;
+
+    while line.last().is_some_and(|&b| b == b'\n' || b == b'\r') {
+        line.pop();
+    }
+    Ok(line)
+}
+
+/// Prompts on the terminal and reads one line with echo turned off
+#[cfg(not(unix))]
+fn read_secret(_prompt: &str) -> Result<Vec<u8>, String> {
+    Err("--secret is only supported on Unix-like systems".into())
+}
+
+/// Formats bytes as lowercase hex
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parses hex digits of either case, ignoring whitespace between them
+pub(crate) fn from_hex(input: &[u8]) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = input
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    if digits.len() % 2 != 0 {
+        return Err("Hex input has an odd number of digits".into());
+    }
+    digits
+        .chunks_exact(2)
+        .map(|pair| {
+            // from_str_radix alone would also take a leading + sign
+            Some(pair)
+                .filter(|pair| pair.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|pair| core::str::from_utf8(pair).ok())
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| format!("Invalid hex digits: {}", String::from_utf8_lossy(pair)))
+        })
+        .collect()
+}
+
+/// Characters of standard Base64, in digit order
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Formats bytes as standard Base64 with padding
+fn to_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (i, &b)| group | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(BASE64_ALPHABET[(group >> (18 - 6 * i) & 63) as usize] as char);
+        }
+        for _ in chunk.len()..3 {
+            out.push('=');
+        }
+    }
+    out
+}
+
+/// Parses standard or URL-safe Base64, ignoring whitespace. Padding is optional.
+fn from_base64(input: &[u8]) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = input
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    let digits = digits
+        .strip_suffix(b"==")
+        .or_else(|| digits.strip_suffix(b"="))
+        .unwrap_or(&digits);
+    if digits.len() % 4 == 1 {
+        return Err("Base64 input has an invalid length".into());
+    }
+
+    let mut out = Vec::with_capacity(digits.len() / 4 * 3 + 2);
+    for chunk in digits.chunks(4) {
+        let mut group = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'+' | b'-' => 62,
+                b'/' | b'_' => 63,
+                _ => {
+                    return Err(format!(
+                        "Invalid character '{}' in Base64 input",
+                        c.escape_ascii()
+                    ));
+                }
+            };
+            group |= (value as u32) << (18 - 6 * i);
+        }
+        // Four digits hold three bytes, and a short final group one byte fewer than digits
+        out.extend_from_slice(&group.to_be_bytes()[1..chunk.len()]);
+    }
+    Ok(out)
+}
+
+/// Returns the name of an alphabet as accepted by `--alphabet`
+fn alphabet_name(alphabet: Alphabet) -> &'static str {
+    match alphabet {
+        Alphabet::Bitcoin => "bitcoin",
+        Alphabet::Ripple => "ripple",
+        Alphabet::Flickr => "flickr",
+        Alphabet::Custom(_) => "custom",
+    }
+}
+
+/// Describes a decoding failure of `input` for the user. An invalid character is
+/// shown in place, under a caret.
+fn describe_decode_error(e: DecodeError, input: &str) -> String {
+    match e {
+        DecodeError::InvalidCharacter {
+            character,
+            position,
+        } => format!(
+            "Invalid character '{character}' at position {position} in Base58 input\n{}",
+            caret_diagnostic(input, position)
+        ),
+        DecodeError::ChecksumMismatch => "Base58Check checksum does not match".into(),
+        e => e.to_string(),
+    }
+}
+
+/// Characters of input shown around an invalid character
+const DIAGNOSTIC_WIDTH: usize = 72;
+
+/// Shows `input` with a caret under the character at byte offset `position`, cutting
+/// long input down to the part around it
+fn caret_diagnostic(input: &str, position: usize) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let column = input[..position].chars().count();
+    let start = column
+        .saturating_sub(DIAGNOSTIC_WIDTH / 2)
+        .min(chars.len().saturating_sub(DIAGNOSTIC_WIDTH));
+    let end = (start + DIAGNOSTIC_WIDTH).min(chars.len());
+    let before = if start > 0 { "..." } else { "" };
+    let after = if end < chars.len() { "..." } else { "" };
+    let excerpt: String = chars[start..end].iter().collect();
+    let indent = before.len() + column - start;
+    format!("  {before}{excerpt}{after}\n  {}^", " ".repeat(indent))
+}
+
+/// Interprets input as a trimmed Base58 string, joining lines wrapped by `--wrap`.
+/// With `--ignore-garbage`, every character outside the alphabet is dropped instead.
+fn base58_input<'a>(input: &'a [u8], options: &Options) -> Result<Cow<'a, str>, String> {
+    if options.ignore_garbage {
+        // The built-in alphabets share their characters, so under `--alphabet auto`
+        // the default alphabet keeps the same ones
+        let alphabet = options.alphabet.as_bytes();
+        let kept = String::from_utf8_lossy(input)
+            .chars()
+            .filter(|&c| c.is_ascii() && alphabet.contains(&(c as u8)))
+            .collect();
+        return Ok(Cow::Owned(kept));
+    }
+    let input = core::str::from_utf8(input)
+        .map_err(|e| format!("Input is not valid UTF-8: {e}"))?
+        .trim();
+    if input.contains(['\n', '\r']) {
+        Ok(Cow::Owned(input.replace(['\n', '\r'], "")))
+    } else {
+        Ok(Cow::Borrowed(input))
+    }
+}
+
+/// Breaks text into lines of `width` characters, or returns it unchanged if `width` is 0
+fn wrap(text: String, width: usize) -> String {
+    if width == 0 || text.len() <= width {
+        return text;
+    }
+    // Encoded text is ASCII, so any byte offset is a character boundary
+    let lines: Vec<&str> = text
+        .as_bytes()
+        .chunks(width)
+        .map(|line| core::str::from_utf8(line).expect("encoded output is ASCII"))
+        .collect();
+    lines.join("\n")
+}
+
+/// Splits input into records ending in `delimiter`, without the delimiter. A final
+/// delimiter does not start another record, and lines also drop a `\r` before the `\n`.
+fn split_records(input: &[u8], delimiter: u8) -> impl Iterator<Item = &[u8]> {
+    let records = input.strip_suffix(&[delimiter]).unwrap_or(input);
+    records
+        .split(move |&b| b == delimiter)
+        .filter(move |_| !input.is_empty())
+        .map(move |record| match delimiter {
+            b'\n' => record.strip_suffix(b"\r").unwrap_or(record),
+            _ => record,
+        })
+}
+
+/// Describes a Base58 string: its length, leading zeros, decoded bytes and whether it
+/// carries a valid Base58Check checksum
+fn inspect(input: &str, alphabet: Alphabet) -> Result<String, String> {
+    let decoded =
+        decode_with_alphabet(input, alphabet).map_err(|e| describe_decode_error(e, input))?;
+    let zero = alphabet.as_bytes()[0];
+    let leading_zeros = input.bytes().take_while(|&b| b == zero).count();
+
+    let mut report = String::new();
+    report.push_str(&format!("input:         {input}\n"));
+    report.push_str(&format!("alphabet:      {}\n", alphabet_name(alphabet)));
+    report.push_str(&format!("characters:    {}\n", input.len()));
+    report.push_str(&format!("leading zeros: {leading_zeros}\n"));
+    report.push_str(&format!("bytes:         {}\n", decoded.len()));
+    report.push_str(&format!("hex:           {}\n", to_hex(&decoded)));
+    match decode_check_with_alphabet(input, alphabet) {
+        Ok(payload) => match payload.split_first() {
+            Some((version, data)) => report.push_str(&format!(
+                "base58check:   valid (version 0x{version:02x}, {}-byte payload)",
+                data.len()
+            )),
+            None => report.push_str("base58check:   valid (empty payload)"),
+        },
+        Err(_) => report.push_str("base58check:   no"),
+    }
+    Ok(report)
+}
+
+/// Encodes one value, adding the version byte and checksum if requested
+fn encode_value(input: &[u8], options: &Options) -> String {
+    if !options.check {
+        return encode_with_alphabet(input, options.alphabet);
+    }
+    match options.version {
+        Some(version) => {
+            let payload: Vec<u8> = core::iter::once(version)
+                .chain(input.iter().copied())
+                .collect();
+            encode_check_with_alphabet(payload, options.alphabet)
+        }
+        None => encode_check_with_alphabet(input, options.alphabet),
+    }
+}
+
+/// Decodes one value, verifying and removing the checksum and version byte if requested
+fn decode_value(input: &str, options: &Options) -> Result<Vec<u8>, String> {
+    if !options.check {
+        return decode_with_alphabet(input, options.alphabet)
+            .map_err(|e| describe_decode_error(e, input));
+    }
+    let mut payload = decode_check_with_alphabet(input, options.alphabet)
+        .map_err(|e| describe_decode_error(e, input))?;
+    if let Some(version) = options.version {
+        match payload.first() {
+            Some(&found) if found == version => {
+                payload.remove(0);
+            }
+            Some(&found) => {
+                return Err(format!(
+                    "Version byte is 0x{found:02x}, expected 0x{version:02x}"
+                ));
             }
+            None => return Err("Base58Check payload has no version byte".into()),
+        }
+    }
+    Ok(payload)
+}
+
+/// Built-in alphabets in the order `--alphabet auto` tries them
+const DETECTED_ALPHABETS: [Alphabet; 3] = [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr];
+
+/// Returns the first built-in alphabet that decodes `input`, with a valid Base58Check
+/// checksum if `checksum` is set
+fn detect_alphabet(input: &str, checksum: bool) -> Option<Alphabet> {
+    DETECTED_ALPHABETS.into_iter().find(|&alphabet| {
+        if checksum {
+            decode_check_with_alphabet(input, alphabet).is_ok()
+        } else {
+            decode_with_alphabet(input, alphabet).is_ok()
+        }
+    })
+}
+
+/// Picks the alphabet for one value under `--alphabet auto` and reports it on stderr.
+/// The built-in alphabets share their characters, so without a checksum to verify the
+/// first one always matches.
+fn resolve_alphabet(input: &[u8], options: &Options) -> Result<Alphabet, String> {
+    let input = base58_input(input, options)?;
+    let alphabet = match options.command {
+        Command::Decode | Command::Transcode if !options.check => detect_alphabet(&input, false),
+        Command::Inspect => detect_alphabet(&input, true).or(detect_alphabet(&input, false)),
+        _ => detect_alphabet(&input, true),
+    };
+    let alphabet = alphabet.ok_or_else(|| {
+        let checksum = if options.check || options.command == Command::Check {
+            " with a valid checksum"
+        } else {
+            ""
+        };
+        format!(
+            "No built-in alphabet decodes the input{checksum} (tried bitcoin, ripple and flickr)"
+        )
+    })?;
+    if !options.quiet {
+        eprintln!("Detected alphabet: {}", alphabet_name(alphabet));
+    }
+    Ok(alphabet)
+}
+
+/// Runs the command on one value, returning its output without a line ending
+fn process(input: &[u8], options: &Options) -> Result<Vec<u8>, String> {
+    if options.detect_alphabet {
+        let options = Options {
+            alphabet: resolve_alphabet(input, options)?,
+            detect_alphabet: false,
+            ..options.clone()
         };
+        return process(input, &options);
+    }
 
-        match decode_with_alphabet(&input_str, alphabet) {
-            Ok(decoded) => {
-                if let Err(e) = io::stdout().write_all(&decoded) {
-                    eprintln!("Error writing output: {e}");
-                    process::exit(1);
+    match options.command {
+        Command::Encode => {
+            let encoded = if options.hex_in {
+                encode_value(&from_hex(input)?, options)
+            } else if options.base64_in {
+                encode_value(&from_base64(input)?, options)
+            } else {
+                encode_value(input, options)
+            };
+            Ok(wrap(encoded, options.wrap).into_bytes())
+        }
+        Command::Decode => {
+            let decoded = decode_value(&base58_input(input, options)?, options)?;
+            if options.hex_out {
+                Ok(to_hex(&decoded).into_bytes())
+            } else if options.base64_out {
+                Ok(to_base64(&decoded).into_bytes())
+            } else {
+                Ok(decoded)
+            }
+        }
+        Command::Check => {
+            let options = Options {
+                check: true,
+                ..options.clone()
+            };
+            decode_value(&base58_input(input, &options)?, &options)?;
+            Ok(b"OK".to_vec())
+        }
+        Command::Inspect => {
+            Ok(inspect(&base58_input(input, options)?, options.alphabet)?.into_bytes())
+        }
+        Command::Transcode => {
+            let decoded = decode_value(&base58_input(input, options)?, options)?;
+            let options = Options {
+                alphabet: options.target,
+                ..options.clone()
+            };
+            Ok(wrap(encode_value(&decoded, &options), options.wrap).into_bytes())
+        }
+        Command::Validate => {
+            decode_value(&base58_input(input, options)?, options)?;
+            Ok(b"OK".to_vec())
+        }
+    }
+}
+
+/// Runs the command on one value and describes the outcome as a JSON object, returning
+/// it with the outcome. With `--tag`, the object names the value's file.
+fn process_json(
+    input: &[u8],
+    file: Option<&str>,
+    options: &Options,
+) -> (String, Result<(), String>) {
+    let alphabet = match options.detect_alphabet {
+        true => resolve_alphabet(input, options),
+        false => Ok(options.alphabet),
+    };
+    let result = match &alphabet {
+        Ok(alphabet) => process(
+            input,
+            &Options {
+                alphabet: *alphabet,
+                detect_alphabet: false,
+                ..options.clone()
+            },
+        ),
+        Err(e) => Err(e.clone()),
+    };
+
+    // Whitespace around Base58 input is not part of the value
+    let input = String::from_utf8_lossy(input);
+    let input = match options.command {
+        Command::Encode => &input,
+        _ => input.trim(),
+    };
+    let alphabet = match alphabet {
+        Ok(alphabet) => json_string(alphabet_name(alphabet)),
+        Err(_) => "null".into(),
+    };
+    let output = match &result {
+        Ok(output) => json_string(&String::from_utf8_lossy(output)),
+        Err(_) => "null".into(),
+    };
+    let mut object = String::from("{");
+    if let Some(file) = file {
+        object.push_str(&format!("\"file\":{},", json_string(file)));
+    }
+    object.push_str(&format!(
+        "\"input\":{},\"output\":{output},\"alphabet\":{alphabet},\"valid\":{}",
+        json_string(input),
+        result.is_ok()
+    ));
+    // The caret under an invalid character is for terminals, not JSON consumers
+    if let Err(e) = &result {
+        let e = e.lines().next().unwrap_or_default();
+        object.push_str(&format!(",\"error\":{}", json_string(e)));
+    }
+    object.push('}');
+    (object, result.map(|_| ()))
+}
+
+/// Quotes text as a JSON string
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Returns the names of the inputs, where `-` is stdin
+fn input_names(files: &[String]) -> Vec<String> {
+    match files {
+        [] => vec!["-".into()],
+        files => files.to_vec(),
+    }
+}
+
+/// Reads a whole input, stopping as soon as it is larger than `max_size`
+fn read_limited(file: &str, max_size: Option<u64>) -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    open_input(file)?
+        .take(max_size.map_or(u64::MAX, |max_size| max_size + 1))
+        .read_to_end(&mut buffer)
+        .map_err(|e| format!("{file}: {e}"))?;
+    match max_size {
+        Some(max_size) if buffer.len() as u64 > max_size => Err(format!(
+            "{}Input is larger than {max_size} bytes; use --stream or raise --max-size",
+            if file == "-" {
+                String::new()
+            } else {
+                format!("{file}: ")
+            }
+        )),
+        _ => Ok(buffer),
+    }
+}
+
+/// Opens an input file as a reader, or stdin for `-`
+fn open_input(file: &str) -> Result<Box<dyn Read>, String> {
+    if file == "-" {
+        return Ok(Box::new(io::stdin()));
+    }
+    let file = fs::File::open(file).map_err(|e| format!("{file}: {e}"))?;
+    Ok(Box::new(file))
+}
+
+/// A reader that drops ASCII whitespace, such as the newline after encoded output
+struct SkipWhitespace<R>(R);
+
+impl<R: Read> Read for SkipWhitespace<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let n = self.0.read(buf)?;
+            let mut kept = 0;
+            for i in 0..n {
+                if !buf[i].is_ascii_whitespace() {
+                    buf[kept] = buf[i];
+                    kept += 1;
                 }
             }
-            Err(DecodeError::InvalidCharacter(c)) => {
-                eprintln!("Error: Invalid character '{c}' in Base58 input");
-                process::exit(1);
+            // Only a read of nothing at all means the end of the input
+            if kept > 0 || n == 0 || buf.is_empty() {
+                return Ok(kept);
+            }
+        }
+    }
+}
+
+/// Copies `input` to `output` in fixed-size chunks
+fn copy_stream(input: &mut dyn Read, output: &mut dyn Write) -> Result<(), String> {
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = match input.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            // Invalid Base58 is reported by the decoder as invalid data
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => return Err(e.to_string()),
+            Err(e) => return Err(format!("Failed to read input: {e}")),
+        };
+        output
+            .write_all(&buf[..n])
+            .map_err(|e| format!("Failed to write output: {e}"))?;
+    }
+}
+
+/// How often `--progress` updates its line
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A reader that counts the bytes read through it and reports the count on stderr
+struct Progress<R> {
+    inner: R,
+    read: u64,
+    /// Size of the whole input, if known
+    total: Option<u64>,
+    start: Instant,
+    reported: Instant,
+}
+
+impl<R> Progress<R> {
+    fn new(inner: R, total: Option<u64>) -> Self {
+        let now = Instant::now();
+        Progress {
+            inner,
+            read: 0,
+            total,
+            start: now,
+            reported: now,
+        }
+    }
+
+    /// Rewrites the progress line with the amount read and the throughput
+    fn report(&self) {
+        let mib = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+        let elapsed = self.start.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            mib(self.read) / elapsed
+        } else {
+            0.0
+        };
+        let amount = match self.total {
+            Some(total) if total > 0 => format!(
+                "{:.1} of {:.1} MiB ({}%)",
+                mib(self.read),
+                mib(total),
+                self.read * 100 / total
+            ),
+            _ => format!("{:.1} MiB", mib(self.read)),
+        };
+        // Trailing spaces cover the end of a longer previous line
+        eprint!("\r{amount}, {rate:.1} MiB/s    ");
+    }
+
+    /// Reports the final amount and ends the progress line
+    fn finish(&self) {
+        self.report();
+        eprintln!();
+    }
+}
+
+impl<R: Read> Read for Progress<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.read += n as u64;
+        if self.reported.elapsed() >= PROGRESS_INTERVAL {
+            self.report();
+            self.reported = Instant::now();
+        }
+        Ok(n)
+    }
+}
+
+/// Returns the size of an input file, which is unknown for stdin
+fn input_size(file: &str) -> Option<u64> {
+    match file {
+        "-" => None,
+        _ => fs::metadata(file).ok().map(|metadata| metadata.len()),
+    }
+}
+
+/// Encodes or decodes with `--stream`, holding only one buffer of data at a time
+fn run_stream(options: &Options) -> Result<(), String> {
+    let names = input_names(&options.files);
+    let mut out: Box<dyn Write> = match &options.output {
+        _ if options.quiet => Box::new(io::sink()),
+        Some(path) => Box::new(fs::File::create(path).map_err(|e| format!("{path}: {e}"))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    for_each_input(&names, options, |name| {
+        let mut input = open_input(name)?;
+        if options.tag {
+            write!(out, "{name}: ").map_err(|e| format!("Failed to write output: {e}"))?;
+        }
+        if !options.progress || options.quiet {
+            return convert_stream(&mut input, &mut out, options);
+        }
+        let mut progress = Progress::new(input, input_size(name));
+        let result = convert_stream(&mut progress, &mut out, options);
+        progress.finish();
+        result
+    })
+}
+
+/// Encodes or decodes all of `input` to `out` with the block-wise format
+fn convert_stream(
+    input: &mut dyn Read,
+    out: &mut dyn Write,
+    options: &Options,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| format!("Failed to write output: {e}");
+    if options.command == Command::Decode {
+        let mut decoder = Base58DecodeReader::new(SkipWhitespace(input));
+        copy_stream(&mut decoder, out)?;
+        return out.flush().map_err(write_error);
+    }
+    let mut encoder = Base58EncodeWriter::new(out);
+    copy_stream(input, &mut encoder)?;
+    let out = encoder.finish().map_err(write_error)?;
+    if !options.no_newline {
+        out.write_all(b"\n").map_err(write_error)?;
+    }
+    out.flush().map_err(write_error)
+}
+
+/// Runs `convert` on each named input. With several inputs, a failed one is reported
+/// with its name and the rest are still converted; a single input's error is returned
+/// as it is.
+fn for_each_input(
+    names: &[String],
+    options: &Options,
+    mut convert: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(), String> {
+    if let [name] = names {
+        return convert(name);
+    }
+    let mut failed = 0;
+    for name in names {
+        if let Err(e) = convert(name) {
+            if !options.quiet {
+                // Errors opening or reading a file already start with its name
+                match e.starts_with(&format!("{name}: ")) {
+                    true => eprintln!("Error: {e}"),
+                    false => eprintln!("Error: {name}: {e}"),
+                }
+            }
+            failed += 1;
+        }
+    }
+    failures(failed, "file")
+}
+
+/// Turns the number of failed inputs or records into the overall result
+fn failures(failed: usize, noun: &str) -> Result<(), String> {
+    match failed {
+        0 => Ok(()),
+        1 => Err(format!("1 {noun} failed")),
+        n => Err(format!("{n} {noun}s failed")),
+    }
+}
+
+/// Runs the command on each input, or on each record of each input with `--lines` or
+/// `--null`
+fn run(options: &Options) -> Result<(), String> {
+    if options.stream {
+        return run_stream(options);
+    }
+    let names = input_names(&options.files);
+    // A file that cannot be read fails on its turn, like one that does not decode
+    let inputs: Vec<Result<Vec<u8>, String>> = match options.secret {
+        true => vec![read_secret(match options.command {
+            Command::Encode => "Value to encode: ",
+            _ => "Base58 value: ",
+        })],
+        false => names
+            .iter()
+            .map(|name| read_limited(name, options.max_size))
+            .collect(),
+    };
+    // The output file is created only after reading, so it may also be an input
+    let mut out: Box<dyn Write> = match &options.output {
+        _ if options.quiet => Box::new(io::sink()),
+        Some(path) => Box::new(fs::File::create(path).map_err(|e| format!("{path}: {e}"))?),
+        None => Box::new(io::stdout()),
+    };
+
+    let mut inputs = inputs.iter();
+    let result = for_each_input(&names, options, |name| {
+        let input = inputs.next().expect("one input per name").as_ref()?;
+        match options.delimiter {
+            None => run_value(input, name, &mut out, options),
+            Some(delimiter) => {
+                // Record errors name the file when there are several
+                let context = match names.len() {
+                    1 => String::new(),
+                    _ => format!("{name}: "),
+                };
+                run_records(input, delimiter, name, &context, &mut out, options)
+            }
+        }
+    });
+    out.flush()
+        .map_err(|e| format!("Failed to write output: {e}"))?;
+    result
+}
+
+/// Runs the command on the whole of one input
+fn run_value(
+    input: &[u8],
+    name: &str,
+    out: &mut dyn Write,
+    options: &Options,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| format!("Failed to write output: {e}");
+    let tag = options.tag.then_some(name);
+    if options.json {
+        let (object, result) = process_json(input, tag, options);
+        writeln!(out, "{object}").map_err(write_error)?;
+        return result;
+    }
+    let output = process(input, options)?;
+    if let Some(name) = tag {
+        write!(out, "{name}: ").map_err(write_error)?;
+    }
+    out.write_all(&output).map_err(write_error)?;
+    // Raw decoded bytes are written exactly; everything else is a line of text
+    let text = options.command != Command::Decode || options.hex_out || options.base64_out;
+    if text && !options.no_newline {
+        out.write_all(b"\n").map_err(write_error)?;
+    }
+    Ok(())
+}
+
+/// Runs the command on each record of one input. `context` starts error messages.
+fn run_records(
+    input: &[u8],
+    delimiter: u8,
+    name: &str,
+    context: &str,
+    out: &mut dyn Write,
+    options: &Options,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| format!("Failed to write output: {e}");
+    let tag = options.tag.then_some(name);
+
+    // A bad record is reported and skipped, so one typo does not stop a bulk conversion
+    let noun = if delimiter == b'\n' { "line" } else { "record" };
+    let mut failed = 0;
+    for (number, record) in split_records(input, delimiter).enumerate() {
+        // JSON objects carry their own errors and are always one per line
+        if options.json {
+            let (object, result) = process_json(record, tag, options);
+            writeln!(out, "{object}").map_err(write_error)?;
+            if result.is_err() {
+                failed += 1;
+            }
+            continue;
+        }
+        match process(record, options) {
+            Ok(output) => {
+                if let Some(name) = tag {
+                    write!(out, "{name}: ").map_err(write_error)?;
+                }
+                out.write_all(&output).map_err(write_error)?;
+                out.write_all(&[delimiter]).map_err(write_error)?;
+            }
+            Err(e) => {
+                if !options.quiet {
+                    eprintln!("Error: {context}{noun} {}: {e}", number + 1);
+                }
+                failed += 1;
+            }
+        }
+    }
+    failures(failed, noun)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match parse_args(&args, &EnvDefaults::from_env()) {
+        Ok(Parsed::Help) => {
+            print_usage();
+            process::exit(0);
+        }
+        Ok(Parsed::Version) => {
+            println!("base58 {}", env!("CARGO_PKG_VERSION"));
+            process::exit(0);
+        }
+        Ok(Parsed::Usage(usage)) => {
+            eprint!("{usage}");
+            process::exit(0);
+        }
+        Ok(Parsed::Tool(tool)) => match tool.run() {
+            Ok(output) => {
+                if !output.is_empty() {
+                    println!("{output}");
+                }
+                process::exit(0);
             }
             Err(e) => {
                 eprintln!("Error: {e}");
                 process::exit(1);
             }
+        },
+        Ok(Parsed::Run(options)) => options,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            print_usage();
+            process::exit(2);
         }
-    } else {
-        let result = encode_with_alphabet(&input, alphabet);
-        println!("{result}");
+    };
+
+    if let Err(e) = run(&options) {
+        if !options.quiet {
+            eprintln!("Error: {e}");
+        }
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Parsed, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        parse_args(&args, &EnvDefaults::default())
+    }
+
+    fn run_options(command: Command, alphabet: Alphabet) -> Parsed {
+        Parsed::Run(Options {
+            command,
+            alphabet,
+            max_size: Some(DEFAULT_MAX_SIZE),
+            ..Options::default()
+        })
+    }
+
+    #[test]
+    fn test_subcommands_and_bare_flags() {
+        assert_eq!(
+            parse(&[]),
+            Ok(run_options(Command::Encode, Alphabet::Bitcoin))
+        );
+        assert_eq!(
+            parse(&["-d", "-a", "ripple"]),
+            Ok(run_options(Command::Decode, Alphabet::Ripple))
+        );
+        assert_eq!(
+            parse(&["decode", "--alphabet", "flickr"]),
+            Ok(run_options(Command::Decode, Alphabet::Flickr))
+        );
+        assert_eq!(
+            parse(&["inspect"]),
+            Ok(run_options(Command::Inspect, Alphabet::Bitcoin))
+        );
+        assert_eq!(parse(&["help"]), Ok(Parsed::Help));
+        assert_eq!(parse(&["check", "-h"]), Ok(Parsed::Help));
+
+        assert!(parse(&["check", "-d"]).is_err());
+        assert!(parse(&["-a"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_gnu_conventions() {
+        fn split(args: &[&str]) -> Vec<String> {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            split_flags(&args)
+        }
+        assert_eq!(split(&["--alphabet=ripple"]), ["--alphabet", "ripple"]);
+        assert_eq!(split(&["-dn", "-w76"]), ["-d", "-n", "-w", "76"]);
+        assert_eq!(
+            split(&["-da", "flickr", "-l0"]),
+            ["-d", "-a", "flickr", "-l", "-0"]
+        );
+        assert_eq!(
+            split(&["-o", "-dn", "-", "--quiet=1"]),
+            ["-o", "-dn", "-", "--quiet=1"]
+        );
+        assert_eq!(
+            split(&["-q", "--", "-dn", "--x=y"]),
+            ["-q", "--", "-dn", "--x=y"]
+        );
+
+        let Ok(Parsed::Run(options)) = parse(&["decode", "-qn", "--alphabet=ripple", "--", "-d"])
+        else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Decode);
+        assert!(options.quiet && options.no_newline);
+        assert_eq!(options.alphabet, Alphabet::Ripple);
+        assert_eq!(options.files, ["-d"]);
+
+        assert_eq!(parse(&["--version"]), Ok(Parsed::Version));
+        assert_eq!(parse(&["-dh"]), Ok(Parsed::Help));
+        assert!(parse(&["--quiet=1"]).is_err());
+        assert!(parse(&["-dx"]).is_err());
+    }
+
+    #[test]
+    fn test_format_subcommands() {
+        assert!(matches!(
+            parse(&["addr", "validate", "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"]),
+            Ok(Parsed::Tool(Tool::Addr(_)))
+        ));
+        assert_eq!(
+            parse(&["addr", "--help"]),
+            Ok(Parsed::Usage(cli::addr::USAGE))
+        );
+        assert!(parse(&["addr"]).is_err());
+    }
+
+    #[test]
+    fn test_file_arguments() {
+        // Subcommands are only recognized first, so a later "decode" is a file name
+        let Ok(Parsed::Run(options)) = parse(&["-d", "decode", "-", "b.txt"]) else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Decode);
+        assert_eq!(options.files, ["decode", "-", "b.txt"]);
+        assert_eq!(options.output, None);
+
+        let Ok(Parsed::Run(options)) = parse(&["decode", "-o", "out.bin", "in.b58"]) else {
+            panic!("expected options");
+        };
+        assert_eq!(options.output.as_deref(), Some("out.bin"));
+        assert_eq!(options.files, ["in.b58"]);
+        assert!(parse(&["--output"]).is_err());
+
+        let dir = std::env::temp_dir();
+        let first = dir.join(format!("base58-test-{}-a", std::process::id()));
+        let second = dir.join(format!("base58-test-{}-b", std::process::id()));
+        fs::write(&first, b"Hel").unwrap();
+        fs::write(&second, b"lo").unwrap();
+        let files = [&first, &second].map(|path| path.to_string_lossy().into_owned());
+        assert_eq!(read_input(&files).unwrap(), b"Hello");
+        fs::remove_file(&first).unwrap();
+        fs::remove_file(&second).unwrap();
+
+        let missing = read_input(&files[..1]).unwrap_err();
+        assert!(missing.starts_with(&files[0]));
+    }
+
+    #[test]
+    fn test_tag() {
+        let Ok(Parsed::Run(options)) = parse(&["--tag", "a.bin", "b.bin"]) else {
+            panic!("expected options");
+        };
+        assert!(options.tag);
+        assert_eq!(input_names(&options.files), ["a.bin", "b.bin"]);
+        assert_eq!(input_names(&[]), ["-"]);
+
+        let mut out = Vec::new();
+        run_value(b"Hello", "a.bin", &mut out, &options).unwrap();
+        let records = Options {
+            command: Command::Decode,
+            hex_out: true,
+            delimiter: Some(b'\n'),
+            quiet: true,
+            ..options.clone()
+        };
+        let result = run_records(
+            b"9Ajdvzr\nbad0\n1\n",
+            b'\n',
+            "b.txt",
+            "",
+            &mut out,
+            &records,
+        );
+        assert_eq!(result, Err("1 line failed".into()));
+        assert_eq!(out, b"a.bin: 9Ajdvzr\nb.txt: 48656c6c6f\nb.txt: 00\n");
+
+        let json = Options {
+            json: true,
+            ..options
+        };
+        let (object, _) = process_json(b"Hello", Some("a.bin"), &json);
+        assert!(object.starts_with(r#"{"file":"a.bin","input":"Hello","#));
+
+        // Every input is converted even when one fails, and the failures are counted
+        let names = ["a", "b", "c"].map(String::from);
+        let mut seen = Vec::new();
+        let result = for_each_input(&names, &records, |name| {
+            seen.push(name.to_string());
+            if name == "b" {
+                Err("bad".into())
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(result, Err("1 file failed".into()));
+        assert_eq!(seen, names);
+        assert_eq!(
+            for_each_input(&names[..1], &records, |_| Err("bad".into())),
+            Err("bad".into())
+        );
+
+        assert!(parse(&["-d", "--tag"]).is_err());
+        assert!(parse(&["-d", "--tag", "--hex-out"]).is_ok());
+    }
+
+    #[test]
+    fn test_check_mode() {
+        let Ok(Parsed::Run(options)) = parse(&["--version-byte", "0x00"]) else {
+            panic!("expected options");
+        };
+        assert!(options.check);
+        assert_eq!(options.version, Some(0));
+        assert_eq!(parse_byte("111"), Ok(111));
+        assert_eq!(parse_byte("0x6F"), Ok(0x6f));
+        assert!(parse_byte("0x100").is_err());
+        assert!(parse_byte("zero").is_err());
+        assert!(parse_byte("+5").is_err());
+        assert!(parse_byte("0x+5").is_err());
+        assert!(parse_byte("0x").is_err());
+        assert!(parse(&["--version-byte", "+5"]).is_err());
+
+        let hash160 = [
+            0x77, 0xbf, 0xf2, 0x0c, 0x60, 0xe5, 0x22, 0xdf, 0xaa, 0x33, 0x50, 0xc3, 0x9b, 0x03,
+            0x0a, 0x5d, 0x00, 0x4e, 0x83, 0x9a,
+        ];
+        let address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        assert_eq!(encode_value(&hash160, &options), address);
+        assert_eq!(decode_value(address, &options).unwrap(), hash160);
+
+        let unversioned = Options {
+            version: None,
+            ..options.clone()
+        };
+        assert_eq!(decode_value(address, &unversioned).unwrap()[1..], hash160);
+        let testnet = Options {
+            version: Some(0x6f),
+            ..options.clone()
+        };
+        assert_eq!(
+            decode_value(address, &testnet),
+            Err("Version byte is 0x00, expected 0x6f".into())
+        );
+        assert!(decode_value("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", &options).is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(from_hex(b"00ff10\n"), Ok(vec![0x00, 0xff, 0x10]));
+        assert_eq!(
+            from_hex(b"DE AD\r\nbe ef"),
+            Ok(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(from_hex(b""), Ok(vec![]));
+        assert!(from_hex(b"abc").is_err());
+        assert!(from_hex(b"0g").is_err());
+        assert!(from_hex(b"+f").is_err());
+        assert!(from_hex("é0".as_bytes()).is_err());
+        assert_eq!(to_hex(&[0x00, 0xab]), "00ab");
+
+        assert!(parse(&["--hex-in"]).is_ok());
+        assert!(parse(&["-d", "--hex-out"]).is_ok());
+        assert!(parse(&["-d", "--hex-in"]).is_err());
+        assert!(parse(&["encode", "--hex-out"]).is_err());
+    }
+
+    #[test]
+    fn test_base64() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"H", "SA=="),
+            (b"He", "SGU="),
+            (b"Hel", "SGVs"),
+            (b"Hello", "SGVsbG8="),
+            (&[0xfb, 0xff], "+/8="),
+        ] {
+            assert_eq!(to_base64(bytes), encoded);
+            assert_eq!(from_base64(encoded.as_bytes()).unwrap(), bytes);
+        }
+        assert_eq!(from_base64(b"SGVs\nbG8\n"), Ok(b"Hello".to_vec()));
+        assert_eq!(from_base64(b"-_8"), Ok(vec![0xfb, 0xff]));
+        assert!(from_base64(b"SGVsb").is_err());
+        assert!(from_base64(b"SG=s").is_err());
+
+        let Ok(Parsed::Run(options)) = parse(&["--from", "base64"]) else {
+            panic!("expected options");
+        };
+        assert_eq!(process(b"SGVsbG8=\n", &options).unwrap(), b"9Ajdvzr");
+        let Ok(Parsed::Run(options)) = parse(&["-d", "--to", "base64"]) else {
+            panic!("expected options");
+        };
+        assert_eq!(process(b"9Ajdvzr", &options).unwrap(), b"SGVsbG8=");
+
+        // Transcoding from or to Base64 is encoding or decoding
+        let Ok(Parsed::Run(options)) = parse(&["transcode", "--from", "base64", "--to", "ripple"])
+        else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Encode);
+        assert_eq!(options.alphabet, Alphabet::Ripple);
+        let Ok(Parsed::Run(options)) = parse(&["transcode", "--from", "ripple", "--to", "base64"])
+        else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Decode);
+        assert!(options.base64_out);
+
+        assert!(parse(&["-d", "--from", "base64"]).is_err());
+        assert!(parse(&["--to", "base64"]).is_err());
+        assert!(parse(&["--from", "base64", "--hex-in"]).is_err());
+        assert!(parse(&["transcode", "--from", "base64", "--to", "base64"]).is_err());
+    }
+
+    #[test]
+    fn test_records() {
+        fn lines(input: &[u8]) -> Vec<&[u8]> {
+            split_records(input, b'\n').collect()
+        }
+        assert_eq!(lines(b"a\nb\r\n\nc"), [&b"a"[..], b"b", b"", b"c"]);
+        assert_eq!(lines(b"a\n"), [b"a"]);
+        assert_eq!(lines(b"\n"), [b""]);
+        assert!(lines(b"").is_empty());
+
+        let records: Vec<&[u8]> = split_records(b"a\nb\r\0c\0", 0).collect();
+        assert_eq!(records, [&b"a\nb\r"[..], b"c"]);
+        assert_eq!(
+            parse(&["-d", "-0"]).map(|parsed| match parsed {
+                Parsed::Run(options) => options.delimiter,
+                _ => None,
+            }),
+            Ok(Some(0))
+        );
+
+        let options = Options {
+            delimiter: Some(b'\n'),
+            ..Options::default()
+        };
+        assert_eq!(process(b"Hello", &options).unwrap(), b"9Ajdvzr");
+        let options = Options {
+            command: Command::Decode,
+            ..options
+        };
+        assert_eq!(process(b" 9Ajdvzr ", &options).unwrap(), b"Hello");
+        assert!(process(b"9Ajdvzr0", &options).is_err());
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("abcdefg".into(), 3), "abc\ndef\ng");
+        assert_eq!(wrap("abcdef".into(), 3), "abc\ndef");
+        assert_eq!(wrap("abcdef".into(), 0), "abcdef");
+        assert_eq!(wrap(String::new(), 3), "");
+        let input = base58_input(b" 9Aj\r\ndv\nzr\n", &Options::default());
+        assert_eq!(input.unwrap(), "9Ajdvzr");
+        assert!(parse(&["--wrap", "x"]).is_err());
+        assert!(parse(&["--wrap", "10", "--lines"]).is_err());
+        assert!(parse(&["-n", "-w", "10"]).is_ok());
+        assert!(parse(&["-n", "-0"]).is_err());
+
+        let options = Options {
+            wrap: 4,
+            ..Options::default()
+        };
+        let wrapped = process(b"Hello World", &options).unwrap();
+        assert_eq!(wrapped, b"JxF1\n2Trw\nUP45\nBMd");
+        let options = Options {
+            command: Command::Decode,
+            ..Options::default()
+        };
+        assert_eq!(process(&wrapped, &options).unwrap(), b"Hello World");
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        const REVERSED: &str = "zyxwvutsrqponmkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA987654321";
+        let alphabet = parse_alphabet(&format!("custom:{REVERSED}")).unwrap();
+        assert_eq!(alphabet.as_bytes(), REVERSED.as_bytes());
+        assert_eq!(parse_alphabet("Ripple"), Ok(Alphabet::Ripple));
+
+        let path =
+            std::env::temp_dir().join(format!("base58-test-{}-alphabet", std::process::id()));
+        fs::write(&path, format!("{REVERSED}\n")).unwrap();
+        let from_file = parse_alphabet(&format!("@{}", path.display())).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(from_file.as_bytes(), REVERSED.as_bytes());
+
+        assert_eq!(
+            parse_alphabet("custom:abc"),
+            Err("Custom alphabet must have 58 characters, found 3".into())
+        );
+        assert!(parse_alphabet(&format!("custom:{}", "a".repeat(58))).is_err());
+        assert!(parse_alphabet("@/nonexistent/alphabet").is_err());
+        assert!(parse_alphabet("base64").is_err());
+    }
+
+    #[test]
+    fn test_detect_alphabet() {
+        let account = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+        assert_eq!(detect_alphabet(account, true), Some(Alphabet::Ripple));
+        assert_eq!(detect_alphabet(account, false), Some(Alphabet::Bitcoin));
+        assert_eq!(detect_alphabet("0", false), None);
+
+        let Ok(Parsed::Run(options)) = parse(&["check", "-a", "auto"]) else {
+            panic!("expected options");
+        };
+        assert!(options.detect_alphabet);
+        assert_eq!(process(account.as_bytes(), &options).unwrap(), b"OK");
+        assert_eq!(
+            process(b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", &options),
+            Err("No built-in alphabet decodes the input with a valid checksum (tried bitcoin, ripple and flickr)".into())
+        );
+        assert!(parse(&["-a", "auto"]).is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let Ok(Parsed::Run(options)) = parse(&["--validate", "--quiet", "--check"]) else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Validate);
+        assert!(options.quiet);
+        assert_eq!(
+            process(b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", &options).unwrap(),
+            b"OK"
+        );
+        assert!(process(b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", &options).is_err());
+
+        assert_eq!(
+            parse(&["decode", "--validate"]),
+            Ok(run_options(Command::Validate, Alphabet::Bitcoin))
+        );
+        assert!(parse(&["inspect", "--validate"]).is_err());
+    }
+
+    #[test]
+    fn test_env_defaults() {
+        let parse_with = |args: &[&str], alphabet: Option<&str>, format: Option<&str>| {
+            let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+            let defaults = EnvDefaults {
+                alphabet: alphabet.map(String::from),
+                format: format.map(String::from),
+            };
+            match parse_args(&args, &defaults) {
+                Ok(Parsed::Run(options)) => Ok(options),
+                Ok(parsed) => panic!("unexpected {parsed:?}"),
+                Err(e) => Err(e),
+            }
+        };
+
+        let options = parse_with(&[], Some("ripple"), Some("check")).unwrap();
+        assert_eq!(options.alphabet, Alphabet::Ripple);
+        assert!(options.check);
+
+        // Flags win over the environment
+        let options = parse_with(
+            &["-a", "flickr", "--format", "plain"],
+            Some("ripple"),
+            Some("check"),
+        );
+        let options = options.unwrap();
+        assert_eq!(options.alphabet, Alphabet::Flickr);
+        assert!(!options.check);
+        let options = parse_with(&["--version-byte", "0"], None, Some("plain")).unwrap();
+        assert!(options.check);
+        let options = parse_with(&["-c"], None, Some("PLAIN")).unwrap();
+        assert!(options.check);
+
+        // A default of auto is ignored when encoding
+        let options = parse_with(&[], Some("auto"), None).unwrap();
+        assert!(!options.detect_alphabet);
+        let options = parse_with(&["-d"], Some("auto"), None).unwrap();
+        assert!(options.detect_alphabet);
+
+        let err = parse_with(&[], Some("base64"), None).unwrap_err();
+        assert!(err.starts_with("BASE58_ALPHABET: "), "{err}");
+        let err = parse_with(&[], None, Some("hex")).unwrap_err();
+        assert!(err.starts_with("BASE58_FORMAT: "), "{err}");
+        assert!(parse(&["--format", "plain", "--version-byte", "0"]).is_err());
+        assert!(parse(&["--format=check"]).is_ok());
+    }
+
+    #[test]
+    fn test_max_size() {
+        assert_eq!(parse_size("100"), Ok(100));
+        assert_eq!(parse_size("4k"), Ok(4096));
+        assert_eq!(parse_size("64MiB"), Ok(DEFAULT_MAX_SIZE));
+        assert_eq!(parse_size("2G"), Ok(2 << 30));
+        for invalid in ["", "M", "1.5M", "10T", "-1", "99999999999G"] {
+            assert!(parse_size(invalid).is_err(), "{invalid}");
+        }
+
+        let max_size = |args: &[&str]| match parse(args) {
+            Ok(Parsed::Run(options)) => options.max_size,
+            other => panic!("unexpected {other:?}"),
+        };
+        assert_eq!(max_size(&[]), Some(DEFAULT_MAX_SIZE));
+        assert_eq!(max_size(&["--max-size=1K"]), Some(1024));
+        assert_eq!(max_size(&["--max-size", "0"]), None);
+        assert_eq!(max_size(&["--stream"]), None);
+        assert!(parse(&["--stream", "--max-size", "1M"]).is_err());
+
+        let path = std::env::temp_dir().join(format!("base58-test-{}-max", std::process::id()));
+        fs::write(&path, [7u8; 100]).unwrap();
+        let file = path.to_str().unwrap();
+        assert_eq!(read_limited(file, Some(100)).unwrap().len(), 100);
+        assert_eq!(read_limited(file, None).unwrap().len(), 100);
+        let err = read_limited(file, Some(99)).unwrap_err();
+        assert!(err.starts_with(file) && err.contains("99 bytes"), "{err}");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_secret() {
+        let Ok(Parsed::Run(options)) = parse(&["--hex-in", "--secret"]) else {
+            panic!("expected options");
+        };
+        assert!(options.secret);
+        assert!(parse(&["-d", "--secret", "key.b58"]).is_err());
+        assert!(parse(&["--secret", "--stream"]).is_err());
+        assert!(parse(&["--secret", "--lines"]).is_err());
+    }
+
+    #[test]
+    fn test_ignore_garbage() {
+        let Ok(Parsed::Run(options)) = parse(&["-d", "-i"]) else {
+            panic!("expected options");
+        };
+        assert!(options.ignore_garbage);
+        assert_eq!(process(b"[9Aj-dv_zr]\xff\n", &options).unwrap(), b"Hello");
+        // Characters that look like Base58 but are not in the alphabet are dropped too
+        assert_eq!(process(b"9Ajd0vzOr", &options).unwrap(), b"Hello");
+
+        // Strict decoding stays the default
+        let options = Options {
+            ignore_garbage: false,
+            ..options
+        };
+        assert!(process(b"[9Ajdvzr]", &options).is_err());
+
+        let Ok(Parsed::Run(options)) = parse(&["check", "-i", "--alphabet", "auto", "-q"]) else {
+            panic!("expected options");
+        };
+        let address = b"\"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh\",";
+        assert_eq!(process(address, &options).unwrap(), b"OK");
+
+        assert!(parse(&["--ignore-garbage"]).is_err());
+        assert!(parse(&["-d", "--stream", "-i"]).is_err());
+    }
+
+    #[test]
+    fn test_caret_diagnostic() {
+        let options = Options {
+            command: Command::Decode,
+            ..Options::default()
+        };
+        assert_eq!(
+            decode_value("9Ajd0zr", &options).unwrap_err(),
+            "Invalid character '0' at position 4 in Base58 input\n  9Ajd0zr\n      ^"
+        );
+
+        // Long input is cut down around the character, with the caret still under it
+        let input = format!("{}l{}", "2".repeat(100), "3".repeat(100));
+        let diagnostic = caret_diagnostic(&input, 100);
+        let (excerpt, caret) = diagnostic.split_once('\n').unwrap();
+        assert!(excerpt.starts_with("  ...2") && excerpt.ends_with("3..."));
+        assert_eq!(excerpt.len(), 2 + 3 + DIAGNOSTIC_WIDTH + 3);
+        assert_eq!(excerpt.find('l'), Some(caret.len() - 1));
+
+        // Columns count characters rather than bytes
+        assert_eq!(caret_diagnostic("ééx", 4), "  ééx\n    ^");
+        assert_eq!(caret_diagnostic("abc", 0), "  abc\n  ^");
+    }
+
+    #[test]
+    fn test_transcode() {
+        let Ok(Parsed::Run(options)) = parse(&["transcode", "--from", "ripple", "--to", "bitcoin"])
+        else {
+            panic!("expected options");
+        };
+        assert_eq!(options.command, Command::Transcode);
+        assert_eq!(options.alphabet, Alphabet::Ripple);
+        assert_eq!(options.target, Alphabet::Bitcoin);
+        assert_eq!(
+            process(b"fpkrxXWGn9CYdzS4ow\n", &options).unwrap(),
+            b"72k1xXWG59fYdzSNoA"
+        );
+        assert!(process(b"72k1xXWG59fYdzSNoA0", &options).is_err());
+
+        // With --check, the checksum is verified and recomputed for the new alphabet
+        let options = Options {
+            check: true,
+            ..options
+        };
+        assert_eq!(
+            process(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", &options).unwrap(),
+            b"1Hb9fJwWyBN1j9sVRW59hDkukGNbAdtyT4"
+        );
+
+        assert!(parse(&["transcode", "--from", "ripple"]).is_err());
+        assert!(parse(&["--to", "ripple"]).is_err());
+        assert!(parse(&["decode", "--from", "ripple"]).is_err());
+        assert!(parse(&["transcode", "--from", "auto", "--to", "flickr"]).is_ok());
+    }
+
+    #[test]
+    fn test_stream() {
+        let Ok(Parsed::Run(options)) = parse(&["-d", "--stream"]) else {
+            panic!("expected options");
+        };
+        assert!(options.stream);
+        assert!(parse(&["--stream", "--check"]).is_err());
+        assert!(parse(&["--stream", "-a", "ripple"]).is_err());
+        assert!(parse(&["--stream", "--lines"]).is_err());
+        assert!(parse(&["inspect", "--stream"]).is_err());
+
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+        let mut encoded = Vec::new();
+        let mut encoder = Base58EncodeWriter::new(&mut encoded);
+        copy_stream(&mut &data[..], &mut encoder).unwrap();
+        encoder.finish().unwrap();
+        assert_eq!(encoded, b58::monero::encode(&data).as_bytes());
+
+        // Line breaks, such as the final newline, are skipped when decoding
+        encoded.splice(500..500, *b"\r\n");
+        encoded.push(b'\n');
+        let mut decoded = Vec::new();
+        let mut decoder = Base58DecodeReader::new(SkipWhitespace(&encoded[..]));
+        copy_stream(&mut decoder, &mut decoded).unwrap();
+        assert_eq!(decoded, data);
+
+        let mut decoder = Base58DecodeReader::new(SkipWhitespace(&b"1An6Ueb0"[..]));
+        assert!(copy_stream(&mut decoder, &mut Vec::new()).is_err());
+
+        // Progress counts the bytes read, which the total covers only for files
+        let mut progress = Progress::new(&data[..], None);
+        copy_stream(&mut progress, &mut io::sink()).unwrap();
+        assert_eq!(progress.read, data.len() as u64);
+        assert_eq!(input_size("-"), None);
+        assert!(parse(&["--progress"]).is_err());
+        assert!(parse(&["--stream", "--progress"]).is_ok());
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}é"), r#""a\"b\\c\nd\u0001é""#);
+
+        // Raw bytes are not text and would be mangled into a JSON string
+        assert!(parse(&["-d", "--json"]).is_err());
+        assert!(parse(&["-d", "--json", "--to", "base64"]).is_ok());
+        let Ok(Parsed::Run(options)) = parse(&["-d", "--lines", "--json", "--hex-out"]) else {
+            panic!("expected options");
+        };
+        assert!(options.json);
+        assert_eq!(
+            process_json(b" 9Ajdvzr ", None, &options),
+            (
+                r#"{"input":"9Ajdvzr","output":"48656c6c6f","alphabet":"bitcoin","valid":true}"#
+                    .into(),
+                Ok(())
+            )
+        );
+        let (object, result) = process_json(b"9Ajdvzr0", None, &options);
+        assert_eq!(
+            object,
+            r#"{"input":"9Ajdvzr0","output":null,"alphabet":"bitcoin","valid":false,"error":"Invalid character '0' at position 7 in Base58 input"}"#
+        );
+        assert!(result.is_err());
+
+        let options = Options {
+            json: true,
+            quiet: true,
+            ..Options::default()
+        };
+        assert_eq!(
+            process_json(b"Hello\n", None, &options).0,
+            r#"{"input":"Hello\n","output":"d3yC1LKT","alphabet":"bitcoin","valid":true}"#
+        );
+        let options = Options {
+            command: Command::Check,
+            detect_alphabet: true,
+            ..options
+        };
+        assert!(
+            process_json(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", None, &options)
+                .0
+                .contains(r#""alphabet":"ripple","valid":true"#)
+        );
+        assert!(
+            process_json(b"0", None, &options)
+                .0
+                .contains(r#""alphabet":null,"valid":false"#)
+        );
+        assert!(parse(&["--json", "-n"]).is_err());
+    }
+
+    #[test]
+    fn test_inspect() {
+        let report = inspect("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Alphabet::Bitcoin).unwrap();
+        assert!(report.contains("leading zeros: 1\n"));
+        assert!(report.contains("bytes:         25\n"));
+        assert!(report.contains("valid (version 0x00, 20-byte payload)"));
+
+        let report = inspect("9Ajdvzr", Alphabet::Bitcoin).unwrap();
+        assert!(report.contains("hex:           48656c6c6f\n"));
+        assert!(report.ends_with("base58check:   no"));
+        assert!(inspect("0OIl", Alphabet::Bitcoin).is_err());
     }
 }
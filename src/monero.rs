@@ -0,0 +1,313 @@
+//! Monero's block-wise Base58 variant.
+//!
+//! Instead of treating the whole input as one big integer, Monero splits it into 8-byte
+//! blocks and encodes each block independently into exactly 11 characters, padding with
+//! the zero digit. A final partial block of `n` bytes uses a fixed, shorter width. The
+//! output length therefore depends only on the input length, and each block can be
+//! processed without looking at the rest of the input.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::monero;
+//!
+//! let encoded = monero::encode(&[0u8; 8]);
+//! assert_eq!(encoded, "11111111111");
+//! assert_eq!(monero::decode(&encoded).unwrap(), [0u8; 8]);
+//! ```
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Alphabet, DecodeError};
+
+/// Number of bytes in a full block.
+pub const BLOCK_SIZE: usize = 8;
+/// Number of characters in an encoded full block.
+pub const ENCODED_BLOCK_SIZE: usize = 11;
+
+/// Number of blocks each rayon task encodes
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_BLOCKS: usize = 8192;
+
+/// Encoded width of a block of `n` bytes, indexed by `n`.
+const ENCODED_BLOCK_SIZES: [usize; BLOCK_SIZE + 1] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+/// Error type for Monero Base58 decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneroError {
+    /// A character is not in the alphabet, or a block's value does not fit in its size.
+    Decode(DecodeError),
+    /// The input length does not correspond to any number of bytes.
+    InvalidLength(usize),
+}
+
+impl core::fmt::Display for MoneroError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MoneroError::Decode(e) => write!(f, "Invalid Monero Base58: {e}"),
+            MoneroError::InvalidLength(len) => {
+                write!(f, "Invalid Monero Base58 length: {len} characters")
+            }
+        }
+    }
+}
+
+impl core::error::Error for MoneroError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MoneroError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for MoneroError {
+    fn from(e: DecodeError) -> Self {
+        MoneroError::Decode(e)
+    }
+}
+
+/// Returns the exact encoded length of `input_len` bytes.
+///
+/// # Examples
+///
+/// ```
+/// // A standard 69-byte Monero address is always 95 characters
+/// assert_eq!(b58::monero::encoded_len(69), 95);
+/// ```
+pub const fn encoded_len(input_len: usize) -> usize {
+    (input_len / BLOCK_SIZE) * ENCODED_BLOCK_SIZE + ENCODED_BLOCK_SIZES[input_len % BLOCK_SIZE]
+}
+
+/// Encodes a byte slice using Monero's block-wise Base58 and the Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The byte slice to encode
+///
+/// # Returns
+///
+/// A string of exactly [`encoded_len`] characters
+pub fn encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(encoded_len(input.len()));
+    let mut digits = [0u8; ENCODED_BLOCK_SIZE];
+
+    for block in input.chunks(BLOCK_SIZE) {
+        let encoded = encode_block(block, &mut digits);
+        out.extend(encoded.iter().map(|&c| c as char));
+    }
+
+    out
+}
+
+/// Encodes a byte slice like [`encode`], spreading the blocks over the rayon thread pool.
+///
+/// Every block is encoded independently into a known position of the output, so large
+/// inputs split into runs of blocks that are encoded concurrently, straight into one
+/// preallocated buffer. The output is identical to [`encode`]; for short inputs the
+/// sequential version is faster.
+///
+/// # Arguments
+///
+/// * `input` - The byte slice to encode
+///
+/// # Returns
+///
+/// A string of exactly [`encoded_len`] characters
+///
+/// # Examples
+///
+/// ```
+/// use b58::monero;
+///
+/// let data = vec![0xA5u8; 1 << 20];
+/// assert_eq!(monero::par_encode(&data), monero::encode(&data));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_encode(input: &[u8]) -> String {
+    let mut out = vec![0u8; encoded_len(input.len())];
+    let whole = input.len() / BLOCK_SIZE * BLOCK_SIZE;
+    let (blocks, tail) = input.split_at(whole);
+    let (encoded_blocks, encoded_tail) = out.split_at_mut(whole / BLOCK_SIZE * ENCODED_BLOCK_SIZE);
+
+    blocks
+        .par_chunks(PAR_CHUNK_BLOCKS * BLOCK_SIZE)
+        .zip(encoded_blocks.par_chunks_mut(PAR_CHUNK_BLOCKS * ENCODED_BLOCK_SIZE))
+        .for_each(|(blocks, encoded)| {
+            let mut digits = [0u8; ENCODED_BLOCK_SIZE];
+            for (block, encoded) in blocks
+                .chunks_exact(BLOCK_SIZE)
+                .zip(encoded.chunks_exact_mut(ENCODED_BLOCK_SIZE))
+            {
+                encoded.copy_from_slice(encode_block(block, &mut digits));
+            }
+        });
+    let mut digits = [0u8; ENCODED_BLOCK_SIZE];
+    encoded_tail.copy_from_slice(encode_block(tail, &mut digits));
+
+    String::from_utf8(out).expect("Base58 output is ASCII")
+}
+
+/// Decodes a Monero block-wise Base58 string using the Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The string to decode
+///
+/// # Returns
+///
+/// The decoded bytes, or a `MoneroError` if the length is impossible, a character is
+/// invalid, or a block encodes a value too large for its size
+pub fn decode(input: &str) -> Result<Vec<u8>, MoneroError> {
+    // Every alphabet character is ASCII, so this also makes byte offsets character offsets
+    if let Some((position, character)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(DecodeError::InvalidCharacter {
+            character,
+            position,
+        }
+        .into());
+    }
+
+    let bytes = input.as_bytes();
+    let tail_len = decoded_block_len(bytes.len() % ENCODED_BLOCK_SIZE)
+        .ok_or(MoneroError::InvalidLength(bytes.len()))?;
+    let mut out = Vec::with_capacity(bytes.len() / ENCODED_BLOCK_SIZE * BLOCK_SIZE + tail_len);
+
+    for (block_index, block) in bytes.chunks(ENCODED_BLOCK_SIZE).enumerate() {
+        let size = if block.len() == ENCODED_BLOCK_SIZE {
+            BLOCK_SIZE
+        } else {
+            tail_len
+        };
+        let value = decode_block(block, size, block_index * ENCODED_BLOCK_SIZE)?;
+        out.extend_from_slice(&value[BLOCK_SIZE - size..]);
+    }
+
+    Ok(out)
+}
+
+/// Returns the number of bytes in a block encoded as `width` characters, if any
+pub(crate) fn decoded_block_len(width: usize) -> Option<usize> {
+    ENCODED_BLOCK_SIZES.iter().position(|&w| w == width)
+}
+
+/// Encodes a block of at most `BLOCK_SIZE` bytes into `digits`, returning the used part
+pub(crate) fn encode_block<'a>(block: &[u8], digits: &'a mut [u8; ENCODED_BLOCK_SIZE]) -> &'a [u8] {
+    let alphabet = Alphabet::Bitcoin.as_bytes();
+    let mut value = block.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64);
+    let width = ENCODED_BLOCK_SIZES[block.len()];
+    for digit in digits[..width].iter_mut().rev() {
+        *digit = alphabet[(value % 58) as usize];
+        value /= 58;
+    }
+    &digits[..width]
+}
+
+/// Decodes an encoded block of `size` bytes that starts at character `offset` of the
+/// input, returning the value as big-endian bytes
+pub(crate) fn decode_block(
+    block: &[u8],
+    size: usize,
+    offset: usize,
+) -> Result<[u8; BLOCK_SIZE], DecodeError> {
+    let table = Alphabet::Bitcoin.decode_table();
+    let mut value: u64 = 0;
+    for (i, &c) in block.iter().enumerate() {
+        let digit = table[c as usize];
+        if digit == 255 {
+            return Err(DecodeError::InvalidCharacter {
+                character: c as char,
+                position: offset + i,
+            });
+        }
+        value = value
+            .checked_mul(58)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or(DecodeError::Overflow)?;
+    }
+
+    if size < BLOCK_SIZE && value >> (size * 8) != 0 {
+        return Err(DecodeError::Overflow);
+    }
+    Ok(value.to_be_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_hex;
+
+    #[test]
+    fn test_known_vectors() {
+        let vectors = [
+            ("", ""),
+            ("00", "11"),
+            ("39", "1z"),
+            ("ff", "5Q"),
+            ("0000000000000000", "11111111111"),
+            ("ffffffffffffffff", "jpXCZedGfVQ"),
+            ("06156013762879f7ffffffffff", "22222222222VtB5VXc"),
+            ("0102030405060708090a", "1An6UebxCZd1gu"),
+        ];
+        for (hex, encoded) in vectors {
+            let bytes = from_hex(hex);
+            assert_eq!(encode(&bytes), encoded, "encoding {hex}");
+            assert_eq!(decode(encoded).unwrap(), bytes, "decoding {encoded}");
+        }
+    }
+
+    #[test]
+    fn test_encoded_len_is_exact() {
+        for len in 0..40 {
+            let input: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(97)).collect();
+            let encoded = encode(&input);
+            assert_eq!(encoded.len(), encoded_len(len));
+            assert_eq!(decode(&encoded).unwrap(), input);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_encode_matches_encode() {
+        let chunk = PAR_CHUNK_BLOCKS * BLOCK_SIZE;
+        for len in [0, 1, 7, 8, 13, chunk - 3, chunk, 3 * chunk + 5] {
+            let input: Vec<u8> = (0..len).map(|i| (i * 31 + len) as u8).collect();
+            assert_eq!(par_encode(&input), encode(&input), "len {len}");
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // No byte count encodes to 1, 4 or 8 characters in the final block
+        assert_eq!(decode("1"), Err(MoneroError::InvalidLength(1)));
+        assert_eq!(
+            decode("111111111111111"),
+            Err(MoneroError::InvalidLength(15))
+        );
+        assert_eq!(
+            decode("1O"),
+            Err(MoneroError::Decode(DecodeError::InvalidCharacter {
+                character: 'O',
+                position: 1
+            }))
+        );
+        assert_eq!(
+            decode("1é"),
+            Err(MoneroError::Decode(DecodeError::InvalidCharacter {
+                character: 'é',
+                position: 1
+            }))
+        );
+        // "5R" is 256, which does not fit in one byte
+        assert_eq!(
+            decode("5R"),
+            Err(MoneroError::Decode(DecodeError::Overflow))
+        );
+        // Larger than u64::MAX
+        assert_eq!(
+            decode("zzzzzzzzzzz"),
+            Err(MoneroError::Decode(DecodeError::Overflow))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Fixed-width encoding whose string order matches the numeric order of the input.
+
+use crate::{Alphabet, DecodeError, decode_with_alphabet, encode_with_alphabet};
+
+/// Returns the fixed width, in characters, of an encoded value of `input_len` bytes.
+///
+/// This is the number of Base58 digits needed for the largest `input_len`-byte value,
+/// `⌈input_len × log(256) / log(58)⌉`, computed in 64-bit fixed point. The ratio is
+/// irrational, so rounding could only matter when a product falls within 2⁻⁶⁴ ×
+/// `input_len` of an integer; the tests check that this never happens below 2²⁴ bytes.
+///
+/// # Examples
+///
+/// ```
+/// use b58::fixed_width_len;
+///
+/// assert_eq!(fixed_width_len(8), 11);
+/// assert_eq!(fixed_width_len(32), 44);
+/// ```
+pub const fn fixed_width_len(input_len: usize) -> usize {
+    input_len + (input_len as u128 * WIDTH_FRACTION).div_ceil(1 << 64) as usize
+}
+
+/// `⌊(log(256) / log(58) − 1) × 2⁶⁴⌋`, where log(256) / log(58) = 1.36565823730976...
+const WIDTH_FRACTION: u128 = 0x5d9b_c73a_c228_8342;
+
+/// Encodes bytes as fixed-width Base58 using the default Bitcoin alphabet.
+///
+/// The input is treated as a big-endian number and padded on the left with the zero
+/// character to [`fixed_width_len`] characters. For inputs of the same length,
+/// comparing the encoded strings byte by byte gives the same order as comparing the
+/// inputs, which makes the output suitable as a key in ordered key-value stores.
+///
+/// The output differs from [`encode`](crate::encode) and must be decoded with
+/// [`decode_fixed_width`].
+///
+/// # Arguments
+///
+/// * `input` - The bytes to encode
+///
+/// # Returns
+///
+/// A string of exactly `fixed_width_len(input.len())` characters
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_fixed_width, encode_fixed_width};
+///
+/// let a = encode_fixed_width(&[0x00, 0xFF]);
+/// let b = encode_fixed_width(&[0x01, 0x00]);
+/// assert_eq!((a.as_str(), b.as_str()), ("15Q", "15R"));
+/// assert!(a < b);
+/// assert_eq!(decode_fixed_width(&a, 2).unwrap(), [0x00, 0xFF]);
+/// ```
+pub fn encode_fixed_width(input: &[u8]) -> String {
+    encode_fixed_width_with_alphabet(input, Alphabet::Bitcoin)
+}
+
+/// Encodes bytes as fixed-width Base58 using the specified alphabet.
+///
+/// String order only matches numeric order if the alphabet's characters are in
+/// ascending ASCII order, as they are for the Bitcoin alphabet but not for the Ripple
+/// or Flickr alphabets.
+///
+/// # Arguments
+///
+/// * `input` - The bytes to encode
+/// * `alphabet` - The alphabet to use for encoding
+///
+/// # Returns
+///
+/// A string of exactly `fixed_width_len(input.len())` characters
+pub fn encode_fixed_width_with_alphabet(input: &[u8], alphabet: Alphabet) -> String {
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+    let digits = encode_with_alphabet(&input[leading_zeros..], alphabet);
+    let width = fixed_width_len(input.len());
+
+    let mut out = String::with_capacity(width);
+    let zero_char = alphabet.as_bytes()[0] as char;
+    out.extend(core::iter::repeat_n(zero_char, width - digits.len()));
+    out.push_str(&digits);
+    out
+}
+
+/// Decodes a fixed-width Base58 string using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The string produced by [`encode_fixed_width`]
+/// * `output_len` - The number of bytes that was encoded
+///
+/// # Returns
+///
+/// The decoded bytes, `DecodeError::InvalidLength` if the input is not
+/// `fixed_width_len(output_len)` characters long, or `DecodeError::Overflow` if the
+/// value does not fit in `output_len` bytes
+pub fn decode_fixed_width(input: &str, output_len: usize) -> Result<Vec<u8>, DecodeError> {
+    decode_fixed_width_with_alphabet(input, output_len, Alphabet::Bitcoin)
+}
+
+/// Decodes a fixed-width Base58 string using the specified alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The string produced by [`encode_fixed_width_with_alphabet`]
+/// * `output_len` - The number of bytes that was encoded
+/// * `alphabet` - The alphabet to use for decoding
+///
+/// # Returns
+///
+/// The decoded bytes, or a `DecodeError` as described for [`decode_fixed_width`]
+pub fn decode_fixed_width_with_alphabet(
+    input: &str,
+    output_len: usize,
+    alphabet: Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let width = fixed_width_len(output_len);
+    if input.len() != width {
+        return Err(DecodeError::InvalidLength {
+            expected: width,
+            actual: input.len(),
+        });
+    }
+
+    // Padding characters are not zero bytes here, so decode only the digits after them
+    let zero_char = alphabet.as_bytes()[0];
+    let padding = input.bytes().take_while(|&b| b == zero_char).count();
+    let value = decode_with_alphabet(&input[padding..], alphabet).map_err(|e| match e {
+        DecodeError::InvalidCharacter {
+            character,
+            position,
+        } => DecodeError::InvalidCharacter {
+            character,
+            position: padding + position,
+        },
+        e => e,
+    })?;
+    if value.len() > output_len {
+        return Err(DecodeError::Overflow);
+    }
+
+    let mut out = vec![0u8; output_len - value.len()];
+    out.extend_from_slice(&value);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_is_exact() {
+        for len in 0..300 {
+            let max = crate::encode(vec![0xFF; len]);
+            assert_eq!(fixed_width_len(len), max.len(), "input length {len}");
+        }
+    }
+
+    #[test]
+    fn test_width_rounding() {
+        // The true fraction lies between WIDTH_FRACTION and WIDTH_FRACTION + 1, so the
+        // width is exact wherever both bounds round up to the same digit count
+        for len in 0..1u128 << 24 {
+            let low = (len * WIDTH_FRACTION).div_ceil(1 << 64);
+            let high = (len * (WIDTH_FRACTION + 1)).div_ceil(1 << 64);
+            assert_eq!(low, high, "input length {len}");
+        }
+
+        // A 9-digit approximation of the ratio gave one digit too few from here on
+        assert_eq!(fixed_width_len(49_549), 67_668);
+        let encoded = encode_fixed_width(&[0xFF; 49_549]);
+        assert_eq!(encoded.len(), 67_668);
+        assert!(!encoded.starts_with('1'));
+    }
+
+    #[test]
+    fn test_order_preserved() {
+        let mut inputs: Vec<[u8; 3]> = (0..2000u32)
+            .map(|i| {
+                let v = i.wrapping_mul(2_654_435_761) >> 8;
+                [(v >> 16) as u8, (v >> 8) as u8, v as u8]
+            })
+            .collect();
+        inputs.extend([[0, 0, 0], [0, 0, 1], [0xFF, 0xFF, 0xFF]]);
+
+        let mut encoded: Vec<String> = inputs.iter().map(|i| encode_fixed_width(i)).collect();
+        inputs.sort();
+        encoded.sort();
+        for (input, encoded) in inputs.iter().zip(&encoded) {
+            assert_eq!(encoded.len(), fixed_width_len(3));
+            assert_eq!(decode_fixed_width(encoded, 3).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_fixed_width("15Q", 3),
+            Err(DecodeError::InvalidLength {
+                expected: 5,
+                actual: 3
+            })
+        );
+        // "zz" encodes 3363, which needs two bytes
+        assert_eq!(decode_fixed_width("zz", 1), Err(DecodeError::Overflow));
+        assert_eq!(
+            decode_fixed_width("10R", 2),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 1
+            })
+        );
+        assert_eq!(decode_fixed_width("", 0).unwrap(), b"");
+    }
+}
@@ -0,0 +1,213 @@
+//! Serde helpers for byte fields, for use with `#[serde(with = "...")]`.
+//!
+//! In human-readable formats such as JSON the bytes are written as a Base58 string. In
+//! binary formats they are written as raw bytes, since Base58 would only make them
+//! larger. Fields may be `Vec<u8>`, `[u8; N]`, or any type that implements
+//! `AsRef<[u8]>` and `TryFrom<Vec<u8>>`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "b58::serde::as_base58")]
+//!     pubkey: [u8; 4],
+//!     #[serde(with = "b58::serde::as_base58_check")]
+//!     payload: Vec<u8>,
+//! }
+//!
+//! let account = Account { pubkey: [0, 0, 0, 1], payload: b"Hello".to_vec() };
+//! let json = serde_json::to_string(&account).unwrap();
+//! assert_eq!(json, r#"{"pubkey":"1112","payload":"vSxRbq6XzDhP"}"#);
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::Serializer;
+
+/// Serializes bytes as plain Base58 with the Bitcoin alphabet.
+pub mod as_base58 {
+    use super::*;
+
+    /// Serializes `bytes` as a Base58 string, or as raw bytes in binary formats.
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        serialize_with(bytes.as_ref(), serializer, |b| crate::encode(b))
+    }
+
+    /// Deserializes a Base58 string, or raw bytes in binary formats.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        deserialize_with(deserializer, crate::decode)
+    }
+}
+
+/// Serializes bytes as Base58Check with the Bitcoin alphabet.
+///
+/// Binary formats store the bytes without a checksum.
+pub mod as_base58_check {
+    use super::*;
+
+    /// Serializes `bytes` as a Base58Check string, or as raw bytes in binary formats.
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        serialize_with(bytes.as_ref(), serializer, |b| crate::encode_check(b))
+    }
+
+    /// Deserializes and verifies a Base58Check string, or raw bytes in binary formats.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        deserialize_with(deserializer, crate::decode_check)
+    }
+}
+
+/// Writes a string in human-readable formats and raw bytes otherwise
+fn serialize_with<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+    encode: fn(&[u8]) -> String,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Reads a string in human-readable formats and raw bytes otherwise, then converts to `T`
+fn deserialize_with<'de, T, D>(
+    deserializer: D,
+    decode: fn(&str) -> Result<Vec<u8>, crate::DecodeError>,
+) -> Result<T, D::Error>
+where
+    T: TryFrom<Vec<u8>>,
+    D: Deserializer<'de>,
+{
+    let visitor = BytesVisitor { decode };
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)?
+    } else {
+        deserializer.deserialize_byte_buf(visitor)?
+    };
+
+    let len = bytes.len();
+    T::try_from(bytes)
+        .map_err(|_| de::Error::invalid_length(len, &"a byte string of the field's length"))
+}
+
+/// Accepts a Base58 string or raw bytes
+struct BytesVisitor {
+    decode: fn(&str) -> Result<Vec<u8>, crate::DecodeError>,
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Base58 string or a byte array")
+    }
+
+    fn visit_str<Err: de::Error>(self, v: &str) -> Result<Self::Value, Err> {
+        (self.decode)(v).map_err(Err::custom)
+    }
+
+    fn visit_bytes<Err: de::Error>(self, v: &[u8]) -> Result<Self::Value, Err> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<Err: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, Err> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            out.push(byte);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use serde_test::{Configure, Token, assert_tokens};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "crate::serde::as_base58")]
+        array: [u8; 5],
+        #[serde(with = "crate::serde::as_base58_check")]
+        vec: Vec<u8>,
+    }
+
+    fn sample() -> Fields {
+        Fields {
+            array: *b"Hello",
+            vec: vec![0, 1, 2],
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let json = serde_json::to_string(&sample()).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"array":"9Ajdvzr","vec":"{}"}}"#,
+                crate::encode_check([0, 1, 2])
+            )
+        );
+        assert_eq!(serde_json::from_str::<Fields>(&json).unwrap(), sample());
+    }
+
+    #[test]
+    fn test_binary_formats_use_raw_bytes() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "crate::serde::as_base58")] Vec<u8>);
+
+        assert_tokens(
+            &Wrapper(b"Hello".to_vec()).compact(),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Bytes(b"Hello"),
+            ],
+        );
+        assert_tokens(
+            &Wrapper(b"Hello".to_vec()).readable(),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Str("9Ajdvzr"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let bad_char = r#"{"array":"9Ajdvz0","vec":"1"}"#;
+        assert!(serde_json::from_str::<Fields>(bad_char).is_err());
+
+        let wrong_len = format!(r#"{{"array":"9Ajdv","vec":"{}"}}"#, crate::encode_check([]));
+        let err = serde_json::from_str::<Fields>(&wrong_len).unwrap_err();
+        assert!(err.to_string().contains("invalid length"));
+
+        let bad_checksum = r#"{"array":"9Ajdvzr","vec":"9Ajdvzr"}"#;
+        let err = serde_json::from_str::<Fields>(bad_checksum).unwrap_err();
+        assert!(err.to_string().contains("Checksum"), "{err}");
+    }
+}
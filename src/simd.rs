@@ -0,0 +1,270 @@
+//! Vectorized alphabet lookups for the `simd` feature.
+//!
+//! The big-integer conversion is a chain of carries from one limb to the next, so it
+//! stays scalar. What vectorizes is the translation on either side of it: digit values
+//! to alphabet characters when encoding, and characters to digit values, with
+//! validation, when decoding. Both are table lookups done one byte at a time by the
+//! scalar code.
+//!
+//! This is only a partial speedup. The limb arithmetic is quadratic in the input length
+//! and dominates for all but short inputs, and it is the same with or without this
+//! feature.
+//!
+//! On x86-64, AVX2 is used when the CPU supports it, which is detected at runtime. NEON is
+//! part of the aarch64 baseline and is always used there. Other targets, and inputs too
+//! short to fill a vector, use the scalar loops. CI cross-compiles the NEON path but
+//! does not run it.
+//!
+//! Each function translates a prefix of its input and returns its length; the caller
+//! translates the rest.
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Replaces a prefix of `digits` with alphabet characters and returns its length
+pub(crate) fn digits_to_chars(digits: &mut [u8], alphabet: &[u8]) -> usize {
+    // The vector tables hold up to 64 characters
+    if alphabet.len() > 64 {
+        return 0;
+    }
+    let mut table = [0u8; 64];
+    table[..alphabet.len()].copy_from_slice(alphabet);
+
+    #[cfg(target_arch = "x86_64")]
+    let done = if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        unsafe { digits_to_chars_avx2(digits, &table) }
+    } else {
+        0
+    };
+    #[cfg(target_arch = "aarch64")]
+    let done = digits_to_chars_neon(digits, &table);
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let done = {
+        let _ = (digits, table);
+        0
+    };
+    done
+}
+
+/// Looks up the digit values of a prefix of `input` into `digits` and returns its
+/// length. The prefix stops before any block holding a character that is not in the
+/// table, which the caller then finds.
+pub(crate) fn chars_to_digits(input: &[u8], decode_table: &[u8; 256], digits: &mut [u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    let done = if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        unsafe { chars_to_digits_avx2(input, decode_table, digits) }
+    } else {
+        0
+    };
+    #[cfg(target_arch = "aarch64")]
+    let done = chars_to_digits_neon(input, decode_table, digits);
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let done = {
+        let _ = (input, decode_table, digits);
+        0
+    };
+    done
+}
+
+/// Looks up `indices` in a 64-entry table split into four 16-byte rows, one
+/// `vpshufb` per row selected by the high bits of each index.
+///
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+// The value intrinsics are safe to call in AVX2 functions from Rust 1.87 on, but the
+// MSRV still needs the unsafe blocks around them
+#[allow(unused_unsafe)]
+unsafe fn lookup_avx2(rows: &[__m256i], indices: __m256i) -> __m256i {
+    // SAFETY: the caller guarantees AVX2 support
+    unsafe {
+        let low_nibbles = _mm256_set1_epi8(0x0F);
+        let low = _mm256_and_si256(indices, low_nibbles);
+        let high = _mm256_and_si256(_mm256_srli_epi16::<4>(indices), low_nibbles);
+
+        let mut result = _mm256_setzero_si256();
+        for (row_index, &row) in rows.iter().enumerate() {
+            let selected = _mm256_cmpeq_epi8(high, _mm256_set1_epi8(row_index as i8));
+            let values = _mm256_shuffle_epi8(row, low);
+            result = _mm256_or_si256(result, _mm256_and_si256(selected, values));
+        }
+        result
+    }
+}
+
+/// Loads 16-byte table rows, repeated in both 128-bit lanes as `vpshufb` works per lane.
+///
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[allow(unused_unsafe)]
+unsafe fn load_rows(table: &[u8; 64]) -> [__m256i; 4] {
+    // SAFETY: the caller guarantees AVX2 support
+    let mut rows = [unsafe { _mm256_setzero_si256() }; 4];
+    for (row, bytes) in rows.iter_mut().zip(table.chunks_exact(16)) {
+        // SAFETY: `bytes` is 16 bytes and the load is unaligned, and the caller
+        // guarantees AVX2 support
+        unsafe {
+            let bytes = _mm_loadu_si128(bytes.as_ptr().cast());
+            *row = _mm256_broadcastsi128_si256(bytes);
+        }
+    }
+    rows
+}
+
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn digits_to_chars_avx2(digits: &mut [u8], table: &[u8; 64]) -> usize {
+    // SAFETY: the caller guarantees AVX2 support
+    let rows = unsafe { load_rows(table) };
+    let mut done = 0;
+    for chunk in digits.chunks_exact_mut(32) {
+        // SAFETY: `chunk` is 32 bytes, the load and store are unaligned, and the caller
+        // guarantees AVX2 support
+        unsafe {
+            let indices = _mm256_loadu_si256(chunk.as_ptr().cast());
+            _mm256_storeu_si256(chunk.as_mut_ptr().cast(), lookup_avx2(&rows, indices));
+        }
+        done += 32;
+    }
+    done
+}
+
+/// The CPU must support AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+#[allow(unused_unsafe)]
+unsafe fn chars_to_digits_avx2(input: &[u8], decode_table: &[u8; 256], digits: &mut [u8]) -> usize {
+    // Only ASCII characters can be valid, so the first 128 entries are enough
+    // SAFETY: the caller guarantees AVX2 support
+    let (low_rows, high_rows, invalid) = unsafe {
+        (
+            load_rows(decode_table[..64].try_into().unwrap()),
+            load_rows(decode_table[64..128].try_into().unwrap()),
+            _mm256_set1_epi8(-1),
+        )
+    };
+
+    let mut done = 0;
+    for (chunk, out) in input.chunks_exact(32).zip(digits.chunks_exact_mut(32)) {
+        // SAFETY: `chunk` and `out` are 32 bytes, the load and store are unaligned, and
+        // the caller guarantees AVX2 support
+        unsafe {
+            let chars = _mm256_loadu_si256(chunk.as_ptr().cast());
+
+            // Characters 64 to 127 are looked up in the upper half of the table after
+            // subtracting 64. Bytes outside the half being looked up select rows 4 to
+            // 15, which do not exist and so contribute nothing.
+            let low = lookup_avx2(&low_rows, chars);
+            let high = lookup_avx2(&high_rows, _mm256_sub_epi8(chars, _mm256_set1_epi8(64)));
+            // Non-ASCII bytes are negative as signed bytes and are invalid
+            let non_ascii = _mm256_cmpgt_epi8(_mm256_setzero_si256(), chars);
+            let values = _mm256_or_si256(_mm256_or_si256(low, high), non_ascii);
+
+            if _mm256_movemask_epi8(_mm256_cmpeq_epi8(values, invalid)) != 0 {
+                break;
+            }
+            _mm256_storeu_si256(out.as_mut_ptr().cast(), values);
+        }
+        done += 32;
+    }
+    done
+}
+
+#[cfg(target_arch = "aarch64")]
+fn digits_to_chars_neon(digits: &mut [u8], table: &[u8; 64]) -> usize {
+    // SAFETY: `table` is 64 readable bytes
+    let table = unsafe { vld1q_u8_x4(table.as_ptr()) };
+    let mut done = 0;
+    for chunk in digits.chunks_exact_mut(16) {
+        // SAFETY: `chunk` is 16 bytes, and NEON loads and stores need no alignment
+        unsafe {
+            let indices = vld1q_u8(chunk.as_ptr());
+            vst1q_u8(chunk.as_mut_ptr(), vqtbl4q_u8(table, indices));
+        }
+        done += 16;
+    }
+    done
+}
+
+#[cfg(target_arch = "aarch64")]
+fn chars_to_digits_neon(input: &[u8], decode_table: &[u8; 256], digits: &mut [u8]) -> usize {
+    // SAFETY: the table has 256 readable bytes, of which these read the first 128
+    let (low_table, high_table) = unsafe {
+        (
+            vld1q_u8_x4(decode_table.as_ptr()),
+            vld1q_u8_x4(decode_table.as_ptr().add(64)),
+        )
+    };
+
+    let mut done = 0;
+    for (chunk, out) in input.chunks_exact(16).zip(digits.chunks_exact_mut(16)) {
+        // SAFETY: `chunk` and `out` are 16 bytes, and NEON loads and stores need no
+        // alignment
+        unsafe {
+            let chars = vld1q_u8(chunk.as_ptr());
+            // Indices past the end of a 64-byte table look up zero, so each character
+            // is found in at most one half
+            let low = vqtbl4q_u8(low_table, chars);
+            let high = vqtbl4q_u8(high_table, vsubq_u8(chars, vdupq_n_u8(64)));
+            // Non-ASCII bytes are found in neither half and are invalid
+            let non_ascii = vcgeq_u8(chars, vdupq_n_u8(128));
+            let values = vorrq_u8(vorrq_u8(low, high), non_ascii);
+
+            if vmaxvq_u8(values) == 255 {
+                break;
+            }
+            vst1q_u8(out.as_mut_ptr(), values);
+        }
+        done += 16;
+    }
+    done
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alphabet;
+
+    #[test]
+    fn test_digits_to_chars_matches_scalar() {
+        for alphabet in [Alphabet::Bitcoin, Alphabet::Ripple, Alphabet::Flickr] {
+            let alphabet = alphabet.as_bytes();
+            let digits: Vec<u8> = (0..200u32).map(|i| (i * 7 % 58) as u8).collect();
+            let mut translated = digits.clone();
+            let done = digits_to_chars(&mut translated, alphabet);
+            for (digit, c) in digits.iter().zip(&translated).take(done) {
+                assert_eq!(*c, alphabet[*digit as usize]);
+            }
+        }
+        assert_eq!(digits_to_chars(&mut [0; 64], &[b'a'; 65]), 0);
+    }
+
+    #[test]
+    fn test_chars_to_digits_stops_before_invalid() {
+        let table = Alphabet::Bitcoin.decode_table();
+        let valid: Vec<u8> = (0..=255u8).filter(|&c| table[c as usize] != 255).collect();
+        let input: Vec<u8> = valid.iter().cycle().take(200).copied().collect();
+
+        let mut digits = vec![0; input.len()];
+        let done = chars_to_digits(&input, table, &mut digits);
+        for (c, digit) in input.iter().zip(&digits).take(done) {
+            assert_eq!(*digit, table[*c as usize]);
+        }
+
+        // Every invalid byte, ASCII or not, stops the lookup before its block
+        for bad in (0..=255u8).filter(|&c| table[c as usize] == 255) {
+            let mut input = input.clone();
+            input[100] = bad;
+            assert!(
+                chars_to_digits(&input, table, &mut digits) <= 100,
+                "byte {bad}"
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Solana public keys and signatures.
+//!
+//! Solana displays 32-byte public keys and 64-byte signatures as plain Base58 with the
+//! Bitcoin alphabet. The types here encode into and decode from fixed-size stack
+//! buffers, so they work without the `std` feature and never allocate.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::solana::Pubkey;
+//!
+//! let system_program: Pubkey = "11111111111111111111111111111111".parse().unwrap();
+//! assert_eq!(system_program.to_bytes(), [0u8; 32]);
+//! assert_eq!(system_program.to_string(), "11111111111111111111111111111111");
+//! ```
+
+use crate::specialized::{encode32_to_buf, encode64_to_buf};
+use crate::{DecodeError, decode32, decode64, encoded_len};
+
+/// Length in bytes of a public key.
+pub const PUBKEY_LEN: usize = 32;
+/// Length in bytes of a signature.
+pub const SIGNATURE_LEN: usize = 64;
+
+/// A 32-byte Ed25519 public key, displayed as Base58.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pubkey(pub [u8; PUBKEY_LEN]);
+
+impl Pubkey {
+    /// Creates a public key from its raw bytes.
+    pub const fn new(bytes: [u8; PUBKEY_LEN]) -> Self {
+        Pubkey(bytes)
+    }
+
+    /// Returns the raw bytes of the public key.
+    pub const fn to_bytes(&self) -> [u8; PUBKEY_LEN] {
+        self.0
+    }
+
+    /// Returns a reference to the raw bytes of the public key.
+    pub const fn as_bytes(&self) -> &[u8; PUBKEY_LEN] {
+        &self.0
+    }
+}
+
+impl From<[u8; PUBKEY_LEN]> for Pubkey {
+    fn from(bytes: [u8; PUBKEY_LEN]) -> Self {
+        Pubkey(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Pubkey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for Pubkey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0u8; encoded_len(PUBKEY_LEN)];
+        f.write_str(encode32_to_buf(&self.0, &mut buf))
+    }
+}
+
+impl core::fmt::Debug for Pubkey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Pubkey({self})")
+    }
+}
+
+impl core::str::FromStr for Pubkey {
+    type Err = DecodeError;
+
+    /// Parses a Base58 public key, failing unless it decodes to exactly 32 bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode32(s).map(Pubkey)
+    }
+}
+
+/// A 64-byte Ed25519 signature, displayed as Base58.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Signature(pub [u8; SIGNATURE_LEN]);
+
+impl Signature {
+    /// Creates a signature from its raw bytes.
+    pub const fn new(bytes: [u8; SIGNATURE_LEN]) -> Self {
+        Signature(bytes)
+    }
+
+    /// Returns the raw bytes of the signature.
+    pub const fn to_bytes(&self) -> [u8; SIGNATURE_LEN] {
+        self.0
+    }
+
+    /// Returns a reference to the raw bytes of the signature.
+    pub const fn as_bytes(&self) -> &[u8; SIGNATURE_LEN] {
+        &self.0
+    }
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Signature([0; SIGNATURE_LEN])
+    }
+}
+
+impl From<[u8; SIGNATURE_LEN]> for Signature {
+    fn from(bytes: [u8; SIGNATURE_LEN]) -> Self {
+        Signature(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0u8; encoded_len(SIGNATURE_LEN)];
+        f.write_str(encode64_to_buf(&self.0, &mut buf))
+    }
+}
+
+impl core::fmt::Debug for Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Signature({self})")
+    }
+}
+
+impl core::str::FromStr for Signature {
+    type Err = DecodeError;
+
+    /// Parses a Base58 signature, failing unless it decodes to exactly 64 bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode64(s).map(Signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pubkey_known_vectors() {
+        let system: Pubkey = "11111111111111111111111111111111".parse().unwrap();
+        assert_eq!(system, Pubkey::default());
+
+        let max = Pubkey([0xFF; 32]);
+        assert_eq!(
+            max.to_string(),
+            "JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG"
+        );
+        assert_eq!(max.to_string().parse::<Pubkey>().unwrap(), max);
+    }
+
+    #[test]
+    fn test_signature_roundtrip() {
+        let mut bytes = [0u8; 64];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(37).wrapping_add(1);
+        }
+        let sig = Signature::new(bytes);
+        let encoded = sig.to_string();
+        assert!(encoded.len() <= 88);
+        assert_eq!(encoded.parse::<Signature>().unwrap(), sig);
+
+        let max = Signature([0xFF; 64]).to_string();
+        assert_eq!(max.len(), 88);
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_length() {
+        assert_eq!(
+            "9Ajdvzr".parse::<Pubkey>(),
+            Err(DecodeError::InvalidLength {
+                expected: 32,
+                actual: 5
+            })
+        );
+        let sig = Signature([0xFF; 64]).to_string();
+        assert!(sig.parse::<Pubkey>().is_err());
+        assert_eq!(
+            "1111111111111111111111111111111O".parse::<Pubkey>(),
+            Err(DecodeError::InvalidCharacter {
+                character: 'O',
+                position: 31
+            })
+        );
+    }
+
+    #[test]
+    fn test_debug_shows_base58() {
+        assert_eq!(
+            format!("{:?}", Pubkey::default()),
+            "Pubkey(11111111111111111111111111111111)"
+        );
+    }
+}
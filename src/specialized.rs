@@ -0,0 +1,359 @@
+//! Codecs specialized for the most common input lengths.
+//!
+//! With the length fixed at compile time, every loop in the conversion has a constant trip
+//! count and no limb or digit counts are tracked, so the compiler can unroll the
+//! arithmetic completely. Decoding only takes the fast path for valid input; anything
+//! else is decoded again by [`decode_array`] to report the same error.
+
+use crate::{Alphabet, DecodeError, decode_array, encoded_len};
+
+/// Largest power of 58 that fits in a 32-bit limb
+const POWER: u64 = 58u64.pow(5);
+/// Maximum encoded length of 32 bytes
+const ENCODED_32: usize = encoded_len(32);
+/// Maximum encoded length of 64 bytes
+pub(crate) const ENCODED_64: usize = encoded_len(64);
+/// Maximum encoded length of 25 bytes
+#[cfg(feature = "std")]
+pub(crate) const ENCODED_25: usize = encoded_len(25);
+
+/// Encodes 32 bytes as Base58 using the default Bitcoin alphabet.
+///
+/// This produces the same string as [`encode`](crate::encode), using a conversion
+/// unrolled for the 32-byte hashes and public keys that dominate real workloads.
+///
+/// # Arguments
+///
+/// * `input` - The 32 bytes to encode
+///
+/// # Returns
+///
+/// The Base58 encoded string
+///
+/// # Examples
+///
+/// ```
+/// use b58::{encode, encode32};
+///
+/// let key = [7u8; 32];
+/// assert_eq!(encode32(key), encode(key));
+/// ```
+#[cfg(feature = "std")]
+pub fn encode32(input: [u8; 32]) -> String {
+    let mut buf = [0u8; ENCODED_32];
+    encode32_to_buf(&input, &mut buf).to_owned()
+}
+
+/// Decodes a Base58 string of exactly 32 bytes using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded bytes, or the same `DecodeError` as [`decode_array`] on failure
+///
+/// # Examples
+///
+/// ```
+/// use b58::{DecodeError, decode32};
+///
+/// let bytes = decode32("11111111111111111111111111111111").unwrap();
+/// assert_eq!(bytes, [0u8; 32]);
+/// assert_eq!(
+///     decode32("9Ajdvzr"),
+///     Err(DecodeError::InvalidLength { expected: 32, actual: 5 })
+/// );
+/// ```
+pub fn decode32(input: &str) -> Result<[u8; 32], DecodeError> {
+    match decode_fixed::<32, 8>(input, Alphabet::Bitcoin) {
+        Some(bytes) => Ok(bytes),
+        None => decode_array(input),
+    }
+}
+
+/// Encodes 64 bytes as Base58 using the default Bitcoin alphabet.
+///
+/// Ed25519 signatures, such as Solana's, are 64 bytes.
+/// [`solana::Signature`](crate::solana::Signature) uses this conversion.
+///
+/// # Arguments
+///
+/// * `input` - The 64 bytes to encode
+///
+/// # Returns
+///
+/// The Base58 encoded string
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode64, encode, encode64};
+///
+/// let signature = [9u8; 64];
+/// assert_eq!(encode64(signature), encode(signature));
+/// assert_eq!(decode64(&encode64(signature)).unwrap(), signature);
+/// ```
+#[cfg(feature = "std")]
+pub fn encode64(input: [u8; 64]) -> String {
+    let mut buf = [0u8; ENCODED_64];
+    encode64_to_buf(&input, &mut buf).to_owned()
+}
+
+/// Decodes a Base58 string of exactly 64 bytes using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded bytes, or the same `DecodeError` as [`decode_array`] on failure
+pub fn decode64(input: &str) -> Result<[u8; 64], DecodeError> {
+    match decode_fixed::<64, 16>(input, Alphabet::Bitcoin) {
+        Some(bytes) => Ok(bytes),
+        None => decode_array(input),
+    }
+}
+
+/// Encodes 25 bytes as Base58 using the default Bitcoin alphabet.
+///
+/// Base58Check addresses are 25 bytes: a version byte, a 20-byte hash and a 4-byte
+/// checksum. [`encode_check`](crate::encode_check) and
+/// [`decode_check`](crate::decode_check) use this conversion automatically for 21-byte
+/// payloads.
+///
+/// # Arguments
+///
+/// * `input` - The 25 bytes to encode
+///
+/// # Returns
+///
+/// The Base58 encoded string
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode25, encode25};
+///
+/// let address = encode25([0; 25]);
+/// assert_eq!(address, "1111111111111111111111111");
+/// assert_eq!(decode25(&address).unwrap(), [0; 25]);
+/// ```
+#[cfg(feature = "std")]
+pub fn encode25(input: [u8; 25]) -> String {
+    let mut buf = [0u8; ENCODED_25];
+    let start = encode_fixed::<25, 7, ENCODED_25>(&input, Alphabet::Bitcoin, &mut buf);
+    as_str(&buf[start..]).to_owned()
+}
+
+/// Decodes a Base58 string of exactly 25 bytes using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The decoded bytes, or the same `DecodeError` as [`decode_array`] on failure
+pub fn decode25(input: &str) -> Result<[u8; 25], DecodeError> {
+    match decode_fixed::<25, 7>(input, Alphabet::Bitcoin) {
+        Some(bytes) => Ok(bytes),
+        None => decode_array(input),
+    }
+}
+
+/// Encodes 32 bytes into `buf`, returning the encoded part
+pub(crate) fn encode32_to_buf<'a>(input: &[u8; 32], buf: &'a mut [u8; ENCODED_32]) -> &'a str {
+    let start = encode_fixed::<32, 8, ENCODED_32>(input, Alphabet::Bitcoin, buf);
+    as_str(&buf[start..])
+}
+
+/// Encodes 64 bytes into `buf`, returning the encoded part
+pub(crate) fn encode64_to_buf<'a>(input: &[u8; 64], buf: &'a mut [u8; ENCODED_64]) -> &'a str {
+    let start = encode_fixed::<64, 16, ENCODED_64>(input, Alphabet::Bitcoin, buf);
+    as_str(&buf[start..])
+}
+
+/// Views encoded characters as a string
+fn as_str(encoded: &[u8]) -> &str {
+    core::str::from_utf8(encoded).expect("Base58 output is ASCII")
+}
+
+/// Encodes `N` bytes held in `L` limbs into the end of `out`, returning where the
+/// encoding starts
+pub(crate) fn encode_fixed<const N: usize, const L: usize, const D: usize>(
+    input: &[u8; N],
+    alphabet: Alphabet,
+    out: &mut [u8; D],
+) -> usize {
+    // Pack into big-endian limbs, padding the first one with zeros
+    let mut limbs = [0u32; L];
+    for (i, &b) in input.iter().enumerate() {
+        let from_end = N - 1 - i;
+        limbs[L - 1 - from_end / 4] |= (b as u32) << (from_end % 4 * 8);
+    }
+
+    // Write every digit, most significant first, taking five per division pass. The
+    // digits above the first D are all zero because D is the maximum encoded length.
+    let mut pos = D;
+    while pos > 0 {
+        let mut remainder = 0u64;
+        for limb in limbs.iter_mut() {
+            let temp = (remainder << 32) | *limb as u64;
+            *limb = (temp / POWER) as u32;
+            remainder = temp % POWER;
+        }
+        for _ in 0..5.min(pos) {
+            pos -= 1;
+            out[pos] = (remainder % 58) as u8;
+            remainder /= 58;
+        }
+    }
+
+    // Each leading zero byte becomes one zero digit in front of the significant digits.
+    // There is always room for them, as the significant digits of N - z bytes plus z
+    // zero digits never exceed D.
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    let significant = out.iter().position(|&d| d != 0).unwrap_or(D);
+    let start = significant - zeros;
+
+    let alphabet = alphabet.as_bytes();
+    for digit in &mut out[start..] {
+        *digit = alphabet[*digit as usize];
+    }
+    start
+}
+
+/// Decodes valid Base58 of exactly `N` bytes using `L` limbs, or returns `None`
+pub(crate) fn decode_fixed<const N: usize, const L: usize>(
+    input: &str,
+    alphabet: Alphabet,
+) -> Option<[u8; N]> {
+    let input = input.as_bytes();
+    if input.len() > encoded_len(N) {
+        return None;
+    }
+    let decode_table = alphabet.decode_table();
+    let zero = alphabet.as_bytes()[0];
+    let zeros = input.iter().take_while(|&&b| b == zero).count();
+
+    // Accumulate up to five digits at a time into little-endian limbs, starting with the
+    // short group so that the rest are whole
+    let digits = &input[zeros..];
+    let (head, body) = digits.split_at(digits.len() % 5);
+    let mut limbs = [0u32; L];
+    for group in core::iter::once(head).chain(body.chunks_exact(5)) {
+        let mut value = 0u32;
+        for &b in group {
+            let digit = decode_table[b as usize];
+            if digit == 255 {
+                return None;
+            }
+            value = value * 58 + digit as u32;
+        }
+
+        let multiplier = 58u64.pow(group.len() as u32);
+        let mut carry = value as u64;
+        for limb in limbs.iter_mut() {
+            let temp = *limb as u64 * multiplier + carry;
+            *limb = temp as u32;
+            carry = temp >> 32;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    // Unpack to big-endian bytes; the value must fill exactly the bytes after the zeros
+    let byte = |i: usize| (limbs[i / 4] >> (i % 4 * 8)) as u8;
+    if (N..L * 4).any(|i| byte(i) != 0) {
+        return None;
+    }
+    let mut out = [0u8; N];
+    for (i, b) in out.iter_mut().rev().enumerate() {
+        *b = byte(i);
+    }
+    if out.iter().take_while(|&&b| b == 0).count() != zeros {
+        return None;
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inputs covering leading zeros, all-zero and all-ones keys
+    fn sample_inputs() -> impl Iterator<Item = [u8; 32]> {
+        (0..=32).flat_map(|zeros| {
+            [0x01u8, 0x80, 0xFF].map(|fill| {
+                let mut input = [0; 32];
+                for (i, b) in input.iter_mut().enumerate().skip(zeros) {
+                    *b = fill.wrapping_add((i as u8).wrapping_mul(37));
+                }
+                input
+            })
+        })
+    }
+
+    #[test]
+    fn test_matches_generic_codec() {
+        for input in sample_inputs().chain([[0xFF; 32]]) {
+            let mut buf = [0u8; ENCODED_32];
+            let encoded = encode32_to_buf(&input, &mut buf);
+            let mut expected = [0u8; ENCODED_32];
+            let len = crate::encode_slice(&input, &mut expected).unwrap();
+            assert_eq!(encoded.as_bytes(), &expected[..len]);
+            assert_eq!(decode32(encoded), Ok(input));
+
+            let input: [u8; 25] = input[7..].try_into().unwrap();
+            let mut buf = [0u8; encoded_len(25)];
+            let start =
+                encode_fixed::<25, 7, { encoded_len(25) }>(&input, Alphabet::Bitcoin, &mut buf);
+            let len = crate::encode_slice(&input, &mut expected).unwrap();
+            assert_eq!(buf[start..], expected[..len]);
+            assert_eq!(decode25(as_str(&buf[start..])), Ok(input));
+        }
+    }
+
+    #[test]
+    fn test_64_matches_generic_codec() {
+        let halves: Vec<[u8; 32]> = sample_inputs().chain([[0xFF; 32]]).collect();
+        for (high, low) in halves.iter().zip(halves.iter().rev()) {
+            for input in [[*high, *low].concat(), [[0; 32], *high].concat()] {
+                let input: [u8; 64] = input.try_into().unwrap();
+                let mut buf = [0u8; ENCODED_64];
+                let encoded = encode64_to_buf(&input, &mut buf);
+                let mut expected = [0u8; ENCODED_64];
+                let len = crate::encode_slice(&input, &mut expected).unwrap();
+                assert_eq!(encoded.as_bytes(), &expected[..len]);
+                assert_eq!(decode64(encoded), Ok(input));
+            }
+        }
+
+        let max = encode64_to_buf(&[0xFF; 64], &mut [0u8; ENCODED_64]).to_owned();
+        for input in ["", "1", &max[..80], &format!("{max}1"), &format!("z{max}")] {
+            assert_eq!(decode64(input), decode_array::<64>(input), "{input}");
+            assert!(decode64(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_errors_match_decode_array() {
+        let cases = [
+            "",
+            "1",
+            "9Ajdvzr",
+            "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi0",
+            "zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz",
+            "111111111111111111111111111111111",
+            "11111111111111111111111111111111é",
+        ];
+        for input in cases {
+            assert_eq!(decode32(input), decode_array::<32>(input), "{input}");
+            assert!(decode32(input).is_err(), "{input}");
+        }
+    }
+}
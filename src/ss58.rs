@@ -0,0 +1,218 @@
+//! Substrate SS58 addresses.
+//!
+//! An SS58 address is the Base58 encoding of a network prefix (one byte for prefixes
+//! below 64, two bytes up to 16383), a 32-byte account ID, and the first two bytes of
+//! the BLAKE2b-512 hash of `"SS58PRE"` followed by the prefix and account ID.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::ss58;
+//!
+//! let (prefix, account_id) = ss58::decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+//! assert_eq!(prefix, ss58::SUBSTRATE_PREFIX);
+//!
+//! // The same account on Polkadot
+//! let polkadot = ss58::encode(ss58::POLKADOT_PREFIX, &account_id).unwrap();
+//! assert_eq!(polkadot, "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5");
+//! ```
+
+use crate::DecodeError;
+use crate::blake2b::Blake2b512;
+
+/// Network prefix of Polkadot addresses.
+pub const POLKADOT_PREFIX: u16 = 0;
+/// Network prefix of Kusama addresses.
+pub const KUSAMA_PREFIX: u16 = 2;
+/// Network prefix of generic Substrate addresses.
+pub const SUBSTRATE_PREFIX: u16 = 42;
+
+/// The largest prefix that can be represented.
+pub const MAX_PREFIX: u16 = 0x3FFF;
+
+const CHECKSUM_PREIMAGE: &[u8] = b"SS58PRE";
+const CHECKSUM_LEN: usize = 2;
+const ACCOUNT_ID_LEN: usize = 32;
+
+/// Error type for SS58 encoding and decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ss58Error {
+    /// The string is not valid Base58.
+    Decode(DecodeError),
+    /// The network prefix is larger than [`MAX_PREFIX`], or the first decoded byte is
+    /// not a valid prefix encoding.
+    InvalidPrefix(u16),
+    /// The decoded data has the wrong length for a 32-byte account ID.
+    InvalidLength(usize),
+}
+
+impl core::fmt::Display for Ss58Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ss58Error::Decode(e) => write!(f, "Invalid SS58 encoding: {e}"),
+            Ss58Error::InvalidPrefix(p) => write!(f, "Invalid SS58 network prefix: {p}"),
+            Ss58Error::InvalidLength(len) => write!(f, "Invalid SS58 data length: {len}"),
+        }
+    }
+}
+
+impl core::error::Error for Ss58Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Ss58Error::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for Ss58Error {
+    fn from(e: DecodeError) -> Self {
+        Ss58Error::Decode(e)
+    }
+}
+
+/// Encodes a 32-byte account ID as an SS58 address.
+///
+/// # Arguments
+///
+/// * `prefix` - The network prefix, at most [`MAX_PREFIX`]
+/// * `account_id` - The account ID, usually a public key
+///
+/// # Returns
+///
+/// The SS58 address, or `Ss58Error::InvalidPrefix` if the prefix is larger than
+/// [`MAX_PREFIX`]
+pub fn encode(prefix: u16, account_id: &[u8; 32]) -> Result<String, Ss58Error> {
+    let mut data = Vec::with_capacity(2 + ACCOUNT_ID_LEN + CHECKSUM_LEN);
+    match prefix {
+        0..64 => data.push(prefix as u8),
+        64..=MAX_PREFIX => {
+            // The low six bits of the first byte and the high bits of the second hold
+            // the prefix's low byte; the rest of the second byte holds its high byte
+            data.push((((prefix & 0b1111_1100) >> 2) as u8) | 0b0100_0000);
+            data.push(((prefix >> 8) as u8) | (((prefix & 0b11) as u8) << 6));
+        }
+        _ => return Err(Ss58Error::InvalidPrefix(prefix)),
+    }
+    data.extend_from_slice(account_id);
+
+    let hash = checksum(&data);
+    data.extend_from_slice(&hash[..CHECKSUM_LEN]);
+    Ok(crate::encode(&data))
+}
+
+/// Decodes an SS58 address into its network prefix and 32-byte account ID.
+///
+/// # Arguments
+///
+/// * `input` - The SS58 address
+///
+/// # Returns
+///
+/// The network prefix and account ID, or an `Ss58Error` if the encoding, prefix,
+/// length or checksum is invalid
+pub fn decode(input: &str) -> Result<(u16, [u8; 32]), Ss58Error> {
+    let data = crate::decode(input)?;
+
+    let (prefix, prefix_len) = match data.first() {
+        Some(&b) if b < 64 => (b as u16, 1),
+        Some(&b) if b < 128 => {
+            let second = *data.get(1).ok_or(Ss58Error::InvalidLength(data.len()))?;
+            let lower = (b << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            (lower as u16 | ((upper as u16) << 8), 2)
+        }
+        Some(&b) => return Err(Ss58Error::InvalidPrefix(b as u16)),
+        None => return Err(Ss58Error::InvalidLength(0)),
+    };
+
+    if data.len() != prefix_len + ACCOUNT_ID_LEN + CHECKSUM_LEN {
+        return Err(Ss58Error::InvalidLength(data.len()));
+    }
+
+    let (body, check) = data.split_at(prefix_len + ACCOUNT_ID_LEN);
+    if checksum(body)[..CHECKSUM_LEN] != *check {
+        return Err(DecodeError::ChecksumMismatch.into());
+    }
+
+    let mut account_id = [0u8; 32];
+    account_id.copy_from_slice(&body[prefix_len..]);
+    Ok((prefix, account_id))
+}
+
+/// Computes the BLAKE2b-512 hash of the SS58 checksum preimage
+fn checksum(data: &[u8]) -> [u8; 64] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(CHECKSUM_PREIMAGE);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Well-known development account "Alice"
+    const ALICE: [u8; 32] = [
+        0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f,
+        0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
+        0xa2, 0x7d,
+    ];
+
+    #[test]
+    fn test_encode_known_vectors() {
+        assert_eq!(
+            encode(SUBSTRATE_PREFIX, &ALICE).unwrap(),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        assert_eq!(
+            encode(POLKADOT_PREFIX, &ALICE).unwrap(),
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        assert_eq!(
+            encode(KUSAMA_PREFIX, &ALICE).unwrap(),
+            "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"
+        );
+    }
+
+    #[test]
+    fn test_decode_known_vector() {
+        assert_eq!(
+            decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(),
+            (SUBSTRATE_PREFIX, ALICE)
+        );
+    }
+
+    #[test]
+    fn test_two_byte_prefix_roundtrip() {
+        for prefix in [64, 255, 1000, MAX_PREFIX] {
+            let encoded = encode(prefix, &ALICE).unwrap();
+            assert_eq!(
+                decode(&encoded).unwrap(),
+                (prefix, ALICE),
+                "prefix {prefix}"
+            );
+        }
+        assert_eq!(
+            encode(MAX_PREFIX + 1, &ALICE),
+            Err(Ss58Error::InvalidPrefix(MAX_PREFIX + 1))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"),
+            Err(Ss58Error::Decode(DecodeError::ChecksumMismatch))
+        );
+        assert_eq!(decode("9Ajdvzr"), Err(Ss58Error::InvalidLength(5)));
+        assert_eq!(decode(""), Err(Ss58Error::InvalidLength(0)));
+        assert_eq!(
+            decode("5Grwva0"),
+            Err(Ss58Error::Decode(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 6
+            }))
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! Streaming adapters for `std::io`.
+//!
+//! Plain Base58 treats the whole input as one number, so nothing can be written until
+//! every byte has been read. These adapters use the block-wise format of
+//! [`monero`](crate::monero) instead: each 8-byte block becomes exactly 11 characters,
+//! and a final partial block uses a shorter fixed width. The output of a stream is
+//! identical to [`monero::encode`](crate::monero::encode) of the same bytes.
+
+use std::io::{self, Read, Write};
+
+use crate::DecodeError;
+use crate::monero::{
+    BLOCK_SIZE, ENCODED_BLOCK_SIZE, MoneroError, decode_block, decoded_block_len, encode_block,
+};
+
+/// Number of bytes encoded per call to the inner writer.
+pub(crate) const WRITE_CHUNK_SIZE: usize = 512 * BLOCK_SIZE;
+/// Number of encoded characters read from the inner reader at a time.
+const READ_BUFFER_SIZE: usize = 8192;
+
+/// A writer that encodes the bytes written to it as block-wise Base58 and passes the
+/// characters on to an inner writer.
+///
+/// Only a partial block of up to 7 bytes is buffered, so inputs of any size can be
+/// encoded in constant memory. Call [`finish`](Base58EncodeWriter::finish) once all data
+/// has been written to encode the final partial block; dropping the writer without
+/// finishing loses it.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use b58::{Base58EncodeWriter, monero};
+///
+/// let mut writer = Base58EncodeWriter::new(Vec::new());
+/// writer.write_all(b"Hello, ")?;
+/// writer.write_all(b"World!")?;
+/// let encoded = writer.finish()?;
+/// assert_eq!(encoded, monero::encode(b"Hello, World!").as_bytes());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Base58EncodeWriter<W: Write> {
+    inner: W,
+    encoder: BlockEncoder,
+    out: Vec<u8>,
+}
+
+impl<W: Write> Base58EncodeWriter<W> {
+    /// Creates a writer that sends the encoded characters to `inner`.
+    pub fn new(inner: W) -> Self {
+        Base58EncodeWriter {
+            inner,
+            encoder: BlockEncoder::default(),
+            out: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly interleaves with the encoded output.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Encodes any buffered partial block, flushes the inner writer and returns it.
+    ///
+    /// # Returns
+    ///
+    /// The inner writer, or the first I/O error from writing or flushing it
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.clear();
+        self.encoder.finish(&mut self.out);
+        self.inner.write_all(&self.out)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for Base58EncodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for chunk in buf.chunks(WRITE_CHUNK_SIZE) {
+            self.out.clear();
+            self.encoder.encode(chunk, &mut self.out);
+            self.inner.write_all(&self.out)?;
+        }
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer. A buffered partial block is not written until
+    /// [`finish`](Base58EncodeWriter::finish), since its encoding depends on its final
+    /// length.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that decodes block-wise Base58 read from an inner reader.
+///
+/// This reverses [`Base58EncodeWriter`]: the inner reader must yield exactly the
+/// characters of [`monero::encode`](crate::monero::encode), with no whitespace or other
+/// separators. Invalid input is reported as an `io::ErrorKind::InvalidData` error wrapping
+/// a [`MoneroError`], whose character positions count from the start of the stream. The
+/// bytes of the blocks before an invalid one are returned before the error.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+/// use b58::Base58DecodeReader;
+///
+/// let mut reader = Base58DecodeReader::new("1An6UebxCZd1gu".as_bytes());
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded)?;
+/// assert_eq!(decoded, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Base58DecodeReader<R: Read> {
+    inner: R,
+    decoder: BlockDecoder,
+}
+
+impl<R: Read> Base58DecodeReader<R> {
+    /// Creates a reader that decodes the characters read from `inner`.
+    pub fn new(inner: R) -> Self {
+        Base58DecodeReader {
+            inner,
+            decoder: BlockDecoder::new(),
+        }
+    }
+
+    /// Returns a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips characters that have not been decoded yet.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the reader, returning the inner reader.
+    ///
+    /// Characters that were read from it but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for Base58DecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.decoder.needs_input() {
+            match self.inner.read(self.decoder.spare_input()) {
+                Ok(n) => self.decoder.filled(n),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.decoder.read(buf)
+    }
+}
+
+/// Block-wise encoding state shared by the sync and async writers
+#[derive(Debug, Default)]
+pub(crate) struct BlockEncoder {
+    pending: [u8; BLOCK_SIZE],
+    pending_len: usize,
+}
+
+impl BlockEncoder {
+    /// Appends the encoding of every completed block to `out`, buffering the rest
+    pub(crate) fn encode(&mut self, mut input: &[u8], out: &mut Vec<u8>) {
+        let mut digits = [0u8; ENCODED_BLOCK_SIZE];
+
+        // Complete the pending block first
+        if self.pending_len > 0 {
+            let take = input.len().min(BLOCK_SIZE - self.pending_len);
+            self.pending[self.pending_len..self.pending_len + take].copy_from_slice(&input[..take]);
+            self.pending_len += take;
+            input = &input[take..];
+            if self.pending_len < BLOCK_SIZE {
+                return;
+            }
+            out.extend_from_slice(encode_block(&self.pending, &mut digits));
+            self.pending_len = 0;
+        }
+
+        let mut blocks = input.chunks_exact(BLOCK_SIZE);
+        for block in &mut blocks {
+            out.extend_from_slice(encode_block(block, &mut digits));
+        }
+
+        let rest = blocks.remainder();
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+    }
+
+    /// Appends the encoding of the final partial block to `out`
+    pub(crate) fn finish(&mut self, out: &mut Vec<u8>) {
+        let mut digits = [0u8; ENCODED_BLOCK_SIZE];
+        out.extend_from_slice(encode_block(&self.pending[..self.pending_len], &mut digits));
+        self.pending_len = 0;
+    }
+}
+
+/// Block-wise decoding state shared by the sync and async readers
+#[derive(Debug)]
+pub(crate) struct BlockDecoder {
+    input: Box<[u8]>,
+    input_start: usize,
+    input_end: usize,
+    /// Stream offset of `input[input_start]`
+    position: usize,
+    eof: bool,
+    output: [u8; BLOCK_SIZE],
+    output_start: usize,
+}
+
+impl BlockDecoder {
+    /// Creates a decoder at the start of a stream
+    pub(crate) fn new() -> Self {
+        BlockDecoder {
+            input: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+            input_start: 0,
+            input_end: 0,
+            position: 0,
+            eof: false,
+            output: [0; BLOCK_SIZE],
+            output_start: BLOCK_SIZE,
+        }
+    }
+
+    /// Returns whether more input must be read before `read` can make progress
+    pub(crate) fn needs_input(&self) -> bool {
+        self.output_start == BLOCK_SIZE
+            && !self.eof
+            && self.input_end - self.input_start < ENCODED_BLOCK_SIZE
+    }
+
+    /// Moves buffered characters to the front and returns the free space after them
+    pub(crate) fn spare_input(&mut self) -> &mut [u8] {
+        self.input.copy_within(self.input_start..self.input_end, 0);
+        self.input_end -= self.input_start;
+        self.input_start = 0;
+        &mut self.input[self.input_end..]
+    }
+
+    /// Records that `n` characters were read into the spare input, with 0 meaning the end
+    pub(crate) fn filled(&mut self, n: usize) {
+        if n == 0 {
+            self.eof = true;
+        }
+        self.input_end += n;
+    }
+
+    /// Decodes buffered input into `buf` once `needs_input` is false
+    pub(crate) fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Return bytes left over from a block that did not fit last time
+        if self.output_start < BLOCK_SIZE {
+            let n = buf.len().min(BLOCK_SIZE - self.output_start);
+            buf[..n].copy_from_slice(&self.output[self.output_start..self.output_start + n]);
+            self.output_start += n;
+            return Ok(n);
+        }
+
+        let buffered = self.input_end - self.input_start;
+        let (width, size) = if buffered >= ENCODED_BLOCK_SIZE {
+            (ENCODED_BLOCK_SIZE, BLOCK_SIZE)
+        } else if buffered == 0 {
+            return Ok(0);
+        } else {
+            let size = decoded_block_len(buffered).ok_or_else(|| {
+                invalid_data(MoneroError::InvalidLength(self.position + buffered))
+            })?;
+            (buffered, size)
+        };
+
+        // Decode as many whole blocks straight into `buf` as fit
+        let mut written = 0;
+        if size == BLOCK_SIZE {
+            while buf.len() - written >= BLOCK_SIZE
+                && self.input_end - self.input_start >= ENCODED_BLOCK_SIZE
+            {
+                let value = self.decode_next(ENCODED_BLOCK_SIZE, BLOCK_SIZE)?;
+                buf[written..written + BLOCK_SIZE].copy_from_slice(&value);
+                written += BLOCK_SIZE;
+            }
+            if written > 0 {
+                return Ok(written);
+            }
+        }
+
+        // Otherwise keep the block and return as much of it as fits
+        self.output = self.decode_next(width, size)?;
+        self.output_start = BLOCK_SIZE - size;
+        let n = buf.len().min(size);
+        buf[..n].copy_from_slice(&self.output[self.output_start..self.output_start + n]);
+        self.output_start += n;
+        Ok(n)
+    }
+
+    /// Decodes the next `width` buffered characters as a block of `size` bytes
+    fn decode_next(&mut self, width: usize, size: usize) -> io::Result<[u8; BLOCK_SIZE]> {
+        let block = &self.input[self.input_start..self.input_start + width];
+        if let Some(i) = block.iter().position(|b| !b.is_ascii()) {
+            // Report the whole character when it is buffered
+            let rest = &self.input[self.input_start + i..self.input_end];
+            let character = String::from_utf8_lossy(&rest[..rest.len().min(4)])
+                .chars()
+                .next()
+                .unwrap_or(char::REPLACEMENT_CHARACTER);
+            return Err(invalid_data(DecodeError::InvalidCharacter {
+                character,
+                position: self.position + i,
+            }));
+        }
+
+        let value = decode_block(block, size, self.position).map_err(invalid_data)?;
+        self.input_start += width;
+        self.position += width;
+        Ok(value)
+    }
+}
+
+/// Wraps a decoding failure in an `io::Error`
+fn invalid_data(e: impl Into<MoneroError>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monero;
+
+    #[test]
+    fn test_matches_monero_encoding() {
+        let input: Vec<u8> = (0..10_000u32).map(|i| (i * 31 + 7) as u8).collect();
+        for split in [1, 3, 8, 13, 4096, input.len()] {
+            let mut writer = Base58EncodeWriter::new(Vec::new());
+            for chunk in input.chunks(split) {
+                writer.write_all(chunk).unwrap();
+            }
+            let encoded = writer.finish().unwrap();
+            assert_eq!(encoded, monero::encode(&input).as_bytes(), "split {split}");
+        }
+    }
+
+    #[test]
+    fn test_empty_and_partial() {
+        let writer = Base58EncodeWriter::new(Vec::new());
+        assert_eq!(writer.finish().unwrap(), b"");
+
+        let mut writer = Base58EncodeWriter::new(Vec::new());
+        writer.write_all(&[0xFF]).unwrap();
+        writer.flush().unwrap();
+        assert!(writer.get_ref().is_empty());
+        assert_eq!(writer.finish().unwrap(), b"5Q");
+    }
+
+    /// Yields the input a few bytes at a time, like a slow pipe
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.0.len().min(self.1).min(buf.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    fn decode_error(input: &str) -> MoneroError {
+        let mut reader = Base58DecodeReader::new(input.as_bytes());
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        *err.into_inner().unwrap().downcast::<MoneroError>().unwrap()
+    }
+
+    #[test]
+    fn test_reader_roundtrip() {
+        for len in [0, 1, 7, 8, 9, 1000, 20_000] {
+            let input: Vec<u8> = (0..len as u32).map(|i| (i * 13 + 5) as u8).collect();
+            let encoded = monero::encode(&input);
+
+            let mut decoded = Vec::new();
+            let mut reader = Base58DecodeReader::new(encoded.as_bytes());
+            reader.read_to_end(&mut decoded).unwrap();
+            assert_eq!(decoded, input, "length {len}");
+
+            // Small reads on both sides exercise the partial block paths
+            let mut decoded = Vec::new();
+            let mut reader = Base58DecodeReader::new(Trickle(encoded.as_bytes(), 5));
+            let mut buf = [0u8; 3];
+            loop {
+                let n = reader.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                decoded.extend_from_slice(&buf[..n]);
+            }
+            assert_eq!(decoded, input, "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_reader_errors() {
+        assert_eq!(decode_error("111111111111"), MoneroError::InvalidLength(12));
+        assert_eq!(
+            decode_error("11111111111110"),
+            MoneroError::Decode(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 13
+            })
+        );
+        assert_eq!(
+            decode_error("11111111111é"),
+            MoneroError::Decode(DecodeError::InvalidCharacter {
+                character: 'é',
+                position: 11
+            })
+        );
+        assert_eq!(
+            decode_error("5R"),
+            MoneroError::Decode(DecodeError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! A string that is known to contain only Base58 characters.
+
+use crate::{Alphabet, DecodeError, decode_with_alphabet, encode_with_alphabet};
+
+/// A validated Base58 string.
+///
+/// Every character is guaranteed to belong to the string's alphabet, so APIs can accept
+/// a `Base58String` instead of re-validating a `&str`, and [`Base58String::decode`]
+/// cannot fail.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, Base58String};
+///
+/// let s: Base58String = "9Ajdvzr".parse().unwrap();
+/// assert_eq!(s.decode(), b"Hello");
+///
+/// assert!(Base58String::new("0OIl", Alphabet::Bitcoin).is_err());
+/// assert!(Base58String::new("9wjdvzi", Alphabet::Ripple).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Base58String {
+    inner: String,
+    alphabet: Alphabet,
+}
+
+impl Base58String {
+    /// Validates a string against an alphabet.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to validate
+    /// * `alphabet` - The alphabet every character must belong to
+    ///
+    /// # Returns
+    ///
+    /// The validated string, or `DecodeError::InvalidCharacter` with the first character
+    /// that is not in the alphabet
+    pub fn new(s: impl Into<String>, alphabet: Alphabet) -> Result<Self, DecodeError> {
+        let inner = s.into();
+        let table = alphabet.decode_table();
+        if let Some((position, character)) = inner
+            .char_indices()
+            .find(|&(_, c)| !c.is_ascii() || table[c as usize] == 255)
+        {
+            return Err(DecodeError::InvalidCharacter {
+                character,
+                position,
+            });
+        }
+        Ok(Base58String { inner, alphabet })
+    }
+
+    /// Encodes bytes into a new Base58 string.
+    pub fn from_bytes(bytes: &[u8], alphabet: Alphabet) -> Self {
+        Base58String {
+            inner: encode_with_alphabet(bytes, alphabet),
+            alphabet,
+        }
+    }
+
+    /// Returns the string.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns the alphabet the string was validated against.
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Decodes the string into bytes.
+    pub fn decode(&self) -> Vec<u8> {
+        decode_with_alphabet(&self.inner, self.alphabet)
+            .expect("Base58String holds only alphabet characters")
+    }
+
+    /// Returns the underlying `String`.
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+}
+
+impl core::fmt::Display for Base58String {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl core::str::FromStr for Base58String {
+    type Err = DecodeError;
+
+    /// Validates against the default Bitcoin alphabet.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base58String::new(s, Alphabet::Bitcoin)
+    }
+}
+
+impl TryFrom<&str> for Base58String {
+    type Error = DecodeError;
+
+    /// Validates against the default Bitcoin alphabet.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Base58String::new(s, Alphabet::Bitcoin)
+    }
+}
+
+impl TryFrom<String> for Base58String {
+    type Error = DecodeError;
+
+    /// Validates against the default Bitcoin alphabet.
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Base58String::new(s, Alphabet::Bitcoin)
+    }
+}
+
+impl AsRef<str> for Base58String {
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl From<Base58String> for String {
+    fn from(s: Base58String) -> Self {
+        s.inner
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Base58String {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+/// Deserializes and validates against the default Bitcoin alphabet.
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Base58String {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Base58String::new(s, Alphabet::Bitcoin).map_err(::serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation() {
+        assert!(Base58String::new("", Alphabet::Bitcoin).is_ok());
+        assert_eq!(
+            Base58String::new("abc0", Alphabet::Bitcoin),
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                position: 3
+            })
+        );
+        assert_eq!(
+            Base58String::try_from("héllo"),
+            Err(DecodeError::InvalidCharacter {
+                character: 'é',
+                position: 1
+            })
+        );
+        // '1' is in the Ripple alphabet but 'l' is not
+        assert_eq!(
+            Base58String::new("1l", Alphabet::Ripple),
+            Err(DecodeError::InvalidCharacter {
+                character: 'l',
+                position: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let s = Base58String::from_bytes(b"Hello", Alphabet::Flickr);
+        assert_eq!(s.alphabet(), Alphabet::Flickr);
+        assert_eq!(s.decode(), b"Hello");
+        assert_eq!(Base58String::new(s.as_str(), Alphabet::Flickr).unwrap(), s);
+
+        let s: Base58String = "9Ajdvzr".parse().unwrap();
+        assert_eq!(s.to_string(), "9Ajdvzr");
+        assert_eq!(String::from(s), "9Ajdvzr");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let s: Base58String = serde_json::from_str(r#""9Ajdvzr""#).unwrap();
+        assert_eq!(s.decode(), b"Hello");
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#""9Ajdvzr""#);
+        assert!(serde_json::from_str::<Base58String>(r#""9Ajdvz0""#).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+//! Tezos prefixed Base58Check.
+//!
+//! Tezos prepends a multi-byte prefix to each payload before Base58Check encoding. The
+//! prefixes are chosen so that every encoding of a given payload length starts with the
+//! same human-readable text, such as `tz1` for Ed25519 public key hashes or `edpk` for
+//! Ed25519 public keys.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::tezos::{self, Prefix};
+//!
+//! let address = tezos::encode(Prefix::Tz1, &[0u8; 20]).unwrap();
+//! assert!(address.starts_with("tz1"));
+//!
+//! let (prefix, payload) = tezos::decode(&address).unwrap();
+//! assert_eq!(prefix, Prefix::Tz1);
+//! assert_eq!(payload, [0u8; 20]);
+//! ```
+
+use crate::{DecodeError, decode_check, encode_check};
+
+/// A known Tezos Base58Check prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    /// `tz1`: Ed25519 public key hash (20 bytes).
+    Tz1,
+    /// `tz2`: Secp256k1 public key hash (20 bytes).
+    Tz2,
+    /// `tz3`: P-256 public key hash (20 bytes).
+    Tz3,
+    /// `tz4`: BLS12-381 public key hash (20 bytes).
+    Tz4,
+    /// `KT1`: originated contract hash (20 bytes).
+    Kt1,
+    /// `edpk`: Ed25519 public key (32 bytes).
+    Edpk,
+    /// `sppk`: Secp256k1 public key (33 bytes).
+    Sppk,
+    /// `p2pk`: P-256 public key (33 bytes).
+    P2pk,
+    /// `edsk`: Ed25519 seed (32 bytes).
+    EdskSeed,
+    /// `edsk`: Ed25519 secret key, the seed followed by the public key (64 bytes).
+    Edsk,
+    /// `spsk`: Secp256k1 secret key (32 bytes).
+    Spsk,
+    /// `p2sk`: P-256 secret key (32 bytes).
+    P2sk,
+    /// `edsig`: Ed25519 signature (64 bytes).
+    Edsig,
+    /// `spsig1`: Secp256k1 signature (64 bytes).
+    Spsig1,
+    /// `p2sig`: P-256 signature (64 bytes).
+    P2sig,
+    /// `sig`: generic signature (64 bytes).
+    Sig,
+    /// `B`: block hash (32 bytes).
+    BlockHash,
+    /// `o`: operation hash (32 bytes).
+    OperationHash,
+    /// `P`: protocol hash (32 bytes).
+    ProtocolHash,
+    /// `Net`: chain ID (4 bytes).
+    ChainId,
+    /// `expr`: script expression hash (32 bytes).
+    ScriptExpr,
+}
+
+impl Prefix {
+    /// Every known prefix.
+    pub const ALL: [Prefix; 21] = [
+        Prefix::Tz1,
+        Prefix::Tz2,
+        Prefix::Tz3,
+        Prefix::Tz4,
+        Prefix::Kt1,
+        Prefix::Edpk,
+        Prefix::Sppk,
+        Prefix::P2pk,
+        Prefix::EdskSeed,
+        Prefix::Edsk,
+        Prefix::Spsk,
+        Prefix::P2sk,
+        Prefix::Edsig,
+        Prefix::Spsig1,
+        Prefix::P2sig,
+        Prefix::Sig,
+        Prefix::BlockHash,
+        Prefix::OperationHash,
+        Prefix::ProtocolHash,
+        Prefix::ChainId,
+        Prefix::ScriptExpr,
+    ];
+
+    /// Returns the bytes prepended to the payload before encoding.
+    pub const fn bytes(self) -> &'static [u8] {
+        match self {
+            Prefix::Tz1 => &[6, 161, 159],
+            Prefix::Tz2 => &[6, 161, 161],
+            Prefix::Tz3 => &[6, 161, 164],
+            Prefix::Tz4 => &[6, 161, 166],
+            Prefix::Kt1 => &[2, 90, 121],
+            Prefix::Edpk => &[13, 15, 37, 217],
+            Prefix::Sppk => &[3, 254, 226, 86],
+            Prefix::P2pk => &[3, 178, 139, 127],
+            Prefix::EdskSeed => &[13, 15, 58, 7],
+            Prefix::Edsk => &[43, 246, 78, 7],
+            Prefix::Spsk => &[17, 162, 224, 201],
+            Prefix::P2sk => &[16, 81, 238, 189],
+            Prefix::Edsig => &[9, 245, 205, 134, 18],
+            Prefix::Spsig1 => &[13, 115, 101, 19, 63],
+            Prefix::P2sig => &[54, 240, 44, 52],
+            Prefix::Sig => &[4, 130, 43],
+            Prefix::BlockHash => &[1, 52],
+            Prefix::OperationHash => &[5, 116],
+            Prefix::ProtocolHash => &[2, 170],
+            Prefix::ChainId => &[87, 82, 0],
+            Prefix::ScriptExpr => &[13, 44, 64, 27],
+        }
+    }
+
+    /// Returns the length in bytes of the payload this prefix is used with.
+    pub const fn payload_len(self) -> usize {
+        match self {
+            Prefix::Tz1 | Prefix::Tz2 | Prefix::Tz3 | Prefix::Tz4 | Prefix::Kt1 => 20,
+            Prefix::Sppk | Prefix::P2pk => 33,
+            Prefix::Edsk | Prefix::Edsig | Prefix::Spsig1 | Prefix::P2sig | Prefix::Sig => 64,
+            Prefix::ChainId => 4,
+            _ => 32,
+        }
+    }
+
+    /// Returns the text every encoding with this prefix starts with.
+    pub const fn text(self) -> &'static str {
+        match self {
+            Prefix::Tz1 => "tz1",
+            Prefix::Tz2 => "tz2",
+            Prefix::Tz3 => "tz3",
+            Prefix::Tz4 => "tz4",
+            Prefix::Kt1 => "KT1",
+            Prefix::Edpk => "edpk",
+            Prefix::Sppk => "sppk",
+            Prefix::P2pk => "p2pk",
+            Prefix::EdskSeed | Prefix::Edsk => "edsk",
+            Prefix::Spsk => "spsk",
+            Prefix::P2sk => "p2sk",
+            Prefix::Edsig => "edsig",
+            Prefix::Spsig1 => "spsig1",
+            Prefix::P2sig => "p2sig",
+            Prefix::Sig => "sig",
+            Prefix::BlockHash => "B",
+            Prefix::OperationHash => "o",
+            Prefix::ProtocolHash => "P",
+            Prefix::ChainId => "Net",
+            Prefix::ScriptExpr => "expr",
+        }
+    }
+}
+
+/// Error type for Tezos encoding and decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TezosError {
+    /// The string is not valid Base58Check.
+    Decode(DecodeError),
+    /// The decoded data does not start with a known prefix of the right payload length.
+    UnknownPrefix,
+    /// The payload does not have the length required by its prefix.
+    InvalidLength {
+        /// The number of payload bytes that was expected.
+        expected: usize,
+        /// The number of payload bytes that was given or decoded.
+        actual: usize,
+    },
+    /// The decoded prefix is not the one that was expected.
+    PrefixMismatch {
+        /// The prefix that was expected.
+        expected: Prefix,
+        /// The prefix that was found.
+        actual: Prefix,
+    },
+}
+
+impl core::fmt::Display for TezosError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TezosError::Decode(e) => write!(f, "Invalid Tezos encoding: {e}"),
+            TezosError::UnknownPrefix => write!(f, "Unknown Tezos prefix"),
+            TezosError::InvalidLength { expected, actual } => write!(
+                f,
+                "Invalid Tezos payload length: expected {expected} bytes, got {actual}"
+            ),
+            TezosError::PrefixMismatch { expected, actual } => write!(
+                f,
+                "Unexpected Tezos prefix: expected {}, got {}",
+                expected.text(),
+                actual.text()
+            ),
+        }
+    }
+}
+
+impl core::error::Error for TezosError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TezosError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for TezosError {
+    fn from(e: DecodeError) -> Self {
+        TezosError::Decode(e)
+    }
+}
+
+/// Encodes a payload with a Tezos prefix as Base58Check.
+///
+/// # Arguments
+///
+/// * `prefix` - The kind of value being encoded
+/// * `payload` - The raw bytes, which must be [`Prefix::payload_len`] bytes long
+///
+/// # Returns
+///
+/// The encoded string, or `TezosError::InvalidLength` if the payload has the wrong length
+pub fn encode(prefix: Prefix, payload: &[u8]) -> Result<String, TezosError> {
+    if payload.len() != prefix.payload_len() {
+        return Err(TezosError::InvalidLength {
+            expected: prefix.payload_len(),
+            actual: payload.len(),
+        });
+    }
+
+    let mut data = Vec::with_capacity(prefix.bytes().len() + payload.len());
+    data.extend_from_slice(prefix.bytes());
+    data.extend_from_slice(payload);
+    Ok(encode_check(&data))
+}
+
+/// Decodes a Tezos Base58Check string and identifies its prefix.
+///
+/// # Arguments
+///
+/// * `input` - The encoded string, such as `tz1...`
+///
+/// # Returns
+///
+/// The prefix kind and the raw payload, or a `TezosError` if the checksum is invalid or
+/// the data does not match any known prefix
+pub fn decode(input: &str) -> Result<(Prefix, Vec<u8>), TezosError> {
+    let data = decode_check(input)?;
+    let prefix = Prefix::ALL
+        .into_iter()
+        .find(|p| data.starts_with(p.bytes()) && data.len() == p.bytes().len() + p.payload_len())
+        .ok_or(TezosError::UnknownPrefix)?;
+    Ok((prefix, data[prefix.bytes().len()..].to_vec()))
+}
+
+/// Decodes a Tezos Base58Check string that must use the given prefix.
+///
+/// # Arguments
+///
+/// * `input` - The encoded string
+/// * `expected` - The prefix the string must use
+///
+/// # Returns
+///
+/// The raw payload, or a `TezosError` if the string is invalid or uses another prefix
+pub fn decode_as(input: &str, expected: Prefix) -> Result<Vec<u8>, TezosError> {
+    let data = decode_check(input)?;
+    let Some(payload) = data.strip_prefix(expected.bytes()) else {
+        return Err(match decode(input) {
+            Ok((actual, _)) => TezosError::PrefixMismatch { expected, actual },
+            Err(e) => e,
+        });
+    };
+
+    if payload.len() != expected.payload_len() {
+        return Err(TezosError::InvalidLength {
+            expected: expected.payload_len(),
+            actual: payload.len(),
+        });
+    }
+    Ok(payload.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_text_matches_encoding() {
+        for prefix in Prefix::ALL {
+            for fill in [0x00, 0xFF] {
+                let payload = vec![fill; prefix.payload_len()];
+                let encoded = encode(prefix, &payload).unwrap();
+                assert!(
+                    encoded.starts_with(prefix.text()),
+                    "{prefix:?} encoded as {encoded}"
+                );
+                assert_eq!(decode(&encoded).unwrap(), (prefix, payload));
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(
+            encode(Prefix::ChainId, &[0x7a, 0x06, 0xa7, 0x70]).unwrap(),
+            "NetXdQprcVkpaWU"
+        );
+        let (prefix, payload) = decode("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx").unwrap();
+        assert_eq!(prefix, Prefix::Tz1);
+        assert_eq!(
+            payload,
+            [
+                0x02, 0x29, 0x8c, 0x03, 0xed, 0x7d, 0x45, 0x4a, 0x10, 0x1e, 0xb7, 0x02, 0x2b, 0xc9,
+                0x5f, 0x7e, 0x5f, 0x41, 0xac, 0x78
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edsk_forms_are_distinguished() {
+        let seed = encode(Prefix::EdskSeed, &[7; 32]).unwrap();
+        let secret = encode(Prefix::Edsk, &[7; 64]).unwrap();
+        assert_eq!(decode(&seed).unwrap().0, Prefix::EdskSeed);
+        assert_eq!(decode(&secret).unwrap().0, Prefix::Edsk);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            encode(Prefix::Tz1, &[0; 19]),
+            Err(TezosError::InvalidLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+        assert_eq!(
+            decode(&encode_check(b"not tezos")),
+            Err(TezosError::UnknownPrefix)
+        );
+
+        let kt1 = encode(Prefix::Kt1, &[1; 20]).unwrap();
+        assert_eq!(decode_as(&kt1, Prefix::Kt1).unwrap(), [1; 20]);
+        assert_eq!(
+            decode_as(&kt1, Prefix::Tz1),
+            Err(TezosError::PrefixMismatch {
+                expected: Prefix::Tz1,
+                actual: Prefix::Kt1
+            })
+        );
+    }
+}
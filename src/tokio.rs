@@ -0,0 +1,248 @@
+//! Async streaming adapters for Tokio.
+//!
+//! These mirror [`Base58EncodeWriter`](crate::Base58EncodeWriter) and
+//! [`Base58DecodeReader`](crate::Base58DecodeReader) for `AsyncWrite` and `AsyncRead`,
+//! using the same block-wise format, so their output is identical to
+//! [`monero::encode`](crate::monero::encode) of the same bytes.
+//!
+//! The inner reader or writer must be `Unpin`; wrap it in `Box::pin` if it is not.
+
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+use std::io;
+
+use ::tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::stream::{BlockDecoder, BlockEncoder, WRITE_CHUNK_SIZE};
+
+/// An async writer that encodes the bytes written to it as block-wise Base58 and passes
+/// the characters on to an inner writer.
+///
+/// Shutting the writer down, for example with `AsyncWriteExt::shutdown`, encodes the
+/// final partial block before shutting down the inner writer. Dropping the writer
+/// without shutting it down loses that block.
+///
+/// # Examples
+///
+/// ```
+/// use b58::tokio::Base58EncodeWriter;
+/// use tokio::io::AsyncWriteExt;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut writer = Base58EncodeWriter::new(Vec::new());
+/// writer.write_all(b"Hello, World!").await?;
+/// writer.shutdown().await?;
+/// assert_eq!(writer.into_inner(), b58::monero::encode(b"Hello, World!").as_bytes());
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Base58EncodeWriter<W: AsyncWrite + Unpin> {
+    inner: W,
+    encoder: BlockEncoder,
+    /// Encoded characters not yet accepted by the inner writer
+    out: Vec<u8>,
+    out_pos: usize,
+    finished: bool,
+}
+
+impl<W: AsyncWrite + Unpin> Base58EncodeWriter<W> {
+    /// Creates a writer that sends the encoded characters to `inner`.
+    pub fn new(inner: W) -> Self {
+        Base58EncodeWriter {
+            inner,
+            encoder: BlockEncoder::default(),
+            out: Vec::new(),
+            out_pos: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly interleaves with the encoded output.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes the writer, returning the inner writer.
+    ///
+    /// Encoded output that has not been flushed is lost.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes buffered encoded characters to the inner writer
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.out_pos < self.out.len() {
+            let n = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.out[self.out_pos..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.out_pos += n;
+        }
+        self.out.clear();
+        self.out_pos = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for Base58EncodeWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        if this.finished {
+            return Poll::Ready(Err(io::Error::other("write after shutdown")));
+        }
+
+        // The encoded characters are written on the next call
+        let n = buf.len().min(WRITE_CHUNK_SIZE);
+        this.encoder.encode(&buf[..n], &mut this.out);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.finished {
+            this.encoder.finish(&mut this.out);
+            this.finished = true;
+        }
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+/// An async reader that decodes block-wise Base58 read from an inner reader.
+///
+/// Input requirements and error reporting are the same as for
+/// [`Base58DecodeReader`](crate::Base58DecodeReader).
+///
+/// # Examples
+///
+/// ```
+/// use b58::tokio::Base58DecodeReader;
+/// use tokio::io::AsyncReadExt;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut reader = Base58DecodeReader::new("1An6UebxCZd1gu".as_bytes());
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded).await?;
+/// assert_eq!(decoded, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Base58DecodeReader<R: AsyncRead + Unpin> {
+    inner: R,
+    decoder: BlockDecoder,
+}
+
+impl<R: AsyncRead + Unpin> Base58DecodeReader<R> {
+    /// Creates a reader that decodes the characters read from `inner`.
+    pub fn new(inner: R) -> Self {
+        Base58DecodeReader {
+            inner,
+            decoder: BlockDecoder::new(),
+        }
+    }
+
+    /// Returns a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips characters that have not been decoded yet.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes the reader, returning the inner reader.
+    ///
+    /// Characters that were read from it but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for Base58DecodeReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        while this.decoder.needs_input() {
+            let mut input = ReadBuf::new(this.decoder.spare_input());
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut input))?;
+            let n = input.filled().len();
+            this.decoder.filled(n);
+        }
+        let n = this.decoder.read(buf.initialize_unfilled())?;
+        buf.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monero;
+    use ::tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[::tokio::test]
+    async fn test_roundtrip() {
+        let input: Vec<u8> = (0..10_000u32).map(|i| (i * 31 + 7) as u8).collect();
+        for split in [1, 13, input.len()] {
+            let mut writer = Base58EncodeWriter::new(Vec::new());
+            for chunk in input.chunks(split) {
+                writer.write_all(chunk).await.unwrap();
+            }
+            writer.shutdown().await.unwrap();
+            let encoded = writer.into_inner();
+            assert_eq!(encoded, monero::encode(&input).as_bytes(), "split {split}");
+
+            let mut reader = Base58DecodeReader::new(&encoded[..]);
+            let mut decoded = Vec::new();
+            let mut buf = [0u8; 5];
+            loop {
+                let n = reader.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                decoded.extend_from_slice(&buf[..n]);
+            }
+            assert_eq!(decoded, input, "split {split}");
+        }
+    }
+
+    #[::tokio::test]
+    async fn test_errors() {
+        let mut reader = Base58DecodeReader::new(&b"111111111111"[..]);
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut writer = Base58EncodeWriter::new(Vec::new());
+        writer.shutdown().await.unwrap();
+        assert!(writer.write_all(b"late").await.is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! Extension traits for method-call style encoding and decoding.
+
+use crate::{Alphabet, DecodeError, decode_with_alphabet, encode_with_alphabet};
+
+/// Encodes bytes as Base58 with method-call syntax.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{Alphabet, ToBase58};
+///
+/// assert_eq!(b"Hello".to_base58(), "9Ajdvzr");
+/// assert_eq!(vec![0u8, 1].to_base58_with_alphabet(Alphabet::Ripple), "rp");
+/// ```
+pub trait ToBase58 {
+    /// Encodes `self` using the specified alphabet.
+    fn to_base58_with_alphabet(&self, alphabet: Alphabet) -> String;
+
+    /// Encodes `self` using the default Bitcoin alphabet.
+    fn to_base58(&self) -> String {
+        self.to_base58_with_alphabet(Alphabet::Bitcoin)
+    }
+}
+
+impl ToBase58 for [u8] {
+    fn to_base58_with_alphabet(&self, alphabet: Alphabet) -> String {
+        encode_with_alphabet(self, alphabet)
+    }
+}
+
+impl ToBase58 for Vec<u8> {
+    fn to_base58_with_alphabet(&self, alphabet: Alphabet) -> String {
+        encode_with_alphabet(self, alphabet)
+    }
+}
+
+/// Decodes Base58 strings with method-call syntax.
+///
+/// # Examples
+///
+/// ```
+/// use b58::{DecodeError, FromBase58};
+///
+/// assert_eq!("9Ajdvzr".from_base58().unwrap(), b"Hello");
+/// assert_eq!("0".from_base58(), Err(DecodeError::InvalidCharacter { character: '0', position: 0 }));
+/// ```
+// Named after the equivalent trait in other Base58 crates to ease migration
+#[allow(clippy::wrong_self_convention)]
+pub trait FromBase58 {
+    /// Decodes `self` using the specified alphabet.
+    fn from_base58_with_alphabet(&self, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError>;
+
+    /// Decodes `self` using the default Bitcoin alphabet.
+    fn from_base58(&self) -> Result<Vec<u8>, DecodeError> {
+        self.from_base58_with_alphabet(Alphabet::Bitcoin)
+    }
+}
+
+impl FromBase58 for str {
+    fn from_base58_with_alphabet(&self, alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+        decode_with_alphabet(self, alphabet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_through_traits() {
+        let data = vec![0u8, 0, 42, 255, 7];
+        let encoded = data.to_base58();
+        assert_eq!(encoded, crate::encode(&data));
+        assert_eq!(encoded.from_base58().unwrap(), data);
+
+        let slice: &[u8] = &data;
+        let flickr = slice.to_base58_with_alphabet(Alphabet::Flickr);
+        assert_eq!(
+            flickr.from_base58_with_alphabet(Alphabet::Flickr).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_generic_bounds() {
+        fn encode_all<T: ToBase58 + ?Sized>(items: &[&T]) -> Vec<String> {
+            items.iter().map(|i| i.to_base58()).collect()
+        }
+
+        let v = b"Hello".to_vec();
+        assert_eq!(encode_all(&[&v]), ["9Ajdvzr"]);
+        assert_eq!(encode_all::<[u8]>(&[b"Hello", b""]), ["9Ajdvzr", ""]);
+    }
+}
@@ -0,0 +1,105 @@
+//! Short Base58 slugs for UUIDs.
+
+use crate::{DecodeError, decode_array};
+
+/// Encodes a UUID's 16 bytes as Base58 using the default Bitcoin alphabet.
+///
+/// The result is at most 22 characters, compared to 36 for the hyphenated hex form.
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID bytes in big-endian order, as returned by `Uuid::as_bytes`
+///
+/// # Returns
+///
+/// A Base58 encoded string
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_uuid, encode_uuid};
+///
+/// let id = [
+///     0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f,
+///     0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+/// ];
+/// let slug = encode_uuid(id);
+/// assert_eq!(slug, "Dq7QdGPZBdz9vwjm3jLQSB");
+/// assert_eq!(decode_uuid(&slug).unwrap(), id);
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_uuid(uuid: [u8; 16]) -> String {
+    crate::encode(uuid)
+}
+
+/// Decodes a Base58 slug back into a UUID's 16 bytes using the default Bitcoin alphabet.
+///
+/// # Arguments
+///
+/// * `input` - The Base58 string to decode
+///
+/// # Returns
+///
+/// The UUID bytes, or `DecodeError::InvalidLength` if the input does not decode to
+/// exactly 16 bytes
+pub fn decode_uuid(input: &str) -> Result<[u8; 16], DecodeError> {
+    decode_array(input)
+}
+
+/// Encodes the UUID's bytes, matching [`encode_uuid`].
+///
+/// # Examples
+///
+/// ```
+/// use b58::{decode_uuid, ToBase58};
+/// use uuid::Uuid;
+///
+/// let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+/// let slug = id.to_base58();
+/// assert_eq!(Uuid::from_bytes(decode_uuid(&slug).unwrap()), id);
+/// ```
+#[cfg(feature = "uuid")]
+impl crate::ToBase58 for ::uuid::Uuid {
+    fn to_base58_with_alphabet(&self, alphabet: crate::Alphabet) -> String {
+        crate::encode_with_alphabet(self.as_bytes(), alphabet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for id in [[0u8; 16], [0xFF; 16], *b"0123456789abcdef"] {
+            let slug = encode_uuid(id);
+            assert!(slug.len() <= 22);
+            assert_eq!(decode_uuid(&slug).unwrap(), id);
+        }
+        assert_eq!(encode_uuid([0xFF; 16]).len(), 22);
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        assert_eq!(
+            decode_uuid("9Ajdvzr"),
+            Err(DecodeError::InvalidLength {
+                expected: 16,
+                actual: 5
+            })
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid_crate() {
+        use crate::{Alphabet, ToBase58};
+
+        let id = ::uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        assert_eq!(id.to_base58(), encode_uuid(*id.as_bytes()));
+        assert_eq!(
+            id.to_base58_with_alphabet(Alphabet::Flickr),
+            crate::encode_with_alphabet(id.as_bytes(), Alphabet::Flickr)
+        );
+    }
+}
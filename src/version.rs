@@ -0,0 +1,40 @@
+//! Well-known single-byte Base58Check version prefixes.
+//!
+//! Use these with [`encode_versioned`](crate::encode_versioned) and
+//! [`decode_versioned`](crate::decode_versioned) to produce or recognize addresses and
+//! keys for Bitcoin and networks derived from it.
+
+/// Bitcoin mainnet pay-to-public-key-hash addresses (`1...`).
+pub const BITCOIN_P2PKH: u8 = 0x00;
+/// Bitcoin mainnet pay-to-script-hash addresses (`3...`).
+pub const BITCOIN_P2SH: u8 = 0x05;
+/// Bitcoin mainnet WIF private keys (`5...`, `K...`, `L...`).
+pub const BITCOIN_WIF: u8 = 0x80;
+
+/// Bitcoin testnet pay-to-public-key-hash addresses (`m...`, `n...`).
+pub const BITCOIN_TESTNET_P2PKH: u8 = 0x6F;
+/// Bitcoin testnet pay-to-script-hash addresses (`2...`).
+pub const BITCOIN_TESTNET_P2SH: u8 = 0xC4;
+/// Bitcoin testnet WIF private keys (`9...`, `c...`).
+pub const BITCOIN_TESTNET_WIF: u8 = 0xEF;
+
+/// Litecoin pay-to-public-key-hash addresses (`L...`).
+pub const LITECOIN_P2PKH: u8 = 0x30;
+/// Litecoin pay-to-script-hash addresses (`M...`).
+pub const LITECOIN_P2SH: u8 = 0x32;
+/// Litecoin WIF private keys.
+pub const LITECOIN_WIF: u8 = 0xB0;
+
+/// Dogecoin pay-to-public-key-hash addresses (`D...`).
+pub const DOGECOIN_P2PKH: u8 = 0x1E;
+/// Dogecoin pay-to-script-hash addresses (`9...`, `A...`).
+pub const DOGECOIN_P2SH: u8 = 0x16;
+/// Dogecoin WIF private keys.
+pub const DOGECOIN_WIF: u8 = 0x9E;
+
+/// Dash pay-to-public-key-hash addresses (`X...`).
+pub const DASH_P2PKH: u8 = 0x4C;
+/// Dash pay-to-script-hash addresses (`7...`).
+pub const DASH_P2SH: u8 = 0x10;
+/// Dash WIF private keys.
+pub const DASH_WIF: u8 = 0xCC;
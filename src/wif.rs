@@ -0,0 +1,278 @@
+//! Wallet Import Format (WIF) private keys.
+//!
+//! A WIF string is the Base58Check encoding of a version byte (`0x80` on mainnet, `0xEF`
+//! on testnet), the 32-byte secret key, and an optional `0x01` suffix marking that the
+//! key corresponds to a compressed public key.
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::wif::{self, Network, PrivateKey};
+//!
+//! let key = wif::decode("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617").unwrap();
+//! assert!(key.compressed);
+//! assert_eq!(key.network, Network::Mainnet);
+//! assert_eq!(wif::encode(&key), "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617");
+//! ```
+
+use crate::{DecodeError, decode_check, encode_check, version, wipe};
+
+/// Version byte of mainnet WIF keys.
+pub const MAINNET_VERSION: u8 = version::BITCOIN_WIF;
+/// Version byte of testnet WIF keys.
+pub const TESTNET_VERSION: u8 = version::BITCOIN_TESTNET_WIF;
+
+const COMPRESSED_FLAG: u8 = 0x01;
+
+/// Bitcoin network a key or address belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Network {
+    /// Bitcoin mainnet.
+    #[default]
+    Mainnet,
+    /// Bitcoin testnet (also used by signet and regtest).
+    Testnet,
+}
+
+/// A private key decoded from, or to be encoded as, WIF.
+///
+/// The `Debug` output leaves out the secret, so keys can be logged without leaking it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKey {
+    /// The raw 32-byte secret key.
+    pub secret: [u8; 32],
+    /// Whether the key corresponds to a compressed public key.
+    pub compressed: bool,
+    /// The network the key is intended for.
+    pub network: Network,
+}
+
+impl core::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("secret", &"<redacted>")
+            .field("compressed", &self.compressed)
+            .field("network", &self.network)
+            .finish()
+    }
+}
+
+/// Error type for WIF decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WifError {
+    /// The string is not valid Base58Check.
+    Decode(DecodeError),
+    /// The version byte is neither the mainnet nor the testnet WIF version.
+    InvalidVersion(u8),
+    /// The payload is not 33 or 34 bytes long.
+    InvalidLength(usize),
+    /// The byte following a 34-byte payload's key is not the compression flag `0x01`.
+    InvalidCompressionFlag(u8),
+}
+
+impl core::fmt::Display for WifError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WifError::Decode(e) => write!(f, "Invalid WIF encoding: {e}"),
+            WifError::InvalidVersion(v) => write!(f, "Invalid WIF version byte: 0x{v:02x}"),
+            WifError::InvalidLength(len) => write!(f, "Invalid WIF payload length: {len}"),
+            WifError::InvalidCompressionFlag(b) => {
+                write!(f, "Invalid WIF compression flag: 0x{b:02x}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for WifError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            WifError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for WifError {
+    fn from(e: DecodeError) -> Self {
+        WifError::Decode(e)
+    }
+}
+
+/// Encodes a private key as a WIF string.
+///
+/// # Arguments
+///
+/// * `key` - The private key, compression flag and network to encode
+///
+/// # Returns
+///
+/// The WIF string
+pub fn encode(key: &PrivateKey) -> String {
+    let mut payload = Vec::with_capacity(34);
+    payload.push(match key.network {
+        Network::Mainnet => MAINNET_VERSION,
+        Network::Testnet => TESTNET_VERSION,
+    });
+    payload.extend_from_slice(&key.secret);
+    if key.compressed {
+        payload.push(COMPRESSED_FLAG);
+    }
+    let encoded = encode_check(&payload);
+    wipe(&mut payload);
+    encoded
+}
+
+/// Decodes a WIF string into a private key.
+///
+/// # Arguments
+///
+/// * `input` - The WIF string to decode
+///
+/// # Returns
+///
+/// The decoded private key, or a `WifError` if the checksum, version byte, length or
+/// compression flag is invalid
+pub fn decode(input: &str) -> Result<PrivateKey, WifError> {
+    let mut payload = decode_check(input)?;
+    let key = from_payload(&payload);
+    wipe(&mut payload);
+    key
+}
+
+/// Reads a private key from a decoded WIF payload
+fn from_payload(payload: &[u8]) -> Result<PrivateKey, WifError> {
+    let compressed = match payload.len() {
+        33 => false,
+        34 if payload[33] == COMPRESSED_FLAG => true,
+        34 => return Err(WifError::InvalidCompressionFlag(payload[33])),
+        len => return Err(WifError::InvalidLength(len)),
+    };
+
+    let network = match payload[0] {
+        MAINNET_VERSION => Network::Mainnet,
+        TESTNET_VERSION => Network::Testnet,
+        version => return Err(WifError::InvalidVersion(version)),
+    };
+
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&payload[1..33]);
+
+    Ok(PrivateKey {
+        secret,
+        compressed,
+        network,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [
+        0x0C, 0x28, 0xFC, 0xA3, 0x86, 0xC7, 0xA2, 0x27, 0x60, 0x0B, 0x2F, 0xE5, 0x0B, 0x7C, 0xAE,
+        0x11, 0xEC, 0x86, 0xD3, 0xBF, 0x1F, 0xBE, 0x47, 0x1B, 0xE8, 0x98, 0x27, 0xE1, 0x9D, 0x72,
+        0xAA, 0x1D,
+    ];
+
+    #[test]
+    fn test_encode_mainnet() {
+        let mut key = PrivateKey {
+            secret: SECRET,
+            compressed: false,
+            network: Network::Mainnet,
+        };
+        assert_eq!(
+            encode(&key),
+            "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"
+        );
+
+        key.compressed = true;
+        assert_eq!(
+            encode(&key),
+            "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"
+        );
+    }
+
+    #[test]
+    fn test_decode_mainnet() {
+        let key = decode("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap();
+        assert_eq!(key.secret, SECRET);
+        assert!(!key.compressed);
+        assert_eq!(key.network, Network::Mainnet);
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let key = PrivateKey {
+            secret: SECRET,
+            compressed: true,
+            network: Network::Mainnet,
+        };
+        assert_eq!(
+            format!("{key:?}"),
+            r#"PrivateKey { secret: "<redacted>", compressed: true, network: Mainnet }"#
+        );
+    }
+
+    #[test]
+    fn test_testnet_roundtrip() {
+        for compressed in [false, true] {
+            let key = PrivateKey {
+                secret: SECRET,
+                compressed,
+                network: Network::Testnet,
+            };
+            let encoded = encode(&key);
+            assert!(encoded.starts_with(if compressed { 'c' } else { '9' }));
+            assert_eq!(decode(&encoded).unwrap(), key);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_roundtrip_with_zeroize() {
+        // The payload buffers are wiped on the way in and out, which must not touch
+        // the encoded string or the returned key
+        for network in [Network::Mainnet, Network::Testnet] {
+            for compressed in [false, true] {
+                let key = PrivateKey {
+                    secret: SECRET,
+                    compressed,
+                    network,
+                };
+                assert_eq!(decode(&encode(&key)).unwrap(), key);
+            }
+        }
+        assert_eq!(
+            decode(&encode_check([0x81; 33])),
+            Err(WifError::InvalidVersion(0x81))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK"),
+            Err(WifError::Decode(DecodeError::ChecksumMismatch))
+        );
+
+        let mut payload = vec![0x42];
+        payload.extend_from_slice(&SECRET);
+        assert_eq!(
+            decode(&encode_check(&payload)),
+            Err(WifError::InvalidVersion(0x42))
+        );
+
+        payload[0] = MAINNET_VERSION;
+        payload.push(0x02);
+        assert_eq!(
+            decode(&encode_check(&payload)),
+            Err(WifError::InvalidCompressionFlag(0x02))
+        );
+
+        assert_eq!(
+            decode(&encode_check(&payload[..20])),
+            Err(WifError::InvalidLength(20))
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! XRP Ledger address and seed encoding.
+//!
+//! The XRP Ledger uses Base58Check with the Ripple alphabet and a type prefix byte:
+//! `0x00` for classic account IDs (`r...`), `0x21` for family seeds (`s...`) and `0x1C`
+//! for node public keys (`n...`).
+//!
+//! # Examples
+//!
+//! ```
+//! use b58::xrp;
+//!
+//! let account = xrp::encode_account_id(&[0u8; 20]);
+//! assert_eq!(account, "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+//! assert_eq!(xrp::decode_account_id(&account).unwrap(), [0u8; 20]);
+//! ```
+
+use crate::{Alphabet, DecodeError, decode_check_with_alphabet, encode_check_with_alphabet};
+
+/// Type prefix of classic account IDs.
+pub const ACCOUNT_ID_PREFIX: u8 = 0x00;
+/// Type prefix of family seeds.
+pub const FAMILY_SEED_PREFIX: u8 = 0x21;
+/// Type prefix of node public keys.
+pub const NODE_PUBLIC_PREFIX: u8 = 0x1C;
+
+/// Error type for XRP Ledger decoding failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XrpError {
+    /// The string is not valid Base58Check in the Ripple alphabet.
+    Decode(DecodeError),
+    /// The type prefix byte does not match the expected value.
+    InvalidPrefix {
+        /// The prefix that was expected.
+        expected: u8,
+        /// The prefix that was found.
+        actual: u8,
+    },
+    /// The payload does not have the expected length.
+    InvalidLength {
+        /// The number of payload bytes that was expected.
+        expected: usize,
+        /// The number of payload bytes that was decoded.
+        actual: usize,
+    },
+}
+
+impl core::fmt::Display for XrpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            XrpError::Decode(e) => write!(f, "Invalid XRP encoding: {e}"),
+            XrpError::InvalidPrefix { expected, actual } => write!(
+                f,
+                "Invalid XRP type prefix: expected 0x{expected:02x}, got 0x{actual:02x}"
+            ),
+            XrpError::InvalidLength { expected, actual } => write!(
+                f,
+                "Invalid XRP payload length: expected {expected} bytes, got {actual}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for XrpError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            XrpError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for XrpError {
+    fn from(e: DecodeError) -> Self {
+        XrpError::Decode(e)
+    }
+}
+
+/// Encodes a 20-byte account ID as a classic `r...` address.
+pub fn encode_account_id(account_id: &[u8; 20]) -> String {
+    encode_with_prefix(ACCOUNT_ID_PREFIX, account_id)
+}
+
+/// Decodes a classic `r...` address into its 20-byte account ID.
+pub fn decode_account_id(input: &str) -> Result<[u8; 20], XrpError> {
+    decode_with_prefix(input, ACCOUNT_ID_PREFIX)
+}
+
+/// Encodes a 16-byte seed as an `s...` family seed.
+pub fn encode_seed(seed: &[u8; 16]) -> String {
+    encode_with_prefix(FAMILY_SEED_PREFIX, seed)
+}
+
+/// Decodes an `s...` family seed into its 16-byte seed.
+pub fn decode_seed(input: &str) -> Result<[u8; 16], XrpError> {
+    decode_with_prefix(input, FAMILY_SEED_PREFIX)
+}
+
+/// Encodes a 33-byte compressed public key as an `n...` node public key.
+pub fn encode_node_public(key: &[u8; 33]) -> String {
+    encode_with_prefix(NODE_PUBLIC_PREFIX, key)
+}
+
+/// Decodes an `n...` node public key into its 33-byte compressed public key.
+pub fn decode_node_public(input: &str) -> Result<[u8; 33], XrpError> {
+    decode_with_prefix(input, NODE_PUBLIC_PREFIX)
+}
+
+/// Prepends the type prefix and encodes as Ripple-alphabet Base58Check
+fn encode_with_prefix(prefix: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 1);
+    data.push(prefix);
+    data.extend_from_slice(payload);
+    encode_check_with_alphabet(&data, Alphabet::Ripple)
+}
+
+/// Decodes Ripple-alphabet Base58Check and checks the type prefix and payload length
+fn decode_with_prefix<const N: usize>(input: &str, prefix: u8) -> Result<[u8; N], XrpError> {
+    let data = decode_check_with_alphabet(input, Alphabet::Ripple)?;
+    let (&actual, payload) = data.split_first().ok_or(XrpError::InvalidLength {
+        expected: N,
+        actual: 0,
+    })?;
+
+    if actual != prefix {
+        return Err(XrpError::InvalidPrefix {
+            expected: prefix,
+            actual,
+        });
+    }
+
+    payload.try_into().map_err(|_| XrpError::InvalidLength {
+        expected: N,
+        actual: payload.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_id_known_vectors() {
+        assert_eq!(encode_account_id(&[0; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+
+        let mut one = [0u8; 20];
+        one[19] = 1;
+        assert_eq!(encode_account_id(&one), "rrrrrrrrrrrrrrrrrrrrBZbvji");
+
+        let genesis = [
+            0xB5, 0xF7, 0x62, 0x79, 0x8A, 0x53, 0xD5, 0x43, 0xA0, 0x14, 0xCA, 0xF8, 0xB2, 0x97,
+            0xCF, 0xF8, 0xF2, 0xF9, 0x37, 0xE8,
+        ];
+        assert_eq!(
+            decode_account_id("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap(),
+            genesis
+        );
+    }
+
+    #[test]
+    fn test_seed_known_vector() {
+        let seed = [
+            0xDE, 0xDC, 0xE9, 0xCE, 0x67, 0xB4, 0x51, 0xD8, 0x52, 0xFD, 0x4E, 0x84, 0x6F, 0xCD,
+            0xE3, 0x1C,
+        ];
+        assert_eq!(encode_seed(&seed), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_eq!(decode_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap(), seed);
+    }
+
+    #[test]
+    fn test_node_public_roundtrip() {
+        let mut key = [0x5A; 33];
+        key[0] = 0x02;
+        let encoded = encode_node_public(&key);
+        assert!(encoded.starts_with('n'));
+        assert_eq!(decode_node_public(&encoded).unwrap(), key);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_account_id("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj"),
+            Err(XrpError::Decode(DecodeError::ChecksumMismatch))
+        );
+        assert_eq!(
+            decode_seed("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            Err(XrpError::InvalidPrefix {
+                expected: FAMILY_SEED_PREFIX,
+                actual: ACCOUNT_ID_PREFIX
+            })
+        );
+        assert_eq!(
+            decode_account_id(&encode_with_prefix(ACCOUNT_ID_PREFIX, &[1; 19])),
+            Err(XrpError::InvalidLength {
+                expected: 20,
+                actual: 19
+            })
+        );
+    }
+}